@@ -3,22 +3,29 @@ use std::{
     time::{Duration, Instant},
 };
 
-use anyhow::Context;
-use augustinus_app::{Action, AgentsInputMode, AppState, GeneralInputMode, LocDelta, PaneId};
-use augustinus_pty::PtySession;
-use augustinus_store::config::{AppConfig, Language};
+#[cfg(unix)]
+use augustinus_pty::PtyServer;
+use augustinus_runtime::{DaemonPtys, RuntimeBuilder};
+use augustinus_store::config::{AppConfig, KeysConfig, Language, PaneConfig, SplitsConfig};
 use augustinus_store::db::Store;
+use augustinus_store::paths;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste,
+        EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 fn main() -> io::Result<()> {
+    if std::env::args().nth(1).as_deref() == Some("daemon") {
+        return run_daemon();
+    }
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableBracketedPaste, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -31,22 +38,48 @@ fn main() -> io::Result<()> {
             shell: "/bin/bash".to_string(),
             git_repo: None,
             agents_cmd: None,
+            general: PaneConfig::default(),
+            agent_pane: PaneConfig::default(),
+            agents: Vec::new(),
+            persistent_sessions: false,
+            keys: KeysConfig::default(),
+            splits: SplitsConfig::default(),
         });
         let chosen_language = run_language_picker(&mut terminal, config.language)?;
         config.language = chosen_language;
         let _ = config.save().map_err(anyhow_to_io)?;
-        run_app(&mut terminal, &config)
+        run_app(&config)
     })();
 
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        LeaveAlternateScreen
+    )?;
     terminal.show_cursor()?;
 
     result
 }
 
 fn anyhow_to_io(error: anyhow::Error) -> io::Error {
-    io::Error::new(io::ErrorKind::Other, error)
+    io::Error::other(error)
+}
+
+/// `augustinus daemon`: hosts the PTYs of `persistent_sessions` until none
+/// is left.
+#[cfg(unix)]
+fn run_daemon() -> io::Result<()> {
+    let socket = paths::pty_socket().map_err(anyhow_to_io)?;
+    PtyServer::bind(socket)
+        .and_then(PtyServer::run)
+        .map_err(anyhow_to_io)
+}
+
+#[cfg(not(unix))]
+fn run_daemon() -> io::Result<()> {
+    Err(io::Error::other("the pty daemon needs Unix sockets"))
 }
 
 fn run_splash(
@@ -63,12 +96,11 @@ fn run_splash(
         })?;
 
         let timeout = tick_rate;
-        if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if should_quit(key) {
-                    return Ok(());
-                }
-            }
+        if event::poll(timeout)?
+            && let Event::Key(key) = event::read()?
+            && should_quit(key)
+        {
+            return Ok(());
         }
     }
 
@@ -87,21 +119,21 @@ fn run_language_picker(
             augustinus_tui::render_first_boot(frame, selected_index);
         })?;
 
-        if event::poll(tick_rate)? {
-            if let Event::Key(key) = event::read()? {
-                if should_quit(key) {
-                    return Ok(default);
+        if event::poll(tick_rate)?
+            && let Event::Key(key) = event::read()?
+        {
+            if should_quit(key) {
+                return Ok(default);
+            }
+            match key.code {
+                KeyCode::Char('k') | KeyCode::Up => {
+                    selected_index = selected_index.saturating_sub(1)
                 }
-                match key.code {
-                    KeyCode::Char('k') | KeyCode::Up => {
-                        selected_index = selected_index.saturating_sub(1)
-                    }
-                    KeyCode::Char('j') | KeyCode::Down => {
-                        selected_index = (selected_index + 1).min(2)
-                    }
-                    KeyCode::Enter => return Ok(index_to_language(selected_index)),
-                    _ => {}
+                KeyCode::Char('j') | KeyCode::Down => {
+                    selected_index = (selected_index + 1).min(2)
                 }
+                KeyCode::Enter => return Ok(index_to_language(selected_index)),
+                _ => {}
             }
         }
     }
@@ -123,261 +155,29 @@ fn index_to_language(index: usize) -> Language {
     }
 }
 
-fn run_app(terminal: &mut Terminal<CrosstermBackend<Stdout>>, config: &AppConfig) -> io::Result<()> {
-    let mut state = AppState::new_for_test();
-    let store = init_store_and_load_stats(&mut state)?;
-    let mut git_poll_elapsed = Duration::from_secs(30);
-
-    let size = terminal.size()?;
-    let (general_cols, general_rows) = general_pty_size(&state, size.width, size.height);
-    let (agents_cols, agents_rows) = pane_pty_size(&state, size.width, size.height, PaneId::Agents);
-
-    let mut pty = PtySession::spawn(&config.shell, general_cols, general_rows).map_err(anyhow_to_io)?;
-    let mut last_general_cols = general_cols;
-    let mut last_general_rows = general_rows;
-
-    let mut agents_pty = match config
-        .agents_cmd
-        .as_ref()
-        .and_then(|cmd| cmd.split_first())
-    {
-        Some((program, args)) => {
-            let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
-            PtySession::spawn_command(program, &args, agents_cols, agents_rows)
-        }
-        None => PtySession::spawn_command("codex", &[], agents_cols, agents_rows),
-    }
-    .or_else(|_| {
-        let mut fallback =
-            PtySession::spawn(&config.shell, agents_cols, agents_rows).context("spawn agents fallback")?;
-        let _ = fallback.send_paste("echo 'codex not found; install it, then restart'\n");
-        Ok(fallback)
-    })
-    .map_err(anyhow_to_io)?;
-
-    let mut last_agents_cols = agents_cols;
-    let mut last_agents_rows = agents_rows;
-    let tick_rate = Duration::from_millis(33);
-    let mut last_tick = Instant::now();
-
-    loop {
-        terminal.draw(|frame| {
-            augustinus_tui::render(frame, &mut state);
-        })?;
-
-        pty.poll();
-        state.general_screen = pty.snapshot().contents;
-
-        agents_pty.poll();
-        let agents_snapshot = agents_pty.snapshot();
-        state.agents_screen = agents_snapshot.contents;
-        state.agents_cursor_row = agents_snapshot.cursor_row;
-        state.agents_cursor_col = agents_snapshot.cursor_col;
-
-        let size = terminal.size()?;
-        let (general_cols, general_rows) = general_pty_size(&state, size.width, size.height);
-        if general_cols != last_general_cols || general_rows != last_general_rows {
-            let _ = pty.resize(general_cols, general_rows);
-            last_general_cols = general_cols;
-            last_general_rows = general_rows;
-        }
-
-        let (agents_cols, agents_rows) = pane_pty_size(&state, size.width, size.height, PaneId::Agents);
-        if agents_cols != last_agents_cols || agents_rows != last_agents_rows {
-            let _ = agents_pty.resize(agents_cols, agents_rows);
-            last_agents_cols = agents_cols;
-            last_agents_rows = agents_rows;
-        }
-
-        let timeout = tick_rate.saturating_sub(last_tick.elapsed());
-        if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                state.on_activity();
-                if handle_key(key, &mut state, &mut pty, &mut agents_pty) {
-                    break;
-                }
-                if let Some(cmd) = state.last_command.take() {
-                    handle_command(&cmd, &mut state, &store)?;
-                }
-            }
-        }
-
-        if last_tick.elapsed() >= tick_rate {
-            let dt = last_tick.elapsed();
-            state.tick(dt);
-            git_poll_elapsed = git_poll_elapsed.saturating_add(dt);
-            if git_poll_elapsed >= Duration::from_secs(30) {
-                git_poll_elapsed = Duration::ZERO;
-                if let Some(repo) = config.git_repo.as_deref() {
-                    state.loc_delta = compute_loc_delta(repo);
-                } else {
-                    state.loc_delta = None;
-                }
-            }
-            last_tick = Instant::now();
-        }
-    }
-
-    Ok(())
-}
-
-fn compute_loc_delta(repo_path: &str) -> Option<LocDelta> {
-    let output = std::process::Command::new("git")
-        .arg("-C")
-        .arg(repo_path)
-        .arg("diff")
-        .arg("--numstat")
-        .output()
-        .ok()?;
-    if !output.status.success() {
-        return None;
-    }
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    Some(LocDelta::parse_git_numstat(&stdout))
-}
-
-fn general_pty_size(state: &AppState, term_cols: u16, term_rows: u16) -> (u16, u16) {
-    pane_pty_size(state, term_cols, term_rows, PaneId::General)
-}
-
-fn pane_pty_size(state: &AppState, term_cols: u16, term_rows: u16, pane_id: PaneId) -> (u16, u16) {
-    let (cols, rows) = match state.fullscreen {
-        Some(id) if id == pane_id => (term_cols, term_rows),
-        _ => (term_cols / 2, term_rows / 2),
-    };
-    (cols.saturating_sub(2).max(1), rows.saturating_sub(2).max(1))
-}
-
-fn init_store_and_load_stats(state: &mut AppState) -> io::Result<Store> {
+fn run_app(config: &AppConfig) -> io::Result<()> {
     let db_path = Store::default_db_path().map_err(anyhow_to_io)?;
     let store = Store::open(db_path).map_err(anyhow_to_io)?;
-
-    let today = chrono::Local::now().date_naive();
-    let focus_seconds_today = store
-        .focus_seconds_for_day(today)
-        .map_err(anyhow_to_io)?
-        .max(0) as u64;
-    let streak = store.streak_days_ending_today().map_err(anyhow_to_io)?;
-    state.focus.set_focus_seconds_today(focus_seconds_today);
-    state.focus.set_streak_days(streak);
-
-    Ok(store)
-}
-
-fn handle_command(cmd: &str, state: &mut AppState, store: &Store) -> io::Result<()> {
-    let cmd = cmd.trim();
-
-    if let Some(rest) = cmd.strip_prefix("focus ") {
-        let arg = rest.trim();
-        match arg {
-            "start" => {
-                store.insert_event("focus_start", "{}").map_err(anyhow_to_io)?;
-                if state.focus.start(Instant::now()) {
-                    state.motivation.on_focus_start();
-                }
-            }
-            "stop" => {
-                if let Some(elapsed) = state.focus.stop(Instant::now()) {
-                    let secs = elapsed.as_secs().min(i64::MAX as u64) as i64;
-                    store
-                        .insert_event("focus_stop", &format!(r#"{{"seconds":{secs}}}"#))
-                        .map_err(anyhow_to_io)?;
-                    store.add_focus_seconds_today(secs).map_err(anyhow_to_io)?;
-                    state.focus.add_focus_seconds_today(secs.max(0) as u64);
-                    let streak = store.streak_days_ending_today().map_err(anyhow_to_io)?;
-                    state.focus.set_streak_days(streak);
-                    state.motivation.on_focus_stop();
-                }
-            }
-            _ => {}
-        }
+    let mut builder = RuntimeBuilder::new(config.clone(), store);
+    if config.persistent_sessions
+        && let Some(daemon) = daemon_ptys()
+    {
+        builder = builder.ptys(daemon);
     }
+    // The splash and picker drew through another `Terminal`; start clean.
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    terminal.clear()?;
+    builder.start(terminal)?.run()
+}
 
-    Ok(())
+/// The PTY daemon for `persistent_sessions`, started if needed; `None` when
+/// it can't be reached, so the UI falls back to local PTYs.
+fn daemon_ptys() -> Option<DaemonPtys> {
+    let socket = paths::pty_socket().ok()?;
+    let exe = std::env::current_exe().ok()?;
+    DaemonPtys::connect(&socket, &exe)
 }
 
 fn should_quit(key: KeyEvent) -> bool {
     key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)
 }
-
-fn handle_key(
-    key: KeyEvent,
-    state: &mut AppState,
-    pty: &mut PtySession,
-    agents_pty: &mut PtySession,
-) -> bool {
-    if state.command.is_some() {
-        match key.code {
-            KeyCode::Esc => state.apply(Action::ExitCommandMode),
-            KeyCode::Enter => state.apply(Action::SubmitCommand),
-            KeyCode::Backspace => state.apply(Action::CommandBackspace),
-            KeyCode::Char(ch) if is_printable(ch) && key.modifiers.is_empty() => {
-                state.apply(Action::CommandAppend(ch));
-            }
-            _ => {}
-        }
-        return false;
-    }
-
-    if state.focused == PaneId::General
-        && state.general_input_mode == GeneralInputMode::TerminalLocked
-    {
-        if key.code == KeyCode::Esc {
-            state.apply(Action::ExitGeneralTerminalMode);
-            return false;
-        }
-        let _ = pty.send_key(key);
-        return false;
-    }
-
-    if state.focused == PaneId::Agents
-        && state.agents_input_mode == AgentsInputMode::CodexLocked
-    {
-        if key.code == KeyCode::Esc {
-            state.apply(Action::ExitAgentsTerminalMode);
-            return false;
-        }
-        let _ = agents_pty.send_key(key);
-        return false;
-    }
-
-    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
-        return true;
-    }
-
-    match key.code {
-        KeyCode::Char('h') => state.apply(Action::FocusLeft),
-        KeyCode::Char('j') => state.apply(Action::FocusDown),
-        KeyCode::Char('k') => state.apply(Action::FocusUp),
-        KeyCode::Char('l') => state.apply(Action::FocusRight),
-        KeyCode::Tab => state.apply(Action::RotateFocus),
-        KeyCode::Enter => {
-            if state.focused == PaneId::Agents
-                && state.agents_input_mode == AgentsInputMode::PaneControls
-            {
-                state.apply(Action::EnterAgentsTerminalMode);
-                return false;
-            }
-            if state.focused == PaneId::General
-                && state.general_input_mode == GeneralInputMode::AppControls
-            {
-                state.apply(Action::EnterGeneralTerminalMode);
-                return false;
-            }
-            state.apply(Action::EnterFullscreen)
-        }
-        KeyCode::Esc => {
-            if state.fullscreen.is_some() {
-                state.apply(Action::ExitFullscreen)
-            }
-        }
-        KeyCode::Char(':') => state.apply(Action::EnterCommandMode),
-        _ => {}
-    }
-
-    false
-}
-
-fn is_printable(ch: char) -> bool {
-    !ch.is_control()
-}
@@ -1,3 +1,7 @@
+use augustinus_i18n::Language;
+
+use crate::{CopyMotion, PaneId, SelectionKind, SendSource};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Action {
     FocusLeft,
@@ -5,15 +9,78 @@ pub enum Action {
     FocusUp,
     FocusDown,
     RotateFocus,
+    FocusPane(PaneId),
     EnterGeneralTerminalMode,
     ExitGeneralTerminalMode,
     EnterAgentsTerminalMode,
     ExitAgentsTerminalMode,
     EnterFullscreen,
     ExitFullscreen,
+    /// Resize the focused pane by `SPLIT_STEP`.
+    GrowPaneWidth,
+    ShrinkPaneWidth,
+    GrowPaneHeight,
+    ShrinkPaneHeight,
+    /// Back to even splits.
+    ResetSplits,
+    /// Switches every UI string, quotes included.
+    SetLanguage(Language),
     EnterCommandMode,
     ExitCommandMode,
     CommandAppend(char),
     CommandBackspace,
+    CommandDeleteWord,
+    CommandCursorLeft,
+    CommandCursorRight,
+    CommandCursorHome,
+    CommandCursorEnd,
+    CommandHistoryPrev,
+    CommandHistoryNext,
+    /// Tab: completes, or selects the next candidate.
+    CommandComplete,
+    /// Shift-Tab: selects the previous candidate.
+    CommandCompleteBack,
+    CommandCompleteCancel,
     SubmitCommand,
+    ScrollLineUp,
+    ScrollLineDown,
+    ScrollPageUp,
+    ScrollPageDown,
+    ScrollTop,
+    ScrollBottom,
+    ExitScroll,
+    Respawn(PaneId),
+    TabNew,
+    TabClose,
+    TabNext,
+    TabPrev,
+    TabSelect(usize),
+    RecordStart(PaneId),
+    RecordStop(PaneId),
+    SearchOpen,
+    SearchAppend(char),
+    SearchBackspace,
+    SearchToggleRegex,
+    SearchSubmit,
+    SearchCancel,
+    SearchNext,
+    SearchPrev,
+    SearchClear,
+    CopyEnter,
+    CopyMove(CopyMotion),
+    CopySelect(SelectionKind),
+    CopyClearSelection,
+    CopyExit,
+    /// Scroll the focused terminal to the previous/next shell prompt.
+    PromptPrev,
+    PromptNext,
+    /// Copy mode with the output of the last finished command selected.
+    SelectLastOutput,
+    /// Paste part of the GENERAL terminal into the AI AGENTS terminal.
+    SendOutput(SendSource),
+    AgentSelect(usize),
+    AgentPickerUp,
+    AgentPickerDown,
+    /// Choose the picker entry at this index, or the highlighted one.
+    AgentPickerConfirm(Option<usize>),
 }
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+/// How long a pane border flashes after a bell or silence alert.
+pub const ALERT_FLASH: Duration = Duration::from_millis(750);
+
+/// Bell, activity and silence alerts for a terminal that is not on screen.
+/// Flags only rise while the terminal is hidden and are cleared when it is
+/// shown again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ActivityMonitor {
+    bell: bool,
+    activity: bool,
+    silence: bool,
+    silence_after: Option<Duration>,
+    since_output: Duration,
+    /// Output arrived since the last silence alert, so another may fire.
+    armed: bool,
+    flash: Duration,
+}
+
+impl ActivityMonitor {
+    pub fn has_bell(&self) -> bool {
+        self.bell
+    }
+
+    pub fn has_activity(&self) -> bool {
+        self.activity
+    }
+
+    pub fn has_silence(&self) -> bool {
+        self.silence
+    }
+
+    pub fn has_alert(&self) -> bool {
+        self.bell || self.activity || self.silence
+    }
+
+    pub fn is_flashing(&self) -> bool {
+        !self.flash.is_zero()
+    }
+
+    /// Alert when a hidden terminal that had output goes quiet this long.
+    pub fn set_silence_after(&mut self, after: Option<Duration>) {
+        self.silence_after = after;
+    }
+
+    pub fn on_output(&mut self, visible: bool) {
+        self.since_output = Duration::ZERO;
+        self.armed = true;
+        if !visible {
+            self.activity = true;
+        }
+    }
+
+    pub fn on_bell(&mut self, visible: bool) {
+        if !visible {
+            self.bell = true;
+            self.flash = ALERT_FLASH;
+        }
+    }
+
+    /// Flashes the border without raising a flag, e.g. when an agent starts
+    /// waiting for input.
+    pub fn flash(&mut self) {
+        self.flash = ALERT_FLASH;
+    }
+
+    pub fn tick(&mut self, dt: Duration, visible: bool) {
+        self.flash = self.flash.saturating_sub(dt);
+        self.since_output = self.since_output.saturating_add(dt);
+        if let Some(after) = self.silence_after
+            && self.armed
+            && self.since_output >= after
+        {
+            self.armed = false;
+            if !visible {
+                self.silence = true;
+                self.flash = ALERT_FLASH;
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.bell = false;
+        self.activity = false;
+        self.silence = false;
+        self.flash = Duration::ZERO;
+    }
+}
@@ -0,0 +1,51 @@
+/// Choice of agent CLI shown in the AI AGENTS pane when none of the
+/// configured agents could be started. The runtime fills in what it found on
+/// `PATH` and spawns whatever the user confirms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentPicker {
+    choices: Vec<String>,
+    selected: usize,
+    chosen: Option<String>,
+}
+
+impl AgentPicker {
+    pub fn new(choices: Vec<String>) -> Self {
+        Self {
+            choices,
+            selected: 0,
+            chosen: None,
+        }
+    }
+
+    pub fn choices(&self) -> &[String] {
+        &self.choices
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn down(&mut self) {
+        if self.selected + 1 < self.choices.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index < self.choices.len() {
+            self.selected = index;
+        }
+    }
+
+    pub fn confirm(&mut self) {
+        self.chosen = self.choices.get(self.selected).cloned();
+    }
+
+    pub fn take_choice(&mut self) -> Option<String> {
+        self.chosen.take()
+    }
+}
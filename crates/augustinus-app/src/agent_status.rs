@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+/// Quiet time after which an agent is taken to be waiting for input.
+pub const AGENT_IDLE_AFTER: Duration = Duration::from_secs(3);
+/// Quiet time that confirms a waiting prompt matched on screen.
+pub const AGENT_PROMPT_SETTLE: Duration = Duration::from_millis(250);
+/// Shorter busy spells (e.g. echoing the user's typing) don't notify.
+pub const AGENT_MIN_BUSY: Duration = Duration::from_secs(2);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AgentStatus {
+    Busy,
+    #[default]
+    Waiting,
+}
+
+/// Infers whether an agent is working or waiting for the user from what
+/// its PTY does: a changing screen (output, spinners, cursor movement) means
+/// busy; a quiet screen, or a configured prompt pattern on screen, means
+/// waiting. The runtime reports screen changes through `on_screen_change`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AgentStatusTracker {
+    status: AgentStatus,
+    quiet: Duration,
+    busy_for: Duration,
+    prompt_visible: bool,
+    became_waiting: bool,
+}
+
+impl AgentStatusTracker {
+    pub fn status(&self) -> AgentStatus {
+        self.status
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.status == AgentStatus::Busy
+    }
+
+    /// `prompt_visible`: a waiting pattern matches the new screen.
+    pub fn on_screen_change(&mut self, prompt_visible: bool) {
+        self.quiet = Duration::ZERO;
+        self.prompt_visible = prompt_visible;
+    }
+
+    pub fn tick(&mut self, dt: Duration) {
+        self.quiet = self.quiet.saturating_add(dt);
+        let waiting = self.quiet >= AGENT_IDLE_AFTER
+            || (self.prompt_visible && self.quiet >= AGENT_PROMPT_SETTLE);
+        match (self.status, waiting) {
+            (AgentStatus::Waiting, false) => {
+                self.status = AgentStatus::Busy;
+                self.busy_for = dt;
+            }
+            (AgentStatus::Busy, false) => {
+                self.busy_for = self.busy_for.saturating_add(dt);
+            }
+            (AgentStatus::Busy, true) => {
+                self.status = AgentStatus::Waiting;
+                self.became_waiting = self.busy_for >= AGENT_MIN_BUSY;
+            }
+            (AgentStatus::Waiting, true) => {}
+        }
+    }
+
+    /// Whether the agent finished a real stretch of work and now waits, since
+    /// the last call.
+    pub fn take_became_waiting(&mut self) -> bool {
+        std::mem::take(&mut self.became_waiting)
+    }
+}
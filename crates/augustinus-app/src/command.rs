@@ -0,0 +1,464 @@
+use augustinus_i18n::{fill, Language, Strings};
+
+use crate::{PaneId, SendSource};
+
+const PANES: &[(&str, PaneId)] = &[("general", PaneId::General), ("agents", PaneId::Agents)];
+const LANGUAGES: &[(&str, Language)] = &[
+    ("en", Language::En),
+    ("fr", Language::Fr),
+    ("ja", Language::Ja),
+];
+
+/// A parsed `:` command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Quit,
+    /// `:help` lists every command; `:help <name>` describes one.
+    Help(Option<String>),
+    FocusStart,
+    FocusStop,
+    Respawn(PaneId),
+    Tab(TabCommand),
+    SendOutput(SendSource),
+    SendText(String),
+    Agent(String),
+    /// `pane` is `None` for the focused terminal.
+    Record { start: bool, pane: Option<PaneId> },
+    /// Switches the UI language and saves it to the config.
+    Lang(Language),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabCommand {
+    New,
+    Close,
+    Next,
+    Prev,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    Empty,
+    UnterminatedQuote,
+    Unknown(String),
+    MissingArgument {
+        command: &'static str,
+        expected: &'static str,
+    },
+    InvalidArgument {
+        command: &'static str,
+        argument: String,
+        expected: &'static str,
+    },
+    UnexpectedArgument {
+        command: &'static str,
+        argument: String,
+    },
+}
+
+impl CommandError {
+    /// The error as shown in the overlay, in the UI language.
+    pub fn message(&self, strings: &Strings) -> String {
+        match self {
+            Self::Empty => strings.error_empty.to_string(),
+            Self::UnterminatedQuote => strings.error_unterminated_quote.to_string(),
+            Self::Unknown(name) => fill(strings.error_unknown, &[&format!("{name:?}")]),
+            Self::MissingArgument { command, expected } => fill(
+                strings.error_missing_argument,
+                &[command, &strings.expected(expected)],
+            ),
+            Self::InvalidArgument {
+                command,
+                argument,
+                expected,
+            } => fill(
+                strings.error_invalid_argument,
+                &[command, &format!("{argument:?}"), &strings.expected(expected)],
+            ),
+            Self::UnexpectedArgument { command, argument } => fill(
+                strings.error_unexpected_argument,
+                &[command, &format!("{argument:?}")],
+            ),
+        }
+    }
+}
+
+/// What an argument accepts, for completion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    Words(&'static [&'static str]),
+    Pane,
+    Agent,
+    Language,
+    Command,
+}
+
+/// One entry of `:help`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub usage: &'static str,
+    pub summary: &'static str,
+    /// Positional arguments, for completion.
+    pub args: &'static [ArgKind],
+}
+
+/// Every `:` command, in `:help` order.
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "quit",
+        aliases: &["q"],
+        usage: "quit",
+        summary: "Leave augustinus",
+        args: &[],
+    },
+    CommandSpec {
+        name: "help",
+        aliases: &["h"],
+        usage: "help [command]",
+        summary: "List commands, or describe one",
+        args: &[ArgKind::Command],
+    },
+    CommandSpec {
+        name: "focus",
+        aliases: &[],
+        usage: "focus start|stop",
+        summary: "Start or stop a focus session",
+        args: &[ArgKind::Words(&["start", "stop"])],
+    },
+    CommandSpec {
+        name: "tab",
+        aliases: &[],
+        usage: "tab new|close|next|prev",
+        summary: "Manage GENERAL tabs",
+        args: &[ArgKind::Words(&["new", "close", "next", "prev"])],
+    },
+    CommandSpec {
+        name: "respawn",
+        aliases: &[],
+        usage: "respawn general|agents",
+        summary: "Restart a terminal's process",
+        args: &[ArgKind::Pane],
+    },
+    CommandSpec {
+        name: "agent",
+        aliases: &[],
+        usage: "agent <name>",
+        summary: "Switch to a configured agent",
+        args: &[ArgKind::Agent],
+    },
+    CommandSpec {
+        name: "send",
+        aliases: &[],
+        usage: "send agents <text>",
+        summary: "Paste text into the agent",
+        args: &[ArgKind::Words(&["agents"])],
+    },
+    CommandSpec {
+        name: "send-output",
+        aliases: &[],
+        usage: "send-output agents [last|screen|<lines>]",
+        summary: "Paste GENERAL output into the agent",
+        args: &[ArgKind::Words(&["agents"]), ArgKind::Words(&["last", "screen"])],
+    },
+    CommandSpec {
+        name: "record",
+        aliases: &[],
+        usage: "record start|stop [general|agents]",
+        summary: "Record a terminal to an asciinema file",
+        args: &[ArgKind::Words(&["start", "stop"]), ArgKind::Pane],
+    },
+    CommandSpec {
+        name: "lang",
+        aliases: &[],
+        usage: "lang en|fr|ja",
+        summary: "Set the interface language",
+        args: &[ArgKind::Language],
+    },
+];
+
+/// The command called `name` or one of its aliases.
+pub fn find_command(name: &str) -> Option<&'static CommandSpec> {
+    COMMANDS
+        .iter()
+        .find(|spec| spec.name == name || spec.aliases.contains(&name))
+}
+
+/// `:help` text: every command with its summary, or the details of `topic`.
+pub fn help_lines(topic: Option<&str>, strings: &Strings) -> Result<Vec<String>, CommandError> {
+    let summary = |spec: &CommandSpec| strings.command_summary(spec.name).unwrap_or(spec.summary);
+    let Some(topic) = topic else {
+        let width = COMMANDS.iter().map(|spec| spec.usage.len()).max().unwrap_or(0);
+        return Ok(COMMANDS
+            .iter()
+            .map(|spec| format!(":{:<width$}  {}", spec.usage, summary(spec)))
+            .collect());
+    };
+    let spec = find_command(topic).ok_or_else(|| CommandError::Unknown(topic.to_string()))?;
+    let mut lines = vec![format!(":{}", spec.usage), summary(spec).to_string()];
+    if !spec.aliases.is_empty() {
+        let aliases: Vec<String> = spec.aliases.iter().map(|alias| format!(":{alias}")).collect();
+        lines.push(fill(strings.command_aliases, &[&aliases.join(", ")]));
+    }
+    Ok(lines)
+}
+
+impl Command {
+    pub fn parse(input: &str) -> Result<Self, CommandError> {
+        if let Some((name, rest)) = first_word(input)
+            && find_command(name).is_some_and(|spec| spec.name == "send")
+        {
+            return parse_send(rest);
+        }
+        let mut words = split_words(input)?.into_iter();
+        let name = words.next().ok_or(CommandError::Empty)?;
+        let spec = find_command(&name).ok_or(CommandError::Unknown(name))?;
+        let mut args = Args {
+            command: spec.name,
+            words,
+        };
+        let command = match spec.name {
+            "quit" => Self::Quit,
+            "help" => Self::Help(args.optional()),
+            "focus" => args.choice(
+                "start or stop",
+                &[("start", Self::FocusStart), ("stop", Self::FocusStop)],
+            )?,
+            "tab" => Self::Tab(args.choice(
+                "new, close, next or prev",
+                &[
+                    ("new", TabCommand::New),
+                    ("close", TabCommand::Close),
+                    ("next", TabCommand::Next),
+                    ("prev", TabCommand::Prev),
+                ],
+            )?),
+            "respawn" => Self::Respawn(args.pane()?),
+            "agent" => Self::Agent(args.required("an agent name")?),
+            "send-output" => {
+                args.choice("agents", &[("agents", ())])?;
+                let source = match args.optional() {
+                    None => SendSource::LastCommand,
+                    Some(word) => match word.as_str() {
+                        "last" => SendSource::LastCommand,
+                        "screen" => SendSource::Screen,
+                        _ => match word.parse::<usize>() {
+                            Ok(n) if n > 0 => SendSource::Lines(n),
+                            _ => return Err(args.invalid(word, "last, screen or a line count")),
+                        },
+                    },
+                };
+                Self::SendOutput(source)
+            }
+            "record" => {
+                let start = args.choice("start or stop", &[("start", true), ("stop", false)])?;
+                let pane = if args.has_more() {
+                    Some(args.pane()?)
+                } else {
+                    None
+                };
+                Self::Record { start, pane }
+            }
+            "lang" => Self::Lang(args.choice("en, fr or ja", LANGUAGES)?),
+            _ => return Err(CommandError::Unknown(spec.name.to_string())),
+        };
+        args.finish()?;
+        Ok(command)
+    }
+}
+
+/// The arguments after a command's name.
+struct Args {
+    command: &'static str,
+    words: std::vec::IntoIter<String>,
+}
+
+impl Args {
+    fn optional(&mut self) -> Option<String> {
+        self.words.next()
+    }
+
+    fn has_more(&self) -> bool {
+        self.words.len() > 0
+    }
+
+    fn required(&mut self, expected: &'static str) -> Result<String, CommandError> {
+        self.words.next().ok_or_else(|| self.missing(expected))
+    }
+
+    fn choice<T: Clone>(
+        &mut self,
+        expected: &'static str,
+        choices: &[(&str, T)],
+    ) -> Result<T, CommandError> {
+        let word = self.required(expected)?;
+        choices
+            .iter()
+            .find(|(name, _)| *name == word)
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| self.invalid(word, expected))
+    }
+
+    fn pane(&mut self) -> Result<PaneId, CommandError> {
+        self.choice("general or agents", PANES)
+    }
+
+    fn missing(&self, expected: &'static str) -> CommandError {
+        CommandError::MissingArgument {
+            command: self.command,
+            expected,
+        }
+    }
+
+    fn invalid(&self, argument: String, expected: &'static str) -> CommandError {
+        CommandError::InvalidArgument {
+            command: self.command,
+            argument,
+            expected,
+        }
+    }
+
+    fn finish(mut self) -> Result<(), CommandError> {
+        match self.words.next() {
+            Some(argument) => Err(CommandError::UnexpectedArgument {
+                command: self.command,
+                argument,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Completions for the word that ends at byte `cursor` of `input`: where
+/// that word starts, and the candidates it is a prefix of. `agents` are the
+/// configured agent names.
+pub fn complete(input: &str, cursor: usize, agents: &[String]) -> (usize, Vec<String>) {
+    let before = input.get(..cursor).unwrap_or(input);
+    let start = before
+        .char_indices()
+        .rev()
+        .find(|(_, ch)| ch.is_whitespace())
+        .map_or(0, |(index, ch)| index + ch.len_utf8());
+    let prefix = &before[start..];
+    let mut previous = before[..start].split_whitespace();
+    let candidates: Vec<String> = match previous.next() {
+        None => COMMANDS.iter().map(|spec| spec.name.to_string()).collect(),
+        Some(name) => {
+            let position = previous.count();
+            match find_command(name).and_then(|spec| spec.args.get(position)) {
+                Some(ArgKind::Words(words)) => words.iter().map(|word| word.to_string()).collect(),
+                Some(ArgKind::Pane) => PANES.iter().map(|(name, _)| name.to_string()).collect(),
+                Some(ArgKind::Language) => {
+                    LANGUAGES.iter().map(|(name, _)| name.to_string()).collect()
+                }
+                Some(ArgKind::Command) => {
+                    COMMANDS.iter().map(|spec| spec.name.to_string()).collect()
+                }
+                Some(ArgKind::Agent) => agents
+                    .iter()
+                    .map(|name| {
+                        if name.contains(char::is_whitespace) {
+                            format!("\"{name}\"")
+                        } else {
+                            name.clone()
+                        }
+                    })
+                    .collect(),
+                None => Vec::new(),
+            }
+        }
+    };
+    let candidates = candidates
+        .into_iter()
+        .filter(|candidate| {
+            candidate.starts_with(prefix) || candidate.trim_start_matches('"').starts_with(prefix)
+        })
+        .collect();
+    (start, candidates)
+}
+
+/// `send agents <text>`, given what follows `send`. The text is for the
+/// agent, so it is taken as typed, quotes and spacing kept, not parsed.
+fn parse_send(rest: &str) -> Result<Command, CommandError> {
+    let missing = |expected| CommandError::MissingArgument {
+        command: "send",
+        expected,
+    };
+    let (target, text) = first_word(rest).ok_or(missing("agents"))?;
+    if target != "agents" {
+        return Err(CommandError::InvalidArgument {
+            command: "send",
+            argument: target.to_string(),
+            expected: "agents",
+        });
+    }
+    let text = text.trim_start();
+    if text.trim().is_empty() {
+        return Err(missing("text to send"));
+    }
+    Ok(Command::SendText(text.to_string()))
+}
+
+/// The first whitespace-separated word of `input` and what follows it.
+fn first_word(input: &str) -> Option<(&str, &str)> {
+    let input = input.trim_start();
+    let end = input.find(char::is_whitespace).unwrap_or(input.len());
+    (end > 0).then(|| input.split_at(end))
+}
+
+/// Splits on whitespace like a shell: single quotes keep everything
+/// literally, double quotes keep whitespace, and a backslash escapes the
+/// next character outside single quotes.
+fn split_words(input: &str) -> Result<Vec<String>, CommandError> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = input.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            ch if ch.is_whitespace() => {
+                words.extend(word.take());
+            }
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(ch) => word.push(ch),
+                        None => return Err(CommandError::UnterminatedQuote),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(ch) => word.push(ch),
+                            None => return Err(CommandError::UnterminatedQuote),
+                        },
+                        Some(ch) => word.push(ch),
+                        None => return Err(CommandError::UnterminatedQuote),
+                    }
+                }
+            }
+            '\\' => {
+                let word = word.get_or_insert_with(String::new);
+                if let Some(ch) = chars.next() {
+                    word.push(ch);
+                }
+            }
+            ch => word.get_or_insert_with(String::new).push(ch),
+        }
+    }
+    words.extend(word);
+    Ok(words)
+}
+
+/// What the command overlay shows under the prompt once a command ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandFeedback {
+    Error(String),
+    Output(Vec<String>),
+}
@@ -0,0 +1,238 @@
+/// Lines of `:` history kept, in memory and when loading from the store.
+pub const COMMAND_HISTORY_LEN: usize = 500;
+
+/// Candidates shown under the prompt after an ambiguous Tab.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// Byte offset of the word being completed.
+    pub start: usize,
+    pub candidates: Vec<String>,
+    /// Index into `candidates` once Tab cycles through them.
+    pub selected: Option<usize>,
+}
+
+/// Cursor, history and completion state of the `:` prompt. The text itself
+/// stays in `AppState::command`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CommandLine {
+    /// Byte offset into the text.
+    cursor: usize,
+    /// Oldest first.
+    history: Vec<String>,
+    /// Index into `history` while recalling, with the text typed before.
+    recall: Option<(usize, String)>,
+    completion: Option<Completion>,
+    /// Submitted since the runtime last saved them.
+    unsaved: Vec<String>,
+}
+
+impl CommandLine {
+    /// Replaces the history, e.g. with the one kept in the store.
+    pub fn set_history(&mut self, history: Vec<String>) {
+        self.history = history;
+        self.trim_history();
+        self.recall = None;
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Commands submitted since the last call, for the runtime to save.
+    pub fn take_unsaved(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.unsaved)
+    }
+
+    /// Byte offset of the cursor in `text`.
+    pub fn cursor(&self, text: &str) -> usize {
+        let mut cursor = self.cursor.min(text.len());
+        while !text.is_char_boundary(cursor) {
+            cursor -= 1;
+        }
+        cursor
+    }
+
+    pub fn completion(&self) -> Option<&Completion> {
+        self.completion.as_ref()
+    }
+
+    /// Clears cursor, recall and completion for a fresh prompt.
+    pub fn reset(&mut self) {
+        self.cursor = 0;
+        self.recall = None;
+        self.completion = None;
+    }
+
+    /// Moves the cursor after the last character.
+    pub fn end(&mut self, text: &str) {
+        self.cursor = text.len();
+        self.completion = None;
+    }
+
+    pub fn home(&mut self) {
+        self.cursor = 0;
+        self.completion = None;
+    }
+
+    pub fn left(&mut self, text: &str) {
+        let cursor = self.cursor(text);
+        self.cursor = text[..cursor]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(index, _)| index);
+        self.completion = None;
+    }
+
+    pub fn right(&mut self, text: &str) {
+        let cursor = self.cursor(text);
+        self.cursor = text[cursor..]
+            .chars()
+            .next()
+            .map_or(cursor, |ch| cursor + ch.len_utf8());
+        self.completion = None;
+    }
+
+    pub fn insert(&mut self, text: &mut String, ch: char) {
+        let cursor = self.cursor(text);
+        text.insert(cursor, ch);
+        self.cursor = cursor + ch.len_utf8();
+        self.completion = None;
+    }
+
+    /// Deletes the character before the cursor.
+    pub fn backspace(&mut self, text: &mut String) {
+        let cursor = self.cursor(text);
+        self.left(text);
+        text.replace_range(self.cursor..cursor, "");
+    }
+
+    /// Deletes the word before the cursor, like Ctrl-W in a shell.
+    pub fn delete_word(&mut self, text: &mut String) {
+        let cursor = self.cursor(text);
+        let before = text[..cursor].trim_end();
+        let start = before
+            .char_indices()
+            .rev()
+            .find(|(_, ch)| ch.is_whitespace())
+            .map_or(0, |(index, ch)| index + ch.len_utf8());
+        text.replace_range(start..cursor, "");
+        self.cursor = start;
+        self.completion = None;
+    }
+
+    /// Replaces `text` with the previous history entry.
+    pub fn history_prev(&mut self, text: &mut String) {
+        let index = match &self.recall {
+            Some((0, _)) => return,
+            Some((index, _)) => index - 1,
+            None if self.history.is_empty() => return,
+            None => {
+                self.recall = Some((self.history.len(), text.clone()));
+                self.history.len() - 1
+            }
+        };
+        self.recall_entry(text, index);
+    }
+
+    /// Replaces `text` with the next history entry, or what was typed
+    /// before recalling.
+    pub fn history_next(&mut self, text: &mut String) {
+        let Some((index, _)) = &self.recall else {
+            return;
+        };
+        let index = index + 1;
+        if index < self.history.len() {
+            self.recall_entry(text, index);
+        } else if let Some((_, draft)) = self.recall.take() {
+            *text = draft;
+            self.end(text);
+            self.completion = None;
+        }
+    }
+
+    fn recall_entry(&mut self, text: &mut String, index: usize) {
+        if let Some((recalled, _)) = self.recall.as_mut() {
+            *recalled = index;
+        }
+        *text = self.history[index].clone();
+        self.end(text);
+        self.completion = None;
+    }
+
+    /// Completes the word before the cursor from `candidates`, which start
+    /// at byte `start`. One candidate is inserted with a space after it;
+    /// several insert their common prefix and open the popup, and further
+    /// calls cycle through them (backwards with `reverse`).
+    pub fn complete(
+        &mut self,
+        text: &mut String,
+        start: usize,
+        candidates: Vec<String>,
+        reverse: bool,
+    ) {
+        let end = self.cursor(text);
+        if let Some(completion) = self.completion.as_mut() {
+            let len = completion.candidates.len();
+            let selected = match (completion.selected, reverse) {
+                (None, false) => 0,
+                (None, true) => len - 1,
+                (Some(index), false) => (index + 1) % len,
+                (Some(index), true) => (index + len - 1) % len,
+            };
+            completion.selected = Some(selected);
+            let start = completion.start;
+            let candidate = &completion.candidates[selected];
+            text.replace_range(start..end, candidate);
+            self.cursor = start + candidate.len();
+            return;
+        }
+        match candidates.as_slice() {
+            [] => {}
+            [only] => {
+                let replacement = format!("{only} ");
+                text.replace_range(start..end, &replacement);
+                self.cursor = start + replacement.len();
+            }
+            [first, rest @ ..] => {
+                let common = rest.iter().fold(first.as_str(), |common, candidate| {
+                    let len = common
+                        .char_indices()
+                        .zip(candidate.chars())
+                        .find(|((_, a), b)| a != b)
+                        .map_or(common.len().min(candidate.len()), |((index, _), _)| index);
+                    &common[..len]
+                });
+                text.replace_range(start..end, common);
+                self.cursor = start + common.len();
+                self.completion = Some(Completion {
+                    start,
+                    candidates,
+                    selected: None,
+                });
+            }
+        }
+    }
+
+    /// Closes the popup; returns `false` if none was open.
+    pub fn cancel_completion(&mut self) -> bool {
+        self.completion.take().is_some()
+    }
+
+    /// Records a submitted command, skipping repeats of the last one.
+    pub fn push_history(&mut self, command: &str) {
+        let command = command.trim();
+        self.recall = None;
+        self.completion = None;
+        if command.is_empty() || self.history.last().is_some_and(|last| last == command) {
+            return;
+        }
+        self.history.push(command.to_string());
+        self.unsaved.push(command.to_string());
+        self.trim_history();
+    }
+
+    fn trim_history(&mut self) {
+        let excess = self.history.len().saturating_sub(COMMAND_HISTORY_LEN);
+        self.history.drain(..excess);
+    }
+}
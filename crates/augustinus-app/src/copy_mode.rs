@@ -0,0 +1,150 @@
+use crate::TerminalScreen;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionKind {
+    Char,
+    Line,
+    Block,
+}
+
+/// A cell in a terminal's history: line `0` is the oldest scrollback line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CopyPos {
+    pub line: usize,
+    pub col: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyMotion {
+    Left,
+    Right,
+    Up,
+    Down,
+    LineStart,
+    LineEnd,
+    HalfPageUp,
+    HalfPageDown,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+}
+
+/// tmux-style copy mode: a cursor over screen and scrollback plus an
+/// optional selection anchored where it was started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyMode {
+    cursor: CopyPos,
+    selection: Option<(SelectionKind, CopyPos)>,
+}
+
+impl CopyMode {
+    pub fn new(cursor: CopyPos) -> Self {
+        Self {
+            cursor,
+            selection: None,
+        }
+    }
+
+    /// Copy mode with a `kind` selection from `anchor` to `cursor`.
+    pub fn with_selection(kind: SelectionKind, anchor: CopyPos, cursor: CopyPos) -> Self {
+        Self {
+            cursor,
+            selection: Some((kind, anchor)),
+        }
+    }
+
+    pub fn cursor(&self) -> CopyPos {
+        self.cursor
+    }
+
+    pub fn selection_kind(&self) -> Option<SelectionKind> {
+        self.selection.map(|(kind, _)| kind)
+    }
+
+    /// Moves the cursor within a history of `lines` × `cols`; `page` is the
+    /// screen height.
+    pub fn apply(&mut self, motion: CopyMotion, lines: usize, cols: u16, page: usize) {
+        let last_line = lines.saturating_sub(1);
+        let last_col = cols.saturating_sub(1);
+        let CopyPos { line, col } = self.cursor;
+        self.cursor = match motion {
+            CopyMotion::Left => CopyPos { line, col: col.saturating_sub(1) },
+            CopyMotion::Right => CopyPos { line, col: (col + 1).min(last_col) },
+            CopyMotion::Up => CopyPos { line: line.saturating_sub(1), col },
+            CopyMotion::Down => CopyPos { line: (line + 1).min(last_line), col },
+            CopyMotion::LineStart => CopyPos { line, col: 0 },
+            CopyMotion::LineEnd => CopyPos { line, col: last_col },
+            CopyMotion::HalfPageUp => CopyPos { line: line.saturating_sub(page / 2), col },
+            CopyMotion::HalfPageDown => CopyPos { line: (line + page / 2).min(last_line), col },
+            CopyMotion::PageUp => CopyPos { line: line.saturating_sub(page), col },
+            CopyMotion::PageDown => CopyPos { line: (line + page).min(last_line), col },
+            CopyMotion::Top => CopyPos { line: 0, col: 0 },
+            CopyMotion::Bottom => CopyPos { line: last_line, col },
+        };
+    }
+
+    /// Starts a selection of `kind` at the cursor. Repeating the same kind
+    /// clears it; another kind keeps the anchor and changes the shape.
+    pub fn toggle_selection(&mut self, kind: SelectionKind) {
+        self.selection = match self.selection {
+            Some((current, _)) if current == kind => None,
+            Some((_, anchor)) => Some((kind, anchor)),
+            None => Some((kind, self.cursor)),
+        };
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection = None;
+    }
+
+    pub fn is_selected(&self, pos: CopyPos) -> bool {
+        let Some((kind, anchor)) = self.selection else {
+            return false;
+        };
+        let (start, end) = ordered(anchor, self.cursor);
+        if pos.line < start.line || pos.line > end.line {
+            return false;
+        }
+        match kind {
+            SelectionKind::Line => true,
+            SelectionKind::Char => pos >= start && pos <= end,
+            SelectionKind::Block => {
+                let (left, right) = column_span(anchor, self.cursor);
+                pos.col >= left && pos.col <= right
+            }
+        }
+    }
+
+    /// The selected text from `history`, one line per row with trailing
+    /// blanks trimmed. Empty when nothing is selected.
+    pub fn selected_text(&self, history: &TerminalScreen) -> String {
+        let Some((kind, anchor)) = self.selection else {
+            return String::new();
+        };
+        let (start, end) = ordered(anchor, self.cursor);
+        let (left, right) = column_span(anchor, self.cursor);
+        let mut lines = Vec::new();
+        for line in start.line..=end.line {
+            let Ok(row) = u16::try_from(line) else { break };
+            let (from, to) = match kind {
+                SelectionKind::Line => (0, history.cols()),
+                SelectionKind::Block => (left, right + 1),
+                SelectionKind::Char => (
+                    if line == start.line { start.col } else { 0 },
+                    if line == end.line { end.col + 1 } else { history.cols() },
+                ),
+            };
+            lines.push(history.row_text_range(row, from, to));
+        }
+        lines.join("\n")
+    }
+}
+
+fn ordered(a: CopyPos, b: CopyPos) -> (CopyPos, CopyPos) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+fn column_span(a: CopyPos, b: CopyPos) -> (u16, u16) {
+    (a.col.min(b.col), a.col.max(b.col))
+}
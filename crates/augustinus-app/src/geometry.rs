@@ -0,0 +1,169 @@
+use crate::PaneId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PaneRect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl PaneRect {
+    pub fn contains(&self, col: u16, row: u16) -> bool {
+        col >= self.x
+            && row >= self.y
+            && col < self.x.saturating_add(self.width)
+            && row < self.y.saturating_add(self.height)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ScreenViewport {
+    pane: PaneId,
+    area: PaneRect,
+    first_row: u16,
+}
+
+/// Where panes and their embedded terminals ended up on the last frame, as
+/// recorded by the renderer. Used to route mouse events.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PaneLayout {
+    panes: Vec<(PaneId, PaneRect)>,
+    screens: Vec<ScreenViewport>,
+}
+
+impl PaneLayout {
+    pub fn clear(&mut self) {
+        self.panes.clear();
+        self.screens.clear();
+    }
+
+    pub fn set_pane(&mut self, pane: PaneId, area: PaneRect) {
+        self.panes.retain(|(id, _)| *id != pane);
+        self.panes.push((pane, area));
+    }
+
+    /// Records the terminal area of a PTY pane; `first_row` is the screen row
+    /// drawn at the top of `area`.
+    pub fn set_screen(&mut self, pane: PaneId, area: PaneRect, first_row: u16) {
+        self.screens.retain(|s| s.pane != pane);
+        self.screens.push(ScreenViewport {
+            pane,
+            area,
+            first_row,
+        });
+    }
+
+    pub fn pane_area(&self, pane: PaneId) -> Option<PaneRect> {
+        self.panes
+            .iter()
+            .find(|(id, _)| *id == pane)
+            .map(|(_, area)| *area)
+    }
+
+    pub fn pane_at(&self, col: u16, row: u16) -> Option<PaneId> {
+        self.panes
+            .iter()
+            .find(|(_, area)| area.contains(col, row))
+            .map(|(id, _)| *id)
+    }
+
+    /// Translates a host terminal position into `(col, row)` on the pane's
+    /// PTY screen, if the position falls on the embedded terminal.
+    pub fn screen_cell_at(&self, pane: PaneId, col: u16, row: u16) -> Option<(u16, u16)> {
+        let viewport = self.screens.iter().find(|s| s.pane == pane)?;
+        if !viewport.area.contains(col, row) {
+            return None;
+        }
+        Some((
+            col - viewport.area.x,
+            row - viewport.area.y + viewport.first_row,
+        ))
+    }
+}
+
+/// Percent points a resize moves a split by.
+pub const SPLIT_STEP: u16 = 5;
+/// Splits stay within this range so no pane disappears.
+pub const SPLIT_MIN: u16 = 10;
+pub const SPLIT_MAX: u16 = 90;
+
+/// How the 2x2 grid is split, in percent: the top row's share of the
+/// height, and the left pane's share of the width in each row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitRatios {
+    /// MOTIVATION and GENERAL over AI AGENTS and STATS.
+    pub rows: u16,
+    /// MOTIVATION beside GENERAL.
+    pub top: u16,
+    /// AI AGENTS beside STATS.
+    pub bottom: u16,
+}
+
+impl Default for SplitRatios {
+    fn default() -> Self {
+        Self {
+            rows: 50,
+            top: 50,
+            bottom: 50,
+        }
+    }
+}
+
+impl SplitRatios {
+    pub fn new(rows: u16, top: u16, bottom: u16) -> Self {
+        let clamp = |percent: u16| percent.clamp(SPLIT_MIN, SPLIT_MAX);
+        Self {
+            rows: clamp(rows),
+            top: clamp(top),
+            bottom: clamp(bottom),
+        }
+    }
+
+    /// Grows `pane` by `width` and `height` percent points; negative values
+    /// shrink it. The neighbours sharing the split give or take the space.
+    pub fn resize(&mut self, pane: PaneId, width: i16, height: i16) {
+        let shift = |percent: u16, by: i16| {
+            (percent as i16 + by).clamp(SPLIT_MIN as i16, SPLIT_MAX as i16) as u16
+        };
+        match pane {
+            PaneId::Motivation => self.top = shift(self.top, width),
+            PaneId::General => self.top = shift(self.top, -width),
+            PaneId::Agents => self.bottom = shift(self.bottom, width),
+            PaneId::Stats => self.bottom = shift(self.bottom, -width),
+        }
+        match pane {
+            PaneId::Motivation | PaneId::General => self.rows = shift(self.rows, height),
+            PaneId::Agents | PaneId::Stats => self.rows = shift(self.rows, -height),
+        }
+    }
+
+    /// Where `pane` goes when the grid fills `area`. The renderer and the
+    /// PTY sizes both come from here, so they always agree.
+    pub fn pane_rect(&self, area: PaneRect, pane: PaneId) -> PaneRect {
+        let top_height = share(area.height, self.rows);
+        let (y, height) = match pane {
+            PaneId::Motivation | PaneId::General => (area.y, top_height),
+            PaneId::Agents | PaneId::Stats => (area.y + top_height, area.height - top_height),
+        };
+        let left_width = match pane {
+            PaneId::Motivation | PaneId::General => share(area.width, self.top),
+            PaneId::Agents | PaneId::Stats => share(area.width, self.bottom),
+        };
+        let (x, width) = match pane {
+            PaneId::Motivation | PaneId::Agents => (area.x, left_width),
+            PaneId::General | PaneId::Stats => (area.x + left_width, area.width - left_width),
+        };
+        PaneRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// `percent` of `total`, rounded.
+fn share(total: u16, percent: u16) -> u16 {
+    ((u32::from(total) * u32::from(percent) + 50) / 100) as u16
+}
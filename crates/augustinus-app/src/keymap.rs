@@ -0,0 +1,590 @@
+use std::{collections::BTreeMap, fmt};
+
+use augustinus_i18n::{fill, Strings};
+
+use crate::Command;
+
+/// A key without its modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Esc,
+    Tab,
+    BackTab,
+    Backspace,
+    Delete,
+    Insert,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    F(u8),
+}
+
+impl Key {
+    /// A single character, or a name like `Enter`, `PgUp` or `F5`.
+    fn parse(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next()) {
+            return Some(Self::Char(ch));
+        }
+        let lower = name.to_ascii_lowercase();
+        let key = match lower.as_str() {
+            "space" => Self::Char(' '),
+            "enter" | "return" | "cr" => Self::Enter,
+            "esc" | "escape" => Self::Esc,
+            "tab" => Self::Tab,
+            "backtab" => Self::BackTab,
+            "backspace" | "bs" => Self::Backspace,
+            "delete" | "del" => Self::Delete,
+            "insert" | "ins" => Self::Insert,
+            "left" => Self::Left,
+            "right" => Self::Right,
+            "up" => Self::Up,
+            "down" => Self::Down,
+            "home" => Self::Home,
+            "end" => Self::End,
+            "pgup" | "pageup" => Self::PageUp,
+            "pgdn" | "pagedown" => Self::PageDown,
+            _ => {
+                let number: u8 = lower.strip_prefix('f')?.parse().ok()?;
+                if !(1..=24).contains(&number) {
+                    return None;
+                }
+                Self::F(number)
+            }
+        };
+        Some(key)
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Char(' ') => write!(f, "Space"),
+            Self::Char(ch) => write!(f, "{ch}"),
+            Self::Enter => write!(f, "Enter"),
+            Self::Esc => write!(f, "Esc"),
+            Self::Tab => write!(f, "Tab"),
+            Self::BackTab => write!(f, "Shift-Tab"),
+            Self::Backspace => write!(f, "Backspace"),
+            Self::Delete => write!(f, "Del"),
+            Self::Insert => write!(f, "Ins"),
+            Self::Left => write!(f, "Left"),
+            Self::Right => write!(f, "Right"),
+            Self::Up => write!(f, "Up"),
+            Self::Down => write!(f, "Down"),
+            Self::Home => write!(f, "Home"),
+            Self::End => write!(f, "End"),
+            Self::PageUp => write!(f, "PgUp"),
+            Self::PageDown => write!(f, "PgDn"),
+            Self::F(number) => write!(f, "F{number}"),
+        }
+    }
+}
+
+/// A key with its modifiers, written like `Ctrl-a`, `Alt-Enter` or
+/// `Shift-PgUp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyChord {
+    pub key: Key,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl KeyChord {
+    /// Shift is folded into the key where the key already carries it: the
+    /// case of a letter, and Tab becoming BackTab. Without Shift, a letter
+    /// with Ctrl is lowercase, since terminals send Ctrl-A as Ctrl-a.
+    pub fn new(key: Key, ctrl: bool, alt: bool, shift: bool) -> Self {
+        let (key, shift) = match key {
+            Key::Char(ch) if shift => (Key::Char(ch.to_ascii_uppercase()), false),
+            Key::Char(ch) if ctrl => (Key::Char(ch.to_ascii_lowercase()), false),
+            Key::Char(_) | Key::BackTab => (key, false),
+            Key::Tab if shift => (Key::BackTab, false),
+            _ => (key, shift),
+        };
+        Self {
+            key,
+            ctrl,
+            alt,
+            shift,
+        }
+    }
+
+    pub fn plain(key: Key) -> Self {
+        Self::new(key, false, false, false)
+    }
+
+    /// Parses `Ctrl-`, `Alt-` and `Shift-` (or `C-`, `M-`, `S-`) prefixes
+    /// followed by a key.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (mut ctrl, mut alt, mut shift) = (false, false, false);
+        let mut rest = text;
+        while let Some((modifier, key)) = rest.split_once('-')
+            && !key.is_empty()
+        {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "c" => ctrl = true,
+                "alt" | "meta" | "m" => alt = true,
+                "shift" | "s" => shift = true,
+                _ => break,
+            }
+            rest = key;
+        }
+        Some(Self::new(Key::parse(rest)?, ctrl, alt, shift))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl-")?;
+        }
+        if self.alt {
+            write!(f, "Alt-")?;
+        }
+        if self.shift {
+            write!(f, "Shift-")?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+/// Chords separated by spaces, e.g. `Ctrl-a f`.
+pub fn parse_key_sequence(text: &str) -> Option<Vec<KeyChord>> {
+    let sequence: Vec<KeyChord> = text
+        .split_whitespace()
+        .map(KeyChord::parse)
+        .collect::<Option<_>>()?;
+    (!sequence.is_empty()).then_some(sequence)
+}
+
+/// Which table of `[keys]` applies to a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyMode {
+    /// App controls: no terminal is locked and the prompt is closed.
+    App,
+    /// A locked terminal; keys that are not bound go to its program.
+    Terminal,
+    /// The `:` prompt; characters that are not bound are typed.
+    Command,
+}
+
+impl KeyMode {
+    /// The `[keys]` table configuring this mode.
+    pub fn table(self) -> &'static str {
+        match self {
+            Self::App => "keys.app",
+            Self::Terminal => "keys.terminal",
+            Self::Command => "keys.command",
+        }
+    }
+}
+
+/// What a key can be bound to by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    FocusLeft,
+    FocusDown,
+    FocusUp,
+    FocusRight,
+    RotateFocus,
+    /// Locks the focused terminal, or makes any other pane fullscreen.
+    Activate,
+    Fullscreen,
+    ExitFullscreen,
+    GrowWidth,
+    ShrinkWidth,
+    GrowHeight,
+    ShrinkHeight,
+    ResetSplits,
+    /// Unlocks the focused terminal.
+    ExitTerminal,
+    CommandMode,
+    Quit,
+    CopyMode,
+    /// Pastes the copy-mode register into the focused terminal.
+    Paste,
+    PromptPrev,
+    PromptNext,
+    SelectOutput,
+    /// Sends the last GENERAL command and its output to the agent.
+    SendOutput,
+    Search,
+    ScrollPageUp,
+    ScrollPageDown,
+    ScrollLineUp,
+    ScrollLineDown,
+    ScrollTop,
+    ScrollBottom,
+    /// Restarts the focused pane's process once it has exited.
+    Respawn,
+    /// Selects the GENERAL tab or agent with this 0-based index.
+    Select(usize),
+    TabNew,
+    TabClose,
+    TabNext,
+    TabPrev,
+    // The `:` prompt.
+    Cancel,
+    Submit,
+    Backspace,
+    DeleteWord,
+    CursorLeft,
+    CursorRight,
+    CursorHome,
+    CursorEnd,
+    HistoryPrev,
+    HistoryNext,
+    Complete,
+    CompleteBack,
+}
+
+impl KeyAction {
+    /// The action named `name` in `[keys]`, e.g. `focus-left` or
+    /// `select-3`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let action = match name {
+            "focus-left" => Self::FocusLeft,
+            "focus-down" => Self::FocusDown,
+            "focus-up" => Self::FocusUp,
+            "focus-right" => Self::FocusRight,
+            "rotate-focus" => Self::RotateFocus,
+            "activate" => Self::Activate,
+            "fullscreen" => Self::Fullscreen,
+            "exit-fullscreen" => Self::ExitFullscreen,
+            "grow-width" => Self::GrowWidth,
+            "shrink-width" => Self::ShrinkWidth,
+            "grow-height" => Self::GrowHeight,
+            "shrink-height" => Self::ShrinkHeight,
+            "reset-splits" => Self::ResetSplits,
+            "exit-terminal" => Self::ExitTerminal,
+            "command-mode" => Self::CommandMode,
+            "quit" => Self::Quit,
+            "copy-mode" => Self::CopyMode,
+            "paste" => Self::Paste,
+            "prompt-prev" => Self::PromptPrev,
+            "prompt-next" => Self::PromptNext,
+            "select-output" => Self::SelectOutput,
+            "send-output" => Self::SendOutput,
+            "search" => Self::Search,
+            "scroll-page-up" => Self::ScrollPageUp,
+            "scroll-page-down" => Self::ScrollPageDown,
+            "scroll-line-up" => Self::ScrollLineUp,
+            "scroll-line-down" => Self::ScrollLineDown,
+            "scroll-top" => Self::ScrollTop,
+            "scroll-bottom" => Self::ScrollBottom,
+            "respawn" => Self::Respawn,
+            "tab-new" => Self::TabNew,
+            "tab-close" => Self::TabClose,
+            "tab-next" => Self::TabNext,
+            "tab-prev" => Self::TabPrev,
+            "cancel" => Self::Cancel,
+            "submit" => Self::Submit,
+            "backspace" => Self::Backspace,
+            "delete-word" => Self::DeleteWord,
+            "cursor-left" => Self::CursorLeft,
+            "cursor-right" => Self::CursorRight,
+            "cursor-home" => Self::CursorHome,
+            "cursor-end" => Self::CursorEnd,
+            "history-prev" => Self::HistoryPrev,
+            "history-next" => Self::HistoryNext,
+            "complete" => Self::Complete,
+            "complete-back" => Self::CompleteBack,
+            _ => {
+                let number: usize = name.strip_prefix("select-")?.parse().ok()?;
+                if !(1..=9).contains(&number) {
+                    return None;
+                }
+                Self::Select(number - 1)
+            }
+        };
+        Some(action)
+    }
+
+    pub fn works_in(self, mode: KeyMode) -> bool {
+        match self {
+            Self::Activate => mode == KeyMode::App,
+            Self::ExitTerminal => mode == KeyMode::Terminal,
+            Self::Cancel
+            | Self::Submit
+            | Self::Backspace
+            | Self::DeleteWord
+            | Self::CursorLeft
+            | Self::CursorRight
+            | Self::CursorHome
+            | Self::CursorEnd
+            | Self::HistoryPrev
+            | Self::HistoryNext
+            | Self::Complete
+            | Self::CompleteBack => mode == KeyMode::Command,
+            _ => mode != KeyMode::Command,
+        }
+    }
+}
+
+/// What a key sequence runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Binding {
+    Action(KeyAction),
+    /// A command line, run as if submitted at the `:` prompt.
+    Command(String),
+}
+
+/// The outcome of one key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyMatch {
+    Bound(Binding),
+    /// The keys so far start a longer sequence.
+    Pending,
+    /// No sequence starts with these keys; they are handed back.
+    Unbound(Vec<KeyChord>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapError {
+    InvalidKeys {
+        mode: KeyMode,
+        keys: String,
+    },
+    InvalidBinding {
+        mode: KeyMode,
+        keys: String,
+        binding: String,
+    },
+    /// Two sequences are equal, or one starts the other.
+    Conflict {
+        mode: KeyMode,
+        keys: String,
+        other: String,
+    },
+}
+
+impl KeymapError {
+    /// The error as shown in the overlay, in the UI language.
+    pub fn message(&self, strings: &Strings) -> String {
+        match self {
+            Self::InvalidKeys { mode, keys } => fill(
+                strings.error_keys_invalid,
+                &[&mode.table(), &format!("{keys:?}")],
+            ),
+            Self::InvalidBinding {
+                mode,
+                keys,
+                binding,
+            } => fill(
+                strings.error_keys_binding,
+                &[&mode.table(), &format!("{keys:?}"), &format!("{binding:?}")],
+            ),
+            Self::Conflict { mode, keys, other } => fill(
+                strings.error_keys_conflict,
+                &[&mode.table(), &format!("{keys:?}"), &format!("{other:?}")],
+            ),
+        }
+    }
+}
+
+/// Bindings used unless `[keys]` replaces them.
+const DEFAULT_BINDINGS: &[(KeyMode, &str, &str)] = &[
+    (KeyMode::App, "h", "focus-left"),
+    (KeyMode::App, "j", "focus-down"),
+    (KeyMode::App, "k", "focus-up"),
+    (KeyMode::App, "l", "focus-right"),
+    (KeyMode::App, "Tab", "rotate-focus"),
+    (KeyMode::App, "Enter", "activate"),
+    (KeyMode::App, "Esc", "exit-fullscreen"),
+    (KeyMode::App, "L", "grow-width"),
+    (KeyMode::App, "H", "shrink-width"),
+    (KeyMode::App, "K", "grow-height"),
+    (KeyMode::App, "J", "shrink-height"),
+    (KeyMode::App, "=", "reset-splits"),
+    (KeyMode::App, ":", "command-mode"),
+    (KeyMode::App, "Ctrl-c", "quit"),
+    (KeyMode::App, "[", "copy-mode"),
+    (KeyMode::App, "p", "paste"),
+    (KeyMode::App, "{", "prompt-prev"),
+    (KeyMode::App, "}", "prompt-next"),
+    (KeyMode::App, "o", "select-output"),
+    (KeyMode::App, "s", "send-output"),
+    (KeyMode::App, "/", "search"),
+    (KeyMode::App, "PgUp", "scroll-page-up"),
+    (KeyMode::App, "r", "respawn"),
+    (KeyMode::App, "1", "select-1"),
+    (KeyMode::App, "2", "select-2"),
+    (KeyMode::App, "3", "select-3"),
+    (KeyMode::App, "4", "select-4"),
+    (KeyMode::App, "5", "select-5"),
+    (KeyMode::App, "6", "select-6"),
+    (KeyMode::App, "7", "select-7"),
+    (KeyMode::App, "8", "select-8"),
+    (KeyMode::App, "9", "select-9"),
+    (KeyMode::Terminal, "Esc", "exit-terminal"),
+    (KeyMode::Terminal, "Shift-PgUp", "scroll-page-up"),
+    (KeyMode::Terminal, "Shift-PgDn", "scroll-page-down"),
+    (KeyMode::Command, "Esc", "cancel"),
+    (KeyMode::Command, "Enter", "submit"),
+    (KeyMode::Command, "Backspace", "backspace"),
+    (KeyMode::Command, "Left", "cursor-left"),
+    (KeyMode::Command, "Right", "cursor-right"),
+    (KeyMode::Command, "Home", "cursor-home"),
+    (KeyMode::Command, "End", "cursor-end"),
+    (KeyMode::Command, "Up", "history-prev"),
+    (KeyMode::Command, "Down", "history-next"),
+    (KeyMode::Command, "Tab", "complete"),
+    (KeyMode::Command, "Shift-Tab", "complete-back"),
+    (KeyMode::Command, "Ctrl-a", "cursor-home"),
+    (KeyMode::Command, "Ctrl-e", "cursor-end"),
+    (KeyMode::Command, "Ctrl-w", "delete-word"),
+];
+
+/// Key sequences bound in each mode, and the keys typed so far of one
+/// that is not finished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    app: BTreeMap<Vec<KeyChord>, Binding>,
+    terminal: BTreeMap<Vec<KeyChord>, Binding>,
+    command: BTreeMap<Vec<KeyChord>, Binding>,
+    pending: Vec<KeyChord>,
+    pending_mode: KeyMode,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut keymap = Self {
+            app: BTreeMap::new(),
+            terminal: BTreeMap::new(),
+            command: BTreeMap::new(),
+            pending: Vec::new(),
+            pending_mode: KeyMode::App,
+        };
+        for &(mode, keys, binding) in DEFAULT_BINDINGS {
+            let sequence = parse_key_sequence(keys).expect("default keys parse");
+            let binding = parse_binding(mode, keys, binding)
+                .ok()
+                .flatten()
+                .expect("default binding parses");
+            keymap.table_mut(mode).insert(sequence, binding);
+        }
+        keymap
+    }
+}
+
+impl Keymap {
+    /// Applies one `[keys]` table of `(keys, binding)` entries. A binding
+    /// is an action name, a `:` command line, or `none` to unbind. Defaults
+    /// overlapping an entry are dropped; entries overlapping each other are
+    /// an error, and then nothing is applied.
+    pub fn configure<'a>(
+        &mut self,
+        mode: KeyMode,
+        entries: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<(), KeymapError> {
+        let mut parsed: Vec<(&str, Vec<KeyChord>, Option<Binding>)> = Vec::new();
+        for (keys, binding) in entries {
+            let sequence = parse_key_sequence(keys).ok_or_else(|| KeymapError::InvalidKeys {
+                mode,
+                keys: keys.to_string(),
+            })?;
+            let binding = parse_binding(mode, keys, binding)?;
+            if let Some((other, ..)) = parsed
+                .iter()
+                .find(|(_, other, _)| overlaps(other, &sequence))
+            {
+                return Err(KeymapError::Conflict {
+                    mode,
+                    keys: keys.to_string(),
+                    other: other.to_string(),
+                });
+            }
+            parsed.push((keys, sequence, binding));
+        }
+
+        let table = self.table_mut(mode);
+        table.retain(|sequence, _| !parsed.iter().any(|(_, user, _)| overlaps(sequence, user)));
+        for (_, sequence, binding) in parsed {
+            if let Some(binding) = binding {
+                table.insert(sequence, binding);
+            }
+        }
+        Ok(())
+    }
+
+    /// The binding of exactly `sequence` in `mode`.
+    pub fn binding(&self, mode: KeyMode, sequence: &[KeyChord]) -> Option<&Binding> {
+        self.table(mode).get(sequence)
+    }
+
+    /// Adds a key press to the pending sequence. Switching modes drops
+    /// what was pending.
+    pub fn feed(&mut self, mode: KeyMode, chord: KeyChord) -> KeyMatch {
+        if self.pending_mode != mode {
+            self.pending.clear();
+            self.pending_mode = mode;
+        }
+        self.pending.push(chord);
+        let table = self.table(mode);
+        if let Some(binding) = table.get(&self.pending).cloned() {
+            self.pending.clear();
+            return KeyMatch::Bound(binding);
+        }
+        if table.keys().any(|sequence| sequence.starts_with(&self.pending)) {
+            return KeyMatch::Pending;
+        }
+        KeyMatch::Unbound(std::mem::take(&mut self.pending))
+    }
+
+    /// Keys typed so far of an unfinished sequence.
+    pub fn pending(&self) -> &[KeyChord] {
+        &self.pending
+    }
+
+    fn table(&self, mode: KeyMode) -> &BTreeMap<Vec<KeyChord>, Binding> {
+        match mode {
+            KeyMode::App => &self.app,
+            KeyMode::Terminal => &self.terminal,
+            KeyMode::Command => &self.command,
+        }
+    }
+
+    fn table_mut(&mut self, mode: KeyMode) -> &mut BTreeMap<Vec<KeyChord>, Binding> {
+        match mode {
+            KeyMode::App => &mut self.app,
+            KeyMode::Terminal => &mut self.terminal,
+            KeyMode::Command => &mut self.command,
+        }
+    }
+}
+
+/// `None` for `none`, which unbinds the keys.
+fn parse_binding(mode: KeyMode, keys: &str, binding: &str) -> Result<Option<Binding>, KeymapError> {
+    let invalid = || KeymapError::InvalidBinding {
+        mode,
+        keys: keys.to_string(),
+        binding: binding.to_string(),
+    };
+    let binding = binding.trim();
+    if binding == "none" {
+        return Ok(None);
+    }
+    if let Some(line) = binding.strip_prefix(':') {
+        if mode == KeyMode::Command || Command::parse(line).is_err() {
+            return Err(invalid());
+        }
+        return Ok(Some(Binding::Command(line.trim().to_string())));
+    }
+    KeyAction::from_name(binding)
+        .filter(|action| action.works_in(mode))
+        .map(|action| Some(Binding::Action(action)))
+        .ok_or_else(invalid)
+}
+
+fn overlaps(a: &[KeyChord], b: &[KeyChord]) -> bool {
+    a.starts_with(b) || b.starts_with(a)
+}
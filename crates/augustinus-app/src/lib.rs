@@ -1,20 +1,58 @@
 mod action;
+mod activity;
+mod agent_picker;
+mod agent_status;
 mod agents_terminal;
+mod command;
+mod command_line;
+mod copy_mode;
 mod focus;
+mod geometry;
+mod keymap;
 mod motivation;
 pub mod motivation_anim;
 pub mod particles;
 mod stats;
 mod panes;
+mod process;
+mod recording;
+mod scroll;
+mod search;
+mod send;
+mod shell;
 mod state;
+mod tabs;
 mod input;
 
 pub use action::Action;
+pub use activity::{ActivityMonitor, ALERT_FLASH};
+pub use agent_picker::AgentPicker;
+pub use agent_status::{
+    AgentStatus, AgentStatusTracker, AGENT_IDLE_AFTER, AGENT_MIN_BUSY, AGENT_PROMPT_SETTLE,
+};
 pub use agents_terminal::AgentsInputMode;
+pub use command::{
+    complete, find_command, help_lines, ArgKind, Command, CommandError, CommandFeedback,
+    CommandSpec, TabCommand, COMMANDS,
+};
+pub use command_line::{CommandLine, Completion, COMMAND_HISTORY_LEN};
+pub use copy_mode::{CopyMode, CopyMotion, CopyPos, SelectionKind};
 pub use focus::FocusState;
+pub use geometry::{PaneLayout, PaneRect, SplitRatios, SPLIT_MAX, SPLIT_MIN, SPLIT_STEP};
+pub use keymap::{
+    parse_key_sequence, Binding, Key, KeyAction, KeyChord, KeyMatch, KeyMode, Keymap, KeymapError,
+};
 pub use motivation::{IdleTracker, MotivationState, Tone};
 pub use motivation::DAILY_FOCUS_GOAL_SECS;
-pub use stats::LocDelta;
+pub use stats::{CommandStats, LocDelta};
 pub use panes::PaneId;
+pub use process::{ProcessLifecycle, ProcessStatus};
+pub use recording::RecordingState;
+pub use scroll::ScrollState;
+pub use search::{SearchMatch, SearchState};
+pub use send::{capture, frame_for_agent, AgentSend, SendSource};
+pub use shell::{FinishedCommand, ShellIntegration};
 pub use state::AppState;
+pub use tabs::{TerminalTab, TerminalTabs};
+pub use augustinus_term::{ShellMark, ShellMarkKind, TermCell, TermColor, TerminalScreen};
 pub use input::GeneralInputMode;
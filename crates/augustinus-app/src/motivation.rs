@@ -1,5 +1,7 @@
 use std::time::Duration;
 
+use augustinus_i18n::Language;
+
 use crate::motivation_anim::{BannerPulse, QuoteTypewriter, Ticker};
 use crate::particles::{ParticleField, Seed};
 
@@ -16,48 +18,14 @@ pub const BANNER_PULSE_PERIOD: Duration = Duration::from_millis(900);
 pub const QUOTE_TYPEWRITER_SPEED_CPS: u32 = 50;
 pub const TICKER_SPEED_CPS: u32 = 18;
 pub const DAILY_FOCUS_GOAL_SECS: u64 = 2 * 60 * 60;
-pub const DEFAULT_TICKER_TEXT: &str = "LOCK IN • NO MERCY • COMPOUND TODAY • STAY DANGEROUS • ONE MORE REP •";
 pub const PARTICLE_COUNT: usize = 48;
 pub const BURST_TTL: Duration = Duration::from_millis(900);
 pub const COOL_DOWN_TTL: Duration = Duration::from_millis(600);
 pub const WAKE_PULSE_TTL: Duration = Duration::from_millis(700);
 
-static BRUTAL_QUOTES: &[&str] = &[
-    "Your biological clock advances whether you code or not.",
-    "Every unfocused hour is stolen from your future children.",
-    "Comfort is the tax you pay for mediocrity.",
-    "Entropy wins unless you resist it.",
-    "Your body is decaying in the background. Work anyway.",
-    "You will not get these years back. Stop negotiating.",
-    "If you drift today, your future self will pay interest.",
-    "The calendar doesn't care about your mood.",
-    "A soft day is a vote for a smaller life.",
-];
-
-static ENCOURAGING_QUOTES: &[&str] = &[
-    "You are building something compounding.",
-    "Consistency is intelligence applied daily.",
-    "One disciplined day changes your trajectory.",
-    "You are closer than you think.",
-    "Keep the chain unbroken. The future is watching.",
-    "Deep work is a promise you keep to your descendants.",
-    "Small clean sessions become a life you recognize.",
-    "Earn tomorrow by honoring the next 60 minutes.",
-];
-
-static EMPEROR_QUOTES: &[&str] = &[
-    "You were not built for small outcomes.",
-    "Discipline is sovereignty.",
-    "AUGUSTINUS does not drift.",
-    "Legacy is constructed in silence.",
-    "Your name can outlive your biology. Act like it.",
-    "The empire is built in the hours nobody applauds.",
-    "You don't need permission to become inevitable.",
-    "Let the work be royal. Let the routine be law.",
-];
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MotivationState {
+    language: Language,
     default_tone: Tone,
     current_tone: Tone,
     rotate_every: Duration,
@@ -78,17 +46,19 @@ pub struct MotivationState {
 
 impl MotivationState {
     pub fn new(idle_threshold: Duration) -> Self {
+        let language = Language::En;
         let default_tone = Tone::Encouraging;
         let current_tone = default_tone;
-        let quote = quote_list(current_tone)
+        let quote = quote_list(language, current_tone)
             .first()
             .copied()
             .unwrap_or("...");
         let mut typewriter = QuoteTypewriter::new(QUOTE_TYPEWRITER_SPEED_CPS);
         typewriter.set_text(quote);
         let mut ticker = Ticker::new(TICKER_SPEED_CPS);
-        ticker.set_text(DEFAULT_TICKER_TEXT);
+        ticker.set_text(augustinus_i18n::strings(language).ticker);
         Self {
+            language,
             default_tone,
             current_tone,
             rotate_every: DEFAULT_ROTATE_EVERY,
@@ -116,6 +86,26 @@ impl MotivationState {
         self.current_tone
     }
 
+    /// Shows the current quote and the ticker in `language`.
+    pub fn set_language(&mut self, language: Language) {
+        if self.language == language {
+            return;
+        }
+        self.language = language;
+        let list = quote_list(language, self.current_tone);
+        self.quote = self
+            .last_quote_index
+            .and_then(|index| list.get(index))
+            .or(list.first())
+            .copied()
+            .unwrap_or("...");
+        self.typewriter.set_text(self.quote);
+        self.ticker.set_text(augustinus_i18n::strings(language).ticker);
+        if self.ticker_width > 0 {
+            self.ticker.fill_window(self.ticker_width, &mut self.ticker_window);
+        }
+    }
+
     pub fn on_activity(&mut self) {
         let was_idle = self.idle.is_idle();
         self.idle.on_activity();
@@ -197,6 +187,16 @@ impl MotivationState {
         self.wake_pulse_remaining
     }
 
+    /// Whether a quote is being typed or a burst is playing. The ticker,
+    /// pulse and drifting particles move all the time and are fine at a
+    /// lower frame rate.
+    pub fn is_animating(&self) -> bool {
+        !self.typewriter.is_done()
+            || self.burst_remaining > Duration::ZERO
+            || self.cool_down_remaining > Duration::ZERO
+            || self.wake_pulse_remaining > Duration::ZERO
+    }
+
     fn set_tone(&mut self, tone: Tone) {
         if self.current_tone == tone {
             return;
@@ -207,7 +207,7 @@ impl MotivationState {
     }
 
     fn rotate_quote(&mut self) {
-        let list = quote_list(self.current_tone);
+        let list = quote_list(self.language, self.current_tone);
         if list.is_empty() {
             self.quote = "...";
             self.last_quote_index = None;
@@ -220,10 +220,11 @@ impl MotivationState {
             None => 0,
         };
 
-        if let Some(last) = self.last_quote_index {
-            if list.len() > 1 && next_index == last {
-                next_index = (next_index + 1) % list.len();
-            }
+        if let Some(last) = self.last_quote_index
+            && list.len() > 1
+            && next_index == last
+        {
+            next_index = (next_index + 1) % list.len();
         }
 
         self.quote = list[next_index];
@@ -232,11 +233,12 @@ impl MotivationState {
     }
 }
 
-fn quote_list(tone: Tone) -> &'static [&'static str] {
+fn quote_list(language: Language, tone: Tone) -> &'static [&'static str] {
+    let strings = augustinus_i18n::strings(language);
     match tone {
-        Tone::Brutal => BRUTAL_QUOTES,
-        Tone::Encouraging => ENCOURAGING_QUOTES,
-        Tone::Emperor => EMPEROR_QUOTES,
+        Tone::Brutal => strings.brutal_quotes,
+        Tone::Encouraging => strings.encouraging_quotes,
+        Tone::Emperor => strings.emperor_quotes,
     }
 }
 
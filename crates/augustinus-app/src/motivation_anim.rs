@@ -77,6 +77,10 @@ impl QuoteTypewriter {
         self.visible
     }
 
+    pub fn is_done(&self) -> bool {
+        self.visible >= self.text.chars().count()
+    }
+
     pub fn visible_text(&self) -> &'static str {
         let len = self.visible.min(self.text.chars().count());
         match self.text.char_indices().nth(len) {
@@ -134,13 +138,13 @@ impl Ticker {
         }
 
         if self.text.is_empty() {
-            out.extend(std::iter::repeat(' ').take(width));
+            out.extend(std::iter::repeat_n(' ', width));
             return;
         }
 
         let len = self.text.chars().count();
         if len == 0 {
-            out.extend(std::iter::repeat(' ').take(width));
+            out.extend(std::iter::repeat_n(' ', width));
             return;
         }
 
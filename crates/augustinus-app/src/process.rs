@@ -0,0 +1,132 @@
+use std::time::Duration;
+
+pub const RESPAWN_BACKOFF_INITIAL: Duration = Duration::from_secs(1);
+pub const RESPAWN_BACKOFF_MAX: Duration = Duration::from_secs(60);
+/// A child that stays up at least this long is no longer considered crash-looping.
+pub const RESPAWN_STABLE_UPTIME: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessStatus {
+    Running,
+    Exited { code: u32 },
+}
+
+/// Tracks the child process behind a PTY pane and decides when it should be
+/// restarted. The runtime owns the actual process and reports spawns/exits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessLifecycle {
+    status: ProcessStatus,
+    auto_restart: bool,
+    uptime: Duration,
+    failures: u32,
+    restart_in: Option<Duration>,
+    restart_requested: bool,
+    /// Why the child could not be started at all.
+    start_error: Option<String>,
+}
+
+impl ProcessLifecycle {
+    pub fn new(auto_restart: bool) -> Self {
+        Self {
+            status: ProcessStatus::Running,
+            auto_restart,
+            uptime: Duration::ZERO,
+            failures: 0,
+            restart_in: None,
+            restart_requested: false,
+            start_error: None,
+        }
+    }
+
+    pub fn status(&self) -> ProcessStatus {
+        self.status
+    }
+
+    pub fn exit_code(&self) -> Option<u32> {
+        match self.status {
+            ProcessStatus::Running => None,
+            ProcessStatus::Exited { code } => Some(code),
+        }
+    }
+
+    /// Time left before an automatic restart, if one is scheduled.
+    pub fn restart_in(&self) -> Option<Duration> {
+        self.restart_in
+    }
+
+    pub fn start_error(&self) -> Option<&str> {
+        self.start_error.as_deref()
+    }
+
+    pub fn on_spawned(&mut self) {
+        self.status = ProcessStatus::Running;
+        self.start_error = None;
+        self.uptime = Duration::ZERO;
+        self.restart_in = None;
+        self.restart_requested = false;
+    }
+
+    pub fn on_exit(&mut self, code: u32) {
+        if self.status != ProcessStatus::Running {
+            return;
+        }
+        self.status = ProcessStatus::Exited { code };
+        if !self.auto_restart || code == 0 {
+            self.failures = 0;
+            return;
+        }
+        if self.uptime >= RESPAWN_STABLE_UPTIME {
+            self.failures = 0;
+        }
+        self.restart_in = Some(backoff_delay(self.failures));
+        self.failures = self.failures.saturating_add(1);
+    }
+
+    /// A restart could not start a new child: tries again after the next
+    /// back-off step instead of leaving the pane down for good.
+    pub fn on_spawn_failed(&mut self) {
+        self.restart_in = Some(backoff_delay(self.failures));
+        self.failures = self.failures.saturating_add(1);
+    }
+
+    /// No child could be started, e.g. its program is missing: shown as an
+    /// exit with code 127, as a shell reports it, and retried only on request.
+    pub fn on_start_failed(&mut self, reason: String) {
+        self.status = ProcessStatus::Exited { code: 127 };
+        self.start_error = Some(reason);
+        self.restart_in = None;
+    }
+
+    /// Manual restart: skips any pending back-off and forgets past failures.
+    pub fn request_restart(&mut self) {
+        self.failures = 0;
+        self.restart_in = None;
+        self.restart_requested = true;
+    }
+
+    pub fn take_restart_request(&mut self) -> bool {
+        std::mem::take(&mut self.restart_requested)
+    }
+
+    pub fn tick(&mut self, dt: Duration) {
+        if self.status == ProcessStatus::Running {
+            self.uptime = self.uptime.saturating_add(dt);
+        }
+        if let Some(remaining) = self.restart_in {
+            let remaining = remaining.saturating_sub(dt);
+            if remaining.is_zero() {
+                self.restart_in = None;
+                self.restart_requested = true;
+            } else {
+                self.restart_in = Some(remaining);
+            }
+        }
+    }
+}
+
+fn backoff_delay(failures: u32) -> Duration {
+    let factor = 1u32.checked_shl(failures.min(16)).unwrap_or(u32::MAX);
+    RESPAWN_BACKOFF_INITIAL
+        .saturating_mul(factor)
+        .min(RESPAWN_BACKOFF_MAX)
+}
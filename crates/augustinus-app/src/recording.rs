@@ -0,0 +1,32 @@
+/// Whether a pane's output is being recorded. Like restarts, start/stop
+/// requests are queued here and carried out by the runtime, which owns the PTY.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RecordingState {
+    active: bool,
+    request: Option<bool>,
+}
+
+impl RecordingState {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn request_start(&mut self) {
+        self.request = Some(true);
+    }
+
+    pub fn request_stop(&mut self) {
+        self.request = Some(false);
+    }
+
+    /// `Some(true)` to start, `Some(false)` to stop.
+    pub fn take_request(&mut self) -> Option<bool> {
+        self.request.take()
+    }
+
+    /// Reported by the runtime after it has acted on a request or the
+    /// recording ended on its own (e.g. a write error).
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+}
@@ -0,0 +1,85 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ScrollState {
+    active: bool,
+    offset: usize,
+    max_offset: usize,
+    page: usize,
+}
+
+impl ScrollState {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Lines scrolled back from the live screen; `0` shows the bottom.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn max_offset(&self) -> usize {
+        self.max_offset
+    }
+
+    /// Called by the runtime after the PTY has processed output; the PTY may
+    /// have moved the offset to keep the viewed lines in place.
+    pub fn sync(&mut self, offset: usize, max_offset: usize, page: usize) {
+        self.max_offset = max_offset;
+        self.page = page.max(1);
+        self.offset = offset.min(max_offset);
+    }
+
+    pub fn exit(&mut self) {
+        self.active = false;
+        self.offset = 0;
+    }
+
+    pub fn line_up(&mut self, lines: usize) {
+        self.active = true;
+        self.offset = self.offset.saturating_add(lines).min(self.max_offset);
+    }
+
+    pub fn line_down(&mut self, lines: usize) {
+        self.offset = self.offset.saturating_sub(lines);
+    }
+
+    pub fn page_up(&mut self) {
+        self.line_up(self.page);
+    }
+
+    pub fn page_down(&mut self) {
+        self.line_down(self.page);
+    }
+
+    pub fn top(&mut self) {
+        self.active = true;
+        self.offset = self.max_offset;
+    }
+
+    /// Scrolls so history line `line` (`0` is the oldest) is on screen,
+    /// centring it when the view has to move.
+    pub fn reveal(&mut self, line: usize) {
+        let top = self.max_offset.saturating_sub(self.offset);
+        if line >= top && line < top + self.page {
+            return;
+        }
+        self.offset = (self.max_offset + self.page / 2)
+            .saturating_sub(line)
+            .min(self.max_offset);
+        if self.offset > 0 {
+            self.active = true;
+        }
+    }
+
+    /// Scrolls so history line `line` is the top row, or as close as the
+    /// history allows.
+    pub fn show_at_top(&mut self, line: usize) {
+        self.offset = self.max_offset.saturating_sub(line);
+        if self.offset > 0 {
+            self.active = true;
+        }
+    }
+
+    pub fn bottom(&mut self) {
+        self.offset = 0;
+    }
+}
@@ -0,0 +1,235 @@
+use regex::RegexBuilder;
+
+use crate::TerminalScreen;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Line in the pane's history: `0` is the oldest scrollback line.
+    pub line: usize,
+    pub start_col: u16,
+    /// Exclusive.
+    pub end_col: u16,
+}
+
+/// `/` search over a terminal's screen and scrollback. The prompt and
+/// navigation live here; the runtime supplies the text through [`run`] when
+/// [`take_run_request`] says it is needed.
+///
+/// [`run`]: SearchState::run
+/// [`take_run_request`]: SearchState::take_run_request
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchState {
+    prompt: Option<String>,
+    regex: bool,
+    query: Option<String>,
+    error: Option<String>,
+    matches: Vec<SearchMatch>,
+    current: Option<usize>,
+    /// Absolute line of history line `0` when the matches were found.
+    origin: u64,
+    run_requested: bool,
+    jump_pending: bool,
+}
+
+impl SearchState {
+    pub fn open_prompt(&mut self) {
+        self.prompt = Some(String::new());
+    }
+
+    pub fn is_prompting(&self) -> bool {
+        self.prompt.is_some()
+    }
+
+    pub fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+
+    pub fn push(&mut self, ch: char) {
+        if let Some(prompt) = &mut self.prompt {
+            prompt.push(ch);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        if let Some(prompt) = &mut self.prompt {
+            prompt.pop();
+        }
+    }
+
+    /// Whether queries are regular expressions rather than literal text.
+    pub fn is_regex(&self) -> bool {
+        self.regex
+    }
+
+    pub fn toggle_regex(&mut self) {
+        self.regex = !self.regex;
+    }
+
+    /// Commits the prompt as the new query. An empty prompt just closes it.
+    pub fn submit(&mut self) {
+        let Some(prompt) = self.prompt.take() else {
+            return;
+        };
+        if prompt.is_empty() {
+            return;
+        }
+        self.query = Some(prompt);
+        self.error = None;
+        self.matches.clear();
+        self.current = None;
+        self.run_requested = true;
+        self.jump_pending = true;
+    }
+
+    pub fn cancel(&mut self) {
+        self.prompt = None;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self {
+            regex: self.regex,
+            ..Self::default()
+        };
+    }
+
+    /// A query has been submitted and matches are being shown.
+    pub fn is_active(&self) -> bool {
+        self.query.is_some()
+    }
+
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    /// Why the last query could not be compiled.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn matches(&self) -> &[SearchMatch] {
+        &self.matches
+    }
+
+    pub fn current_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    pub fn current_match(&self) -> Option<SearchMatch> {
+        self.matches.get(self.current?).copied()
+    }
+
+    /// Moves to the next older match, wrapping to the newest.
+    pub fn next(&mut self) {
+        let Some(current) = self.current else { return };
+        let len = self.matches.len();
+        self.current = Some((current + len - 1) % len);
+        self.jump_pending = true;
+    }
+
+    /// Moves to the next newer match, wrapping to the oldest.
+    pub fn prev(&mut self) {
+        let Some(current) = self.current else { return };
+        self.current = Some((current + 1) % self.matches.len());
+        self.jump_pending = true;
+    }
+
+    /// Set after a submit. Output that arrives later is only searched by the
+    /// next submit, so a busy terminal is not re-searched every frame.
+    pub fn take_run_request(&mut self) -> bool {
+        std::mem::take(&mut self.run_requested)
+    }
+
+    /// Sets the absolute line shown as history line `0`, moving the matches
+    /// up by the lines that left the scrollback and dropping those that
+    /// left with them.
+    pub fn set_origin(&mut self, origin: u64) {
+        let dropped = origin.saturating_sub(self.origin) as usize;
+        self.origin = origin;
+        if dropped == 0 {
+            return;
+        }
+        let before = self.matches.len();
+        self.matches.retain(|found| found.line >= dropped);
+        for found in &mut self.matches {
+            found.line -= dropped;
+        }
+        let removed = before - self.matches.len();
+        self.current = match self.current {
+            Some(current) if current >= removed => Some(current - removed),
+            Some(_) if !self.matches.is_empty() => Some(0),
+            _ => None,
+        };
+    }
+
+    /// The match to scroll into view after a submit or `n`/`N`.
+    pub fn take_jump(&mut self) -> Option<SearchMatch> {
+        if !std::mem::take(&mut self.jump_pending) {
+            return None;
+        }
+        self.current_match()
+    }
+
+    /// Searches every line of `history` for the current query. A fresh query
+    /// starts at the newest match; re-runs keep the selected one.
+    pub fn run(&mut self, history: &TerminalScreen) {
+        let Some(query) = &self.query else { return };
+        let pattern = if self.regex {
+            query.clone()
+        } else {
+            regex::escape(query)
+        };
+        // Smart case: only a query with capitals is case-sensitive.
+        let case_insensitive = !query.chars().any(char::is_uppercase);
+        let re = match RegexBuilder::new(&pattern)
+            .case_insensitive(case_insensitive)
+            .build()
+        {
+            Ok(re) => re,
+            Err(err) => {
+                self.error = Some(err.to_string());
+                self.matches.clear();
+                self.current = None;
+                return;
+            }
+        };
+
+        self.matches.clear();
+        for row in 0..history.rows() {
+            let (text, cols) = row_text_with_columns(history, row);
+            for found in re.find_iter(&text) {
+                if found.is_empty() {
+                    continue;
+                }
+                let start_col = cols[found.start()];
+                let end_col = cols[found.end() - 1] + 1;
+                self.matches.push(SearchMatch {
+                    line: row as usize,
+                    start_col,
+                    end_col,
+                });
+            }
+        }
+        self.current = if self.matches.is_empty() {
+            None
+        } else {
+            let newest = self.matches.len() - 1;
+            Some(self.current.map_or(newest, |current| current.min(newest)))
+        };
+    }
+}
+
+/// Text of a row plus the screen column of every byte in it.
+fn row_text_with_columns(screen: &TerminalScreen, row: u16) -> (String, Vec<u16>) {
+    let mut text = String::new();
+    let mut cols = Vec::new();
+    for col in 0..screen.cols() {
+        let Some(cell) = screen.cell(row, col) else { break };
+        if cell.wide_continuation {
+            continue;
+        }
+        let symbol = if cell.symbol.is_empty() { " " } else { &cell.symbol };
+        text.push_str(symbol);
+        cols.resize(text.len(), col);
+    }
+    (text, cols)
+}
@@ -0,0 +1,93 @@
+use crate::{ShellIntegration, TerminalScreen};
+
+/// Which part of the GENERAL terminal to hand to the agent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendSource {
+    /// The live screen.
+    Screen,
+    /// The last `n` lines of history up to the cursor.
+    Lines(usize),
+    /// The last finished command and its output.
+    LastCommand,
+}
+
+/// Input for the AI AGENTS PTY, queued by the reducer or a command and
+/// delivered by the runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AgentSend {
+    Output(SendSource),
+    Text(String),
+}
+
+/// Text of `source` in `history` (scrollback plus screen, with the cursor in
+/// history coordinates); `screen_rows` is the height of the live screen.
+///
+/// The last command comes from `shell`'s marks when its integration is
+/// loaded. Otherwise, or if the command printed nothing, the text left of
+/// the cursor is taken as the prompt, and the output starts at the closest
+/// earlier line beginning with the same prompt. Without such a line it falls
+/// back to the live screen.
+pub fn capture(
+    history: &TerminalScreen,
+    screen_rows: u16,
+    source: SendSource,
+    shell: &ShellIntegration,
+) -> String {
+    if source == SendSource::LastCommand
+        && shell.is_active()
+        && let Some((output, end)) = shell.last_output()
+    {
+        let row = |line: usize| u16::try_from(line).unwrap_or(u16::MAX);
+        let start = shell.prev_prompt(output).unwrap_or(output);
+        return text_of(history, row(start), row(end).saturating_add(1));
+    }
+    let cursor = history.cursor_row.min(history.rows().saturating_sub(1));
+    let screen_top = history.rows().saturating_sub(screen_rows);
+    let start = match source {
+        SendSource::Screen => screen_top,
+        SendSource::Lines(n) => {
+            let n = u16::try_from(n).unwrap_or(u16::MAX);
+            history.last_used_row().saturating_add(1).saturating_sub(n)
+        }
+        SendSource::LastCommand => {
+            let prompt = history.row_text_range(cursor, 0, history.cursor_col);
+            let prompt = prompt.trim();
+            (0..cursor)
+                .rev()
+                .find(|&row| {
+                    !prompt.is_empty() && history.row_text(row).trim_start().starts_with(prompt)
+                })
+                .unwrap_or(screen_top)
+        }
+    };
+    let end = match source {
+        SendSource::LastCommand if start < cursor => cursor,
+        _ => history.last_used_row().saturating_add(1),
+    };
+    text_of(history, start, end)
+}
+
+/// Rows `start..end` of `history` without leading and trailing blank lines.
+fn text_of(history: &TerminalScreen, start: u16, end: u16) -> String {
+    let end = end.min(history.rows());
+    let mut lines: Vec<String> = (start..end).map(|row| history.row_text(row)).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    while lines.first().is_some_and(|line| line.is_empty()) {
+        lines.remove(0);
+    }
+    lines.join("\n")
+}
+
+/// Wraps captured output in a fenced block under a one-line header so the
+/// agent can tell it apart from the user's own words.
+pub fn frame_for_agent(text: &str, source: SendSource) -> String {
+    let header = match source {
+        SendSource::Screen => "Here is my terminal screen:".to_string(),
+        SendSource::Lines(n) => format!("Here are the last {n} lines of my terminal:"),
+        SendSource::LastCommand => "Here is my last terminal command and its output:".to_string(),
+    };
+    let fence = if text.contains("```") { "~~~~" } else { "```" };
+    format!("{header}\n{fence}\n{text}\n{fence}\n")
+}
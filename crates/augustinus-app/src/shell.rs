@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use augustinus_term::{ShellMark, ShellMarkKind};
+
+/// Commands remembered per terminal for prompt jumps.
+const MAX_COMMANDS: usize = 500;
+
+/// A command that ran to completion, for the runtime to record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinishedCommand {
+    pub command: String,
+    pub exit_code: Option<i32>,
+    pub duration: Duration,
+}
+
+/// One prompt and what was run from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PromptEntry {
+    prompt: u64,
+    command: String,
+    output: Option<(u64, Instant)>,
+    /// Where the cursor was when the command finished.
+    end: Option<(u64, u16)>,
+}
+
+/// Prompts and commands of one terminal, built from the marks its shell
+/// emits. Empty when the shell has no integration snippet loaded.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ShellIntegration {
+    entries: VecDeque<PromptEntry>,
+    /// Absolute line of history line `0`.
+    origin: u64,
+    finished: Vec<FinishedCommand>,
+}
+
+impl ShellIntegration {
+    pub fn is_active(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    pub fn on_mark(&mut self, mark: ShellMark) {
+        match mark.kind {
+            ShellMarkKind::PromptStart => {
+                self.entries.push_back(PromptEntry {
+                    prompt: mark.line,
+                    command: String::new(),
+                    output: None,
+                    end: None,
+                });
+                if self.entries.len() > MAX_COMMANDS {
+                    self.entries.pop_front();
+                }
+            }
+            ShellMarkKind::CommandStart => {}
+            ShellMarkKind::OutputStart { command } => {
+                if let Some(entry) = self.entries.back_mut() {
+                    entry.command = command;
+                    entry.output = Some((mark.line, mark.at));
+                    entry.end = None;
+                }
+            }
+            ShellMarkKind::CommandFinished { exit_code } => {
+                // Shells also report before their first prompt, when
+                // nothing has run yet.
+                let Some(entry) = self.entries.back_mut() else {
+                    return;
+                };
+                let Some((_, started)) = entry.output else {
+                    return;
+                };
+                if entry.end.is_some() {
+                    return;
+                }
+                entry.end = Some((mark.line, mark.col));
+                self.finished.push(FinishedCommand {
+                    command: entry.command.clone(),
+                    exit_code,
+                    duration: mark.at.saturating_duration_since(started),
+                });
+            }
+        }
+    }
+
+    /// Sets the absolute line shown as history line `0` and forgets prompts
+    /// that have left the scrollback.
+    pub fn set_origin(&mut self, origin: u64) {
+        self.origin = origin;
+        while self
+            .entries
+            .front()
+            .is_some_and(|entry| entry.prompt < origin)
+        {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Commands finished since the last call.
+    pub fn take_finished(&mut self) -> Vec<FinishedCommand> {
+        std::mem::take(&mut self.finished)
+    }
+
+    /// History line of the closest prompt above `line`.
+    pub fn prev_prompt(&self, line: usize) -> Option<usize> {
+        self.prompt_lines().rev().find(|&prompt| prompt < line)
+    }
+
+    /// History line of the closest prompt below `line`.
+    pub fn next_prompt(&self, line: usize) -> Option<usize> {
+        self.prompt_lines().find(|&prompt| prompt > line)
+    }
+
+    /// First and last history line of the latest finished command's output;
+    /// `None` if it printed nothing.
+    pub fn last_output(&self) -> Option<(usize, usize)> {
+        let entry = self.entries.iter().rev().find(|entry| entry.end.is_some())?;
+        let (start, _) = entry.output?;
+        let (end_line, end_col) = entry.end?;
+        // Output ending in a newline leaves the cursor at the start of the
+        // next line, which belongs to the prompt.
+        let end = if end_col == 0 {
+            end_line.checked_sub(1)?
+        } else {
+            end_line
+        };
+        if end < start {
+            return None;
+        }
+        Some((self.history_line(start), self.history_line(end)))
+    }
+
+    fn prompt_lines(&self) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        self.entries
+            .iter()
+            .map(|entry| self.history_line(entry.prompt))
+    }
+
+    fn history_line(&self, line: u64) -> usize {
+        usize::try_from(line.saturating_sub(self.origin)).unwrap_or(usize::MAX)
+    }
+}
@@ -1,79 +1,121 @@
-use crate::{Action, AgentsInputMode, GeneralInputMode, PaneId};
+use augustinus_i18n::{fill, Language, Strings};
+
+use crate::{Command, CommandFeedback, CommandLine, TabCommand};
+use crate::{Action, AgentPicker, AgentSend, AgentsInputMode, GeneralInputMode, PaneId};
 use crate::FocusState;
 use crate::{motivation::DEFAULT_IDLE_THRESHOLD, MotivationState};
-use crate::LocDelta;
+use crate::{CommandStats, LocDelta};
+use crate::{CopyMode, CopyPos, SelectionKind};
+use crate::{ScrollState, SearchState};
+use crate::{Keymap, PaneLayout, SplitRatios, SPLIT_STEP};
+use crate::{TerminalTab, TerminalTabs};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AppState {
+    language: Language,
     pub focused: PaneId,
     pub fullscreen: Option<PaneId>,
     pub command: Option<String>,
+    /// Cursor, history and completion of the command prompt.
+    pub command_line: CommandLine,
+    /// Error or output of the last command, shown in the overlay.
+    pub command_feedback: Option<CommandFeedback>,
     pub last_command: Option<String>,
+    /// A command only the runtime can carry out.
+    pending_command: Option<Command>,
     pub motivation: MotivationState,
     pub focus: FocusState,
-    pub general_screen: String,
+    pub general_tabs: TerminalTabs,
     pub general_input_mode: GeneralInputMode,
-    pub agents_screen: String,
+    /// One tab per configured agent; the active one fills AI AGENTS.
+    pub agent_tabs: TerminalTabs,
     pub agents_input_mode: AgentsInputMode,
-    pub agents_cursor_row: u16,
-    pub agents_cursor_col: u16,
+    /// Shown instead of the agent when none could be started.
+    pub agent_picker: Option<AgentPicker>,
+    /// Agents that went from busy to waiting, for the runtime to announce.
+    agent_notifications: Vec<String>,
+    /// Text yanked in copy mode, ready to paste into any terminal pane.
+    pub register: Option<String>,
+    agent_send: Option<AgentSend>,
     pub loc_delta: Option<LocDelta>,
+    pub commands: CommandStats,
+    pub layout: PaneLayout,
+    pub splits: SplitRatios,
+    /// Set when a resize changed `splits`, for the runtime to save them.
+    splits_changed: bool,
+    /// Key bindings per mode, and any sequence being typed.
+    pub keymap: Keymap,
 }
 
 impl AppState {
     pub fn new_for_test() -> Self {
         Self {
+            language: Language::En,
             focused: PaneId::Motivation,
             fullscreen: None,
             command: None,
+            command_line: CommandLine::default(),
+            command_feedback: None,
             last_command: None,
+            pending_command: None,
             motivation: MotivationState::new(DEFAULT_IDLE_THRESHOLD),
             focus: FocusState::new_for_test(),
-            general_screen: String::new(),
+            general_tabs: TerminalTabs::new(false),
             general_input_mode: GeneralInputMode::AppControls,
-            agents_screen: String::new(),
+            agent_tabs: TerminalTabs::new(true),
             agents_input_mode: AgentsInputMode::PaneControls,
-            agents_cursor_row: 0,
-            agents_cursor_col: 0,
+            agent_picker: None,
+            agent_notifications: Vec::new(),
+            register: None,
+            agent_send: None,
             loc_delta: None,
+            commands: CommandStats::default(),
+            layout: PaneLayout::default(),
+            splits: SplitRatios::default(),
+            splits_changed: false,
+            keymap: Keymap::default(),
         }
     }
 
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// UI strings in the active language.
+    pub fn strings(&self) -> &'static Strings {
+        augustinus_i18n::strings(self.language)
+    }
+
     pub fn apply(&mut self, action: Action) {
         match action {
+            Action::SetLanguage(language) => {
+                self.language = language;
+                self.motivation.set_language(language);
+            }
             Action::FocusLeft => {
                 self.focused = focus_left(self.focused);
-                self.general_input_mode = GeneralInputMode::AppControls;
-                if self.focused != PaneId::Agents {
-                    self.agents_input_mode = AgentsInputMode::PaneControls;
-                }
+                self.after_focus_change();
             }
             Action::FocusRight => {
                 self.focused = focus_right(self.focused);
-                self.general_input_mode = GeneralInputMode::AppControls;
-                if self.focused != PaneId::Agents {
-                    self.agents_input_mode = AgentsInputMode::PaneControls;
-                }
+                self.after_focus_change();
             }
             Action::FocusUp => {
                 self.focused = focus_up(self.focused);
-                self.general_input_mode = GeneralInputMode::AppControls;
-                if self.focused != PaneId::Agents {
-                    self.agents_input_mode = AgentsInputMode::PaneControls;
-                }
+                self.after_focus_change();
             }
             Action::FocusDown => {
                 self.focused = focus_down(self.focused);
-                self.general_input_mode = GeneralInputMode::AppControls;
-                if self.focused != PaneId::Agents {
-                    self.agents_input_mode = AgentsInputMode::PaneControls;
-                }
+                self.after_focus_change();
             }
             Action::RotateFocus => {
                 self.focused = self.focused.next();
-                self.general_input_mode = GeneralInputMode::AppControls;
-                if self.focused != PaneId::Agents {
-                    self.agents_input_mode = AgentsInputMode::PaneControls;
+                self.after_focus_change();
+            }
+            Action::FocusPane(pane) => {
+                if self.focused != pane {
+                    self.focused = pane;
+                    self.after_focus_change();
                 }
             }
             Action::EnterGeneralTerminalMode => {
@@ -98,23 +140,494 @@ impl AppState {
             }
             Action::EnterFullscreen => self.fullscreen = Some(self.focused),
             Action::ExitFullscreen => self.fullscreen = None,
-            Action::EnterCommandMode => self.command = Some(String::new()),
-            Action::ExitCommandMode => self.command = None,
+            Action::GrowPaneWidth => self.resize_focused(SPLIT_STEP as i16, 0),
+            Action::ShrinkPaneWidth => self.resize_focused(-(SPLIT_STEP as i16), 0),
+            Action::GrowPaneHeight => self.resize_focused(0, SPLIT_STEP as i16),
+            Action::ShrinkPaneHeight => self.resize_focused(0, -(SPLIT_STEP as i16)),
+            Action::ResetSplits => self.set_splits(SplitRatios::default()),
+            Action::EnterCommandMode => self.open_command(String::new()),
+            Action::ExitCommandMode => {
+                self.command = None;
+                self.command_feedback = None;
+                self.command_line.reset();
+            }
             Action::CommandAppend(ch) => {
                 if let Some(buffer) = self.command.as_mut() {
-                    buffer.push(ch);
+                    self.command_line.insert(buffer, ch);
+                    self.command_feedback = None;
                 }
             }
             Action::CommandBackspace => {
                 if let Some(buffer) = self.command.as_mut() {
-                    buffer.pop();
+                    self.command_line.backspace(buffer);
+                    self.command_feedback = None;
+                }
+            }
+            Action::CommandDeleteWord => {
+                if let Some(buffer) = self.command.as_mut() {
+                    self.command_line.delete_word(buffer);
+                    self.command_feedback = None;
+                }
+            }
+            Action::CommandCursorLeft => {
+                if let Some(buffer) = self.command.as_ref() {
+                    self.command_line.left(buffer);
                 }
             }
+            Action::CommandCursorRight => {
+                if let Some(buffer) = self.command.as_ref() {
+                    self.command_line.right(buffer);
+                }
+            }
+            Action::CommandCursorHome => self.command_line.home(),
+            Action::CommandCursorEnd => {
+                if let Some(buffer) = self.command.as_ref() {
+                    self.command_line.end(buffer);
+                }
+            }
+            Action::CommandHistoryPrev => {
+                if let Some(buffer) = self.command.as_mut() {
+                    self.command_line.history_prev(buffer);
+                    self.command_feedback = None;
+                }
+            }
+            Action::CommandHistoryNext => {
+                if let Some(buffer) = self.command.as_mut() {
+                    self.command_line.history_next(buffer);
+                    self.command_feedback = None;
+                }
+            }
+            Action::CommandComplete => self.complete_command(false),
+            Action::CommandCompleteBack => self.complete_command(true),
+            Action::CommandCompleteCancel => {
+                self.command_line.cancel_completion();
+            }
             Action::SubmitCommand => {
                 if let Some(buffer) = self.command.take() {
-                    self.last_command = Some(buffer);
+                    self.command_line.push_history(&buffer);
+                    self.command_line.reset();
+                    self.submit_command(buffer);
+                }
+            }
+            Action::ScrollLineUp => {
+                if let Some(scroll) = self.focused_scroll_mut() {
+                    scroll.line_up(1);
+                }
+            }
+            Action::ScrollLineDown => {
+                if let Some(scroll) = self.focused_scroll_mut() {
+                    scroll.line_down(1);
+                }
+            }
+            Action::ScrollPageUp => {
+                if let Some(scroll) = self.focused_scroll_mut() {
+                    scroll.page_up();
+                }
+            }
+            Action::ScrollPageDown => {
+                if let Some(scroll) = self.focused_scroll_mut() {
+                    scroll.page_down();
+                }
+            }
+            Action::ScrollTop => {
+                if let Some(scroll) = self.focused_scroll_mut() {
+                    scroll.top();
                 }
             }
+            Action::ScrollBottom => {
+                if let Some(scroll) = self.focused_scroll_mut() {
+                    scroll.bottom();
+                }
+            }
+            Action::ExitScroll => {
+                if let Some(scroll) = self.focused_scroll_mut() {
+                    scroll.exit();
+                }
+            }
+            Action::Respawn(pane) => {
+                if let Some(terminal) = self.terminal_mut(pane) {
+                    terminal.process.request_restart();
+                }
+            }
+            Action::TabNew => {
+                self.general_tabs.open();
+            }
+            Action::TabClose => {
+                self.general_tabs.close_active();
+                self.clear_visible_alerts();
+            }
+            Action::TabNext => {
+                self.general_tabs.next();
+                self.clear_visible_alerts();
+            }
+            Action::TabPrev => {
+                self.general_tabs.prev();
+                self.clear_visible_alerts();
+            }
+            Action::TabSelect(index) => {
+                self.general_tabs.select(index);
+                self.clear_visible_alerts();
+            }
+            Action::RecordStart(pane) => {
+                if let Some(terminal) = self.terminal_mut(pane) {
+                    terminal.recording.request_start();
+                }
+            }
+            Action::RecordStop(pane) => {
+                if let Some(terminal) = self.terminal_mut(pane) {
+                    terminal.recording.request_stop();
+                }
+            }
+            Action::SearchOpen => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.open_prompt();
+                }
+            }
+            Action::SearchAppend(ch) => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.push(ch);
+                }
+            }
+            Action::SearchBackspace => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.backspace();
+                }
+            }
+            Action::SearchToggleRegex => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.toggle_regex();
+                }
+            }
+            Action::SearchSubmit => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.submit();
+                }
+            }
+            Action::SearchCancel => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.cancel();
+                }
+            }
+            Action::SearchNext => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.next();
+                }
+            }
+            Action::SearchPrev => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.prev();
+                }
+            }
+            Action::SearchClear => {
+                if let Some(search) = self.focused_search_mut() {
+                    search.clear();
+                }
+            }
+            Action::CopyEnter => {
+                if let Some(terminal) = self.terminal_mut(self.focused) {
+                    let top = terminal.scroll.max_offset().saturating_sub(terminal.scroll.offset());
+                    let last_line = terminal.scroll.max_offset()
+                        + (terminal.screen.rows() as usize).saturating_sub(1);
+                    let cursor = CopyPos {
+                        line: (top + terminal.screen.cursor_row as usize).min(last_line),
+                        col: terminal.screen.cursor_col,
+                    };
+                    terminal.copy = Some(CopyMode::new(cursor));
+                }
+            }
+            Action::CopyMove(motion) => {
+                if let Some(terminal) = self.terminal_mut(self.focused)
+                    && let Some(copy) = &mut terminal.copy
+                {
+                    let page = terminal.screen.rows() as usize;
+                    let lines = terminal.scroll.max_offset() + page;
+                    copy.apply(motion, lines, terminal.screen.cols(), page);
+                    terminal.scroll.reveal(copy.cursor().line);
+                }
+            }
+            Action::CopySelect(kind) => {
+                if let Some(copy) = self.focused_copy_mut() {
+                    copy.toggle_selection(kind);
+                }
+            }
+            Action::CopyClearSelection => {
+                if let Some(copy) = self.focused_copy_mut() {
+                    copy.clear_selection();
+                }
+            }
+            Action::CopyExit => {
+                if let Some(terminal) = self.terminal_mut(self.focused) {
+                    terminal.copy = None;
+                    terminal.scroll.exit();
+                }
+            }
+            Action::PromptPrev => {
+                if let Some(terminal) = self.terminal_mut(self.focused) {
+                    let top = terminal.scroll.max_offset().saturating_sub(terminal.scroll.offset());
+                    if let Some(line) = terminal.shell.prev_prompt(top) {
+                        terminal.scroll.show_at_top(line);
+                    }
+                }
+            }
+            Action::PromptNext => {
+                if let Some(terminal) = self.terminal_mut(self.focused) {
+                    let top = terminal.scroll.max_offset().saturating_sub(terminal.scroll.offset());
+                    match terminal.shell.next_prompt(top) {
+                        Some(line) => terminal.scroll.show_at_top(line),
+                        None => terminal.scroll.bottom(),
+                    }
+                }
+            }
+            Action::SelectLastOutput => {
+                if let Some(terminal) = self.terminal_mut(self.focused)
+                    && let Some((start, end)) = terminal.shell.last_output()
+                {
+                    let anchor = CopyPos { line: start, col: 0 };
+                    let cursor = CopyPos {
+                        line: end,
+                        col: terminal.screen.cols().saturating_sub(1),
+                    };
+                    terminal.copy = Some(CopyMode::with_selection(SelectionKind::Line, anchor, cursor));
+                    terminal.scroll.reveal(start);
+                }
+            }
+            Action::SendOutput(source) => self.send_to_agent(AgentSend::Output(source)),
+            Action::AgentSelect(index) => {
+                self.agent_tabs.select(index);
+                self.clear_visible_alerts();
+            }
+            Action::AgentPickerUp => {
+                if let Some(picker) = &mut self.agent_picker {
+                    picker.up();
+                }
+            }
+            Action::AgentPickerDown => {
+                if let Some(picker) = &mut self.agent_picker {
+                    picker.down();
+                }
+            }
+            Action::AgentPickerConfirm(index) => {
+                if let Some(picker) = &mut self.agent_picker {
+                    if let Some(index) = index {
+                        picker.select(index);
+                    }
+                    picker.confirm();
+                }
+            }
+        }
+    }
+
+    /// Parses and runs a command typed in the overlay. Errors and output
+    /// keep the overlay open to show them; `:quit` and `:focus` are left to
+    /// the runtime.
+    fn submit_command(&mut self, input: String) {
+        self.command_feedback = None;
+        let command = match Command::parse(&input) {
+            Ok(command) => command,
+            Err(crate::CommandError::Empty) => return,
+            Err(err) => {
+                self.open_command(input);
+                self.command_feedback = Some(CommandFeedback::Error(err.message(self.strings())));
+                return;
+            }
+        };
+        self.last_command = Some(input.trim().to_string());
+        match command {
+            Command::Help(topic) => match crate::help_lines(topic.as_deref(), self.strings()) {
+                Ok(lines) => self.command_output(lines),
+                Err(err) => {
+                    self.open_command(input);
+                    self.command_feedback = Some(CommandFeedback::Error(err.message(self.strings())));
+                }
+            },
+            Command::Quit | Command::FocusStart | Command::FocusStop => {
+                self.pending_command = Some(command);
+            }
+            Command::Lang(language) => {
+                self.apply(Action::SetLanguage(language));
+                self.pending_command = Some(command);
+            }
+            Command::Respawn(pane) => self.apply(Action::Respawn(pane)),
+            Command::Tab(TabCommand::New) => self.apply(Action::TabNew),
+            Command::Tab(TabCommand::Close) => self.apply(Action::TabClose),
+            Command::Tab(TabCommand::Next) => self.apply(Action::TabNext),
+            Command::Tab(TabCommand::Prev) => self.apply(Action::TabPrev),
+            Command::SendOutput(source) => self.apply(Action::SendOutput(source)),
+            Command::SendText(text) => self.send_to_agent(AgentSend::Text(text)),
+            Command::Agent(name) => match self.agent_tabs.position_by_name(&name) {
+                Some(index) => self.apply(Action::AgentSelect(index)),
+                None => {
+                    let message = fill(self.strings().error_no_agent, &[&format!("{name:?}")]);
+                    self.command_failed(message);
+                }
+            },
+            Command::Record { start, pane } => {
+                let pane = pane.unwrap_or(match self.focused {
+                    PaneId::Agents => PaneId::Agents,
+                    _ => PaneId::General,
+                });
+                if start {
+                    self.apply(Action::RecordStart(pane));
+                } else {
+                    self.apply(Action::RecordStop(pane));
+                }
+            }
+        }
+    }
+
+    /// Runs a command line bound to a key, as if submitted in the overlay
+    /// but without adding it to the history.
+    pub fn run_command(&mut self, input: &str) {
+        self.submit_command(input.to_string());
+    }
+
+    /// Reopens the overlay on the last command with `message` under it.
+    pub fn command_failed(&mut self, message: String) {
+        self.open_command(self.last_command.clone().unwrap_or_default());
+        self.command_feedback = Some(CommandFeedback::Error(message));
+    }
+
+    /// Shows `lines` in the overlay under an empty prompt.
+    pub fn command_output(&mut self, lines: Vec<String>) {
+        self.open_command(String::new());
+        self.command_feedback = Some(CommandFeedback::Output(lines));
+    }
+
+    /// Opens the overlay on `text` with the cursor at its end.
+    fn open_command(&mut self, text: String) {
+        self.command_line.reset();
+        self.command_line.end(&text);
+        self.command = Some(text);
+        self.command_feedback = None;
+    }
+
+    /// Tab in the overlay: completes the word before the cursor.
+    fn complete_command(&mut self, reverse: bool) {
+        let Some(buffer) = self.command.as_mut() else {
+            return;
+        };
+        let agents: Vec<String> = self
+            .agent_tabs
+            .iter()
+            .filter_map(|tab| tab.name.clone())
+            .collect();
+        let cursor = self.command_line.cursor(buffer);
+        let (start, candidates) = crate::complete(buffer, cursor, &agents);
+        self.command_line.complete(buffer, start, candidates, reverse);
+        self.command_feedback = None;
+    }
+
+    fn resize_focused(&mut self, width: i16, height: i16) {
+        let mut splits = self.splits;
+        splits.resize(self.focused, width, height);
+        self.set_splits(splits);
+    }
+
+    fn set_splits(&mut self, splits: SplitRatios) {
+        if splits != self.splits {
+            self.splits = splits;
+            self.splits_changed = true;
+        }
+    }
+
+    /// The splits after a resize, for the runtime to save.
+    pub fn take_splits_change(&mut self) -> Option<SplitRatios> {
+        std::mem::take(&mut self.splits_changed).then_some(self.splits)
+    }
+
+    /// A parsed command for the runtime to carry out.
+    pub fn take_command(&mut self) -> Option<Command> {
+        self.pending_command.take()
+    }
+
+    /// Queues input for the AI AGENTS terminal; the runtime delivers it.
+    pub fn send_to_agent(&mut self, send: AgentSend) {
+        self.agent_send = Some(send);
+    }
+
+    pub fn take_agent_send(&mut self) -> Option<AgentSend> {
+        self.agent_send.take()
+    }
+
+    /// The active GENERAL tab.
+    pub fn general(&self) -> &TerminalTab {
+        self.general_tabs.active()
+    }
+
+    pub fn general_mut(&mut self) -> &mut TerminalTab {
+        self.general_tabs.active_mut()
+    }
+
+    pub fn take_agent_notifications(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.agent_notifications)
+    }
+
+    /// The agent shown in AI AGENTS.
+    pub fn agents(&self) -> &TerminalTab {
+        self.agent_tabs.active()
+    }
+
+    pub fn agents_mut(&mut self) -> &mut TerminalTab {
+        self.agent_tabs.active_mut()
+    }
+
+    /// The terminal shown in a PTY pane; for GENERAL and AI AGENTS, the
+    /// active tab.
+    pub fn terminal(&self, pane: PaneId) -> Option<&TerminalTab> {
+        match pane {
+            PaneId::General => Some(self.general()),
+            PaneId::Agents => Some(self.agents()),
+            _ => None,
+        }
+    }
+
+    pub fn terminal_mut(&mut self, pane: PaneId) -> Option<&mut TerminalTab> {
+        match pane {
+            PaneId::General => Some(self.general_mut()),
+            PaneId::Agents => Some(self.agents_mut()),
+            _ => None,
+        }
+    }
+
+    pub fn focused_scroll_mut(&mut self) -> Option<&mut ScrollState> {
+        self.terminal_mut(self.focused).map(|terminal| &mut terminal.scroll)
+    }
+
+    pub fn focused_search_mut(&mut self) -> Option<&mut SearchState> {
+        self.terminal_mut(self.focused).map(|terminal| &mut terminal.search)
+    }
+
+    fn focused_copy_mut(&mut self) -> Option<&mut CopyMode> {
+        self.terminal_mut(self.focused)?.copy.as_mut()
+    }
+
+    fn after_focus_change(&mut self) {
+        self.general_input_mode = GeneralInputMode::AppControls;
+        if self.focused != PaneId::Agents {
+            self.agents_input_mode = AgentsInputMode::PaneControls;
+        }
+        if self.focused != PaneId::General {
+            self.general_mut().scroll.exit();
+            self.general_mut().search.cancel();
+            self.general_mut().copy = None;
+        }
+        if self.focused != PaneId::Agents {
+            self.agents_mut().scroll.exit();
+            self.agents_mut().search.cancel();
+            self.agents_mut().copy = None;
+        }
+        self.clear_visible_alerts();
+    }
+
+    /// Whether the terminal with this id is the one the user is looking at.
+    /// GENERAL and AI AGENTS tab ids are told apart by `pane`.
+    pub fn is_terminal_visible(&self, pane: PaneId, id: u32) -> bool {
+        self.focused == pane && self.terminal(pane).is_some_and(|terminal| terminal.id() == id)
+    }
+
+    fn clear_visible_alerts(&mut self) {
+        if let Some(terminal) = self.terminal_mut(self.focused) {
+            terminal.activity.clear();
         }
     }
 
@@ -124,6 +637,31 @@ impl AppState {
 
     pub fn tick(&mut self, dt: std::time::Duration) {
         self.motivation.tick(dt);
+        for (pane, tabs) in [
+            (PaneId::General, &mut self.general_tabs),
+            (PaneId::Agents, &mut self.agent_tabs),
+        ] {
+            let pane_visible = self.focused == pane;
+            let active = tabs.active().id();
+            for tab in tabs.iter_mut() {
+                tab.process.tick(dt);
+                let visible = pane_visible && tab.id() == active;
+                tab.activity.tick(dt, visible);
+            }
+        }
+        // Typing into an agent makes it look busy; don't announce that.
+        let typing_into = (self.focused == PaneId::Agents
+            && self.agents_input_mode == AgentsInputMode::CodexLocked)
+            .then(|| self.agents().id());
+        for tab in self.agent_tabs.iter_mut() {
+            tab.agent_status.tick(dt);
+            if tab.agent_status.take_became_waiting() && typing_into != Some(tab.id()) {
+                tab.activity.flash();
+                if let Some(name) = &tab.name {
+                    self.agent_notifications.push(name.clone());
+                }
+            }
+        }
     }
 }
 
@@ -1,3 +1,19 @@
+/// Shell commands finished today, as reported by shell integration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CommandStats {
+    pub today: u64,
+    pub failed: u64,
+}
+
+impl CommandStats {
+    pub fn record(&mut self, exit_code: Option<i32>) {
+        self.today += 1;
+        if exit_code.is_some_and(|code| code != 0) {
+            self.failed += 1;
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LocDelta {
     pub added: u64,
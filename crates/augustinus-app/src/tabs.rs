@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use crate::{ActivityMonitor, AgentStatusTracker, CopyMode, ProcessLifecycle, RecordingState, ScrollState, SearchState, ShellIntegration, TerminalScreen};
+
+/// UI-side state of one embedded terminal. The runtime owns the PTY and
+/// matches it to the tab through `id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalTab {
+    id: u32,
+    /// Configured name, e.g. of an agent; GENERAL tabs have none.
+    pub name: Option<String>,
+    /// OSC window title, else the foreground process name.
+    pub title: Option<String>,
+    pub screen: TerminalScreen,
+    pub scroll: ScrollState,
+    pub process: ProcessLifecycle,
+    pub recording: RecordingState,
+    pub search: SearchState,
+    pub copy: Option<CopyMode>,
+    pub activity: ActivityMonitor,
+    /// Busy/waiting inference, fed by the runtime for agent tabs.
+    pub agent_status: AgentStatusTracker,
+    /// Prompts and commands reported through OSC 133.
+    pub shell: ShellIntegration,
+}
+
+impl TerminalTab {
+    pub fn new(id: u32, auto_restart: bool) -> Self {
+        Self {
+            id,
+            name: None,
+            title: None,
+            screen: TerminalScreen::default(),
+            scroll: ScrollState::default(),
+            process: ProcessLifecycle::new(auto_restart),
+            recording: RecordingState::default(),
+            search: SearchState::default(),
+            copy: None,
+            activity: ActivityMonitor::default(),
+            agent_status: AgentStatusTracker::default(),
+            shell: ShellIntegration::default(),
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+/// An ordered set of terminal tabs with one active tab; never empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalTabs {
+    tabs: Vec<TerminalTab>,
+    active: usize,
+    next_id: u32,
+    auto_restart: bool,
+    silence_after: Option<Duration>,
+}
+
+impl TerminalTabs {
+    pub fn new(auto_restart: bool) -> Self {
+        Self {
+            tabs: vec![TerminalTab::new(0, auto_restart)],
+            active: 0,
+            next_id: 1,
+            auto_restart,
+            silence_after: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    pub fn active(&self) -> &TerminalTab {
+        &self.tabs[self.active]
+    }
+
+    pub fn active_mut(&mut self) -> &mut TerminalTab {
+        &mut self.tabs[self.active]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TerminalTab> {
+        self.tabs.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut TerminalTab> {
+        self.tabs.iter_mut()
+    }
+
+    pub fn get_mut(&mut self, id: u32) -> Option<&mut TerminalTab> {
+        self.tabs.iter_mut().find(|tab| tab.id == id)
+    }
+
+    pub fn contains(&self, id: u32) -> bool {
+        self.tabs.iter().any(|tab| tab.id == id)
+    }
+
+    /// Index of the tab with this configured name.
+    pub fn position_by_name(&self, name: &str) -> Option<usize> {
+        self.tabs
+            .iter()
+            .position(|tab| tab.name.as_deref() == Some(name))
+    }
+
+    /// Silence alert threshold for current and future tabs.
+    pub fn set_silence_after(&mut self, after: Option<Duration>) {
+        self.silence_after = after;
+        for tab in &mut self.tabs {
+            tab.activity.set_silence_after(after);
+        }
+    }
+
+    /// Opens a tab after the active one and makes it active.
+    pub fn open(&mut self) -> u32 {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.active_mut().scroll.exit();
+        let index = (self.active + 1).min(self.tabs.len());
+        let mut tab = TerminalTab::new(id, self.auto_restart);
+        tab.activity.set_silence_after(self.silence_after);
+        self.tabs.insert(index, tab);
+        self.active = index;
+        id
+    }
+
+    /// Closes the active tab unless it is the last one.
+    pub fn close_active(&mut self) -> bool {
+        if self.tabs.len() <= 1 {
+            return false;
+        }
+        self.tabs.remove(self.active);
+        self.active = self.active.min(self.tabs.len() - 1);
+        true
+    }
+
+    /// Drops a tab whose PTY could not be started.
+    pub fn remove(&mut self, id: u32) {
+        if self.tabs.len() <= 1 {
+            return;
+        }
+        if let Some(index) = self.tabs.iter().position(|tab| tab.id == id) {
+            self.tabs.remove(index);
+            if self.active > index || self.active == self.tabs.len() {
+                self.active = self.active.saturating_sub(1);
+            }
+        }
+    }
+
+    pub fn select(&mut self, index: usize) {
+        if index < self.tabs.len() && index != self.active {
+            self.active_mut().scroll.exit();
+            self.active = index;
+        }
+    }
+
+    pub fn next(&mut self) {
+        self.select((self.active + 1) % self.tabs.len());
+    }
+
+    pub fn prev(&mut self) {
+        self.select((self.active + self.tabs.len() - 1) % self.tabs.len());
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use augustinus_app::{Action, ActivityMonitor, AppState, PaneId, ALERT_FLASH};
+
+#[test]
+fn flags_rise_only_while_hidden() {
+    let mut monitor = ActivityMonitor::default();
+    monitor.on_output(true);
+    monitor.on_bell(true);
+    assert!(!monitor.has_alert());
+
+    monitor.on_output(false);
+    assert!(monitor.has_activity());
+    assert!(!monitor.is_flashing());
+    monitor.on_bell(false);
+    assert!(monitor.has_bell());
+    assert!(monitor.is_flashing());
+    monitor.tick(ALERT_FLASH, false);
+    assert!(!monitor.is_flashing());
+
+    monitor.clear();
+    assert!(!monitor.has_alert());
+}
+
+#[test]
+fn silence_fires_once_per_burst_of_output() {
+    let mut monitor = ActivityMonitor::default();
+    monitor.set_silence_after(Some(Duration::from_secs(5)));
+    monitor.tick(Duration::from_secs(10), false);
+    assert!(!monitor.has_silence(), "no output yet, nothing went quiet");
+
+    monitor.on_output(false);
+    monitor.tick(Duration::from_secs(4), false);
+    assert!(!monitor.has_silence());
+    monitor.tick(Duration::from_secs(1), false);
+    assert!(monitor.has_silence());
+    assert!(monitor.is_flashing());
+
+    monitor.clear();
+    monitor.tick(Duration::from_secs(10), false);
+    assert!(!monitor.has_silence());
+}
+
+#[test]
+fn focusing_a_pane_clears_its_alerts() {
+    let mut state = AppState::new_for_test();
+    state.apply(Action::FocusPane(PaneId::Stats));
+    state.agents_mut().activity.on_bell(false);
+    state.general_mut().activity.on_output(false);
+
+    state.apply(Action::FocusPane(PaneId::Agents));
+    assert!(!state.agents_mut().activity.has_alert());
+    assert!(state.general().activity.has_activity());
+}
+
+#[test]
+fn selecting_a_tab_clears_its_alerts() {
+    let mut state = AppState::new_for_test();
+    state.apply(Action::FocusPane(PaneId::General));
+    let first = state.general().id();
+    state.apply(Action::TabNew);
+    assert!(!state.is_terminal_visible(PaneId::General, first));
+    state.general_tabs.iter_mut().for_each(|tab| tab.activity.on_bell(false));
+
+    state.apply(Action::TabPrev);
+    assert!(state.is_terminal_visible(PaneId::General, first));
+    assert!(!state.general().activity.has_alert());
+    assert!(state.general_tabs.iter().any(|tab| tab.activity.has_bell()));
+}
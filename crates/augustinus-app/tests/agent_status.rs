@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use augustinus_app::{
+    Action, AgentStatus, AgentStatusTracker, AppState, PaneId, AGENT_IDLE_AFTER, AGENT_MIN_BUSY,
+    AGENT_PROMPT_SETTLE,
+};
+
+const FRAME: Duration = Duration::from_millis(100);
+
+/// Keeps the screen changing for `busy`, then lets it go quiet.
+fn work(tracker: &mut AgentStatusTracker, busy: Duration, prompt_visible: bool) {
+    let mut elapsed = Duration::ZERO;
+    while elapsed < busy {
+        tracker.on_screen_change(prompt_visible);
+        tracker.tick(FRAME);
+        elapsed += FRAME;
+    }
+}
+
+#[test]
+fn quiet_screen_turns_busy_agent_to_waiting() {
+    let mut tracker = AgentStatusTracker::default();
+    assert_eq!(tracker.status(), AgentStatus::Waiting);
+
+    work(&mut tracker, AGENT_MIN_BUSY, false);
+    assert!(tracker.is_busy());
+    // The last frame of work already counted as quiet.
+    tracker.tick(AGENT_IDLE_AFTER - FRAME * 2);
+    assert!(tracker.is_busy());
+    tracker.tick(FRAME);
+    assert_eq!(tracker.status(), AgentStatus::Waiting);
+    assert!(tracker.take_became_waiting());
+    assert!(!tracker.take_became_waiting());
+}
+
+#[test]
+fn matched_prompt_settles_quickly() {
+    let mut tracker = AgentStatusTracker::default();
+    work(&mut tracker, AGENT_MIN_BUSY, false);
+    tracker.on_screen_change(true);
+    tracker.tick(AGENT_PROMPT_SETTLE);
+    assert_eq!(tracker.status(), AgentStatus::Waiting);
+    assert!(tracker.take_became_waiting());
+}
+
+#[test]
+fn short_bursts_do_not_notify() {
+    let mut tracker = AgentStatusTracker::default();
+    work(&mut tracker, FRAME * 3, false);
+    tracker.tick(AGENT_IDLE_AFTER);
+    assert_eq!(tracker.status(), AgentStatus::Waiting);
+    assert!(!tracker.take_became_waiting());
+}
+
+#[test]
+fn state_announces_agents_that_start_waiting() {
+    let mut state = AppState::new_for_test();
+    state.agents_mut().name = Some("codex".to_string());
+    work(&mut state.agents_mut().agent_status, AGENT_MIN_BUSY, false);
+    state.tick(AGENT_IDLE_AFTER);
+    assert_eq!(state.take_agent_notifications(), ["codex"]);
+    assert!(state.agents().activity.is_flashing());
+    assert!(state.take_agent_notifications().is_empty());
+}
+
+#[test]
+fn typing_into_the_agent_is_not_announced() {
+    let mut state = AppState::new_for_test();
+    state.agents_mut().name = Some("codex".to_string());
+    state.apply(Action::FocusPane(PaneId::Agents));
+    state.apply(Action::EnterAgentsTerminalMode);
+    work(&mut state.agents_mut().agent_status, AGENT_MIN_BUSY, false);
+    state.tick(AGENT_IDLE_AFTER);
+    assert!(state.take_agent_notifications().is_empty());
+}
@@ -0,0 +1,66 @@
+use augustinus_app::{Action, AgentPicker, AppState, PaneId};
+
+fn with_agents(names: &[&str]) -> AppState {
+    let mut state = AppState::new_for_test();
+    for (index, name) in names.iter().enumerate() {
+        if index > 0 {
+            state.agent_tabs.open();
+        }
+        state.agents_mut().name = Some(name.to_string());
+    }
+    state.apply(Action::AgentSelect(0));
+    state
+}
+
+#[test]
+fn agents_are_selected_by_index_or_name() {
+    let mut state = with_agents(&["codex", "aider"]);
+    assert_eq!(state.agents().name.as_deref(), Some("codex"));
+
+    state.apply(Action::AgentSelect(1));
+    assert_eq!(state.agents().name.as_deref(), Some("aider"));
+    assert_eq!(state.agent_tabs.position_by_name("codex"), Some(0));
+    assert_eq!(state.agent_tabs.position_by_name("gemini"), None);
+
+    state.apply(Action::AgentSelect(5));
+    assert_eq!(state.agents().name.as_deref(), Some("aider"));
+}
+
+#[test]
+fn only_the_shown_agent_counts_as_visible() {
+    let mut state = with_agents(&["codex", "aider"]);
+    state.apply(Action::FocusPane(PaneId::Agents));
+    let codex = state.agents().id();
+    state.apply(Action::AgentSelect(1));
+    let aider = state.agents().id();
+
+    assert!(state.is_terminal_visible(PaneId::Agents, aider));
+    assert!(!state.is_terminal_visible(PaneId::Agents, codex));
+}
+
+#[test]
+fn picker_confirms_the_highlighted_or_numbered_choice() {
+    let mut state = AppState::new_for_test();
+    state.agent_picker = Some(AgentPicker::new(vec![
+        "claude".to_string(),
+        "aider".to_string(),
+        "shell".to_string(),
+    ]));
+
+    state.apply(Action::AgentPickerDown);
+    state.apply(Action::AgentPickerDown);
+    state.apply(Action::AgentPickerDown);
+    state.apply(Action::AgentPickerUp);
+    state.apply(Action::AgentPickerConfirm(None));
+    let picker = state.agent_picker.as_mut().unwrap();
+    assert_eq!(picker.take_choice().as_deref(), Some("aider"));
+    assert_eq!(picker.take_choice(), None);
+
+    state.apply(Action::AgentPickerConfirm(Some(2)));
+    let picker = state.agent_picker.as_mut().unwrap();
+    assert_eq!(picker.take_choice().as_deref(), Some("shell"));
+
+    state.apply(Action::AgentPickerConfirm(Some(7)));
+    let picker = state.agent_picker.as_mut().unwrap();
+    assert_eq!(picker.take_choice().as_deref(), Some("shell"));
+}
@@ -0,0 +1,130 @@
+use augustinus_app::{complete, Action, AppState};
+
+fn typed(state: &mut AppState, text: &str) {
+    for ch in text.chars() {
+        state.apply(Action::CommandAppend(ch));
+    }
+}
+
+fn prompt(text: &str) -> AppState {
+    let mut state = AppState::new_for_test();
+    state.apply(Action::EnterCommandMode);
+    typed(&mut state, text);
+    state
+}
+
+fn command(state: &AppState) -> &str {
+    state.command.as_deref().unwrap()
+}
+
+#[test]
+fn cursor_moves_and_edits_in_place() {
+    let mut state = prompt("tab nxt");
+    state.apply(Action::CommandCursorLeft);
+    state.apply(Action::CommandCursorLeft);
+    typed(&mut state, "e");
+    assert_eq!(command(&state), "tab next");
+
+    state.apply(Action::CommandCursorHome);
+    state.apply(Action::CommandBackspace);
+    typed(&mut state, ":");
+    assert_eq!(command(&state), ":tab next");
+    state.apply(Action::CommandCursorRight);
+    state.apply(Action::CommandBackspace);
+    state.apply(Action::CommandBackspace);
+    assert_eq!(command(&state), "ab next");
+
+    state.apply(Action::CommandCursorEnd);
+    state.apply(Action::CommandDeleteWord);
+    assert_eq!(command(&state), "ab ");
+    state.apply(Action::CommandDeleteWord);
+    assert_eq!(command(&state), "");
+}
+
+#[test]
+fn cursor_steps_over_whole_characters() {
+    let mut state = prompt("send héllo");
+    state.apply(Action::CommandCursorLeft);
+    state.apply(Action::CommandCursorLeft);
+    state.apply(Action::CommandCursorLeft);
+    state.apply(Action::CommandBackspace);
+    assert_eq!(command(&state), "send hllo");
+    assert_eq!(state.command_line.cursor(command(&state)), 6);
+}
+
+#[test]
+fn history_recalls_submitted_commands_and_the_draft() {
+    let mut state = AppState::new_for_test();
+    state.command_line.set_history(vec!["tab new".into()]);
+    for line in ["tab next", "tab next", "  ", "help tab"] {
+        state.apply(Action::EnterCommandMode);
+        typed(&mut state, line);
+        state.apply(Action::SubmitCommand);
+        state.apply(Action::ExitCommandMode);
+    }
+    assert_eq!(state.command_line.history(), ["tab new", "tab next", "help tab"]);
+    assert_eq!(state.command_line.take_unsaved(), ["tab next", "help tab"]);
+    assert!(state.command_line.take_unsaved().is_empty());
+
+    state.apply(Action::EnterCommandMode);
+    typed(&mut state, "dra");
+    state.apply(Action::CommandHistoryPrev);
+    assert_eq!(command(&state), "help tab");
+    state.apply(Action::CommandHistoryPrev);
+    state.apply(Action::CommandHistoryPrev);
+    state.apply(Action::CommandHistoryPrev);
+    assert_eq!(command(&state), "tab new");
+    state.apply(Action::CommandHistoryNext);
+    assert_eq!(command(&state), "tab next");
+    state.apply(Action::CommandHistoryNext);
+    state.apply(Action::CommandHistoryNext);
+    assert_eq!(command(&state), "dra");
+    assert_eq!(state.command_line.cursor(command(&state)), 3);
+}
+
+#[test]
+fn completes_command_names_and_arguments() {
+    let agents = vec!["codex".to_string(), "claude code".to_string()];
+    assert_eq!(complete("re", 2, &agents), (0, vec!["respawn".into(), "record".into()]));
+    assert_eq!(complete("tab n", 5, &agents), (4, vec!["new".into(), "next".into()]));
+    assert_eq!(
+        complete("record start ", 13, &agents),
+        (13, vec!["general".into(), "agents".into()])
+    );
+    assert_eq!(complete("lang f", 6, &agents), (5, vec!["fr".into()]));
+    assert_eq!(
+        complete("agent c", 7, &agents),
+        (6, vec!["codex".into(), "\"claude code\"".into()])
+    );
+    assert_eq!(complete("help fo", 7, &agents), (5, vec!["focus".into()]));
+    assert_eq!(complete("quit ", 5, &agents), (5, vec![]));
+    // Only the text before the cursor counts.
+    assert_eq!(complete("tab next", 5, &agents), (4, vec!["new".into(), "next".into()]));
+}
+
+#[test]
+fn tab_inserts_unique_matches_and_cycles_through_ambiguous_ones() {
+    let mut state = prompt("fo");
+    state.apply(Action::CommandComplete);
+    assert_eq!(command(&state), "focus ");
+    assert!(state.command_line.completion().is_none());
+
+    typed(&mut state, "s");
+    state.apply(Action::CommandComplete);
+    assert_eq!(command(&state), "focus st");
+    let completion = state.command_line.completion().unwrap();
+    assert_eq!(completion.candidates, ["start", "stop"]);
+    assert_eq!(completion.selected, None);
+
+    state.apply(Action::CommandComplete);
+    assert_eq!(command(&state), "focus start");
+    state.apply(Action::CommandComplete);
+    assert_eq!(command(&state), "focus stop");
+    state.apply(Action::CommandCompleteBack);
+    assert_eq!(command(&state), "focus start");
+    assert_eq!(state.command_line.completion().unwrap().selected, Some(0));
+
+    state.apply(Action::CommandCompleteCancel);
+    assert!(state.command_line.completion().is_none());
+    assert_eq!(command(&state), "focus start");
+}
@@ -0,0 +1,133 @@
+use augustinus_app::{
+    help_lines, Action, AppState, Command, CommandError, CommandFeedback, PaneId, SendSource,
+    TabCommand, COMMANDS,
+};
+use augustinus_i18n::{strings, Language};
+
+#[test]
+fn parses_commands_and_aliases() {
+    assert_eq!(Command::parse("q"), Ok(Command::Quit));
+    assert_eq!(Command::parse("  quit  "), Ok(Command::Quit));
+    assert_eq!(Command::parse("focus start"), Ok(Command::FocusStart));
+    assert_eq!(Command::parse("tab prev"), Ok(Command::Tab(TabCommand::Prev)));
+    assert_eq!(Command::parse("respawn agents"), Ok(Command::Respawn(PaneId::Agents)));
+    assert_eq!(
+        Command::parse("send-output agents 40"),
+        Ok(Command::SendOutput(SendSource::Lines(40)))
+    );
+    assert_eq!(
+        Command::parse("send-output agents"),
+        Ok(Command::SendOutput(SendSource::LastCommand))
+    );
+    assert_eq!(
+        Command::parse("record stop"),
+        Ok(Command::Record { start: false, pane: None })
+    );
+    assert_eq!(Command::parse("help tab"), Ok(Command::Help(Some("tab".into()))));
+}
+
+#[test]
+fn quoting_groups_words() {
+    assert_eq!(
+        Command::parse(r#"agent "claude code""#),
+        Ok(Command::Agent("claude code".into()))
+    );
+    assert_eq!(
+        Command::parse(r#"record start "oops"#),
+        Err(CommandError::UnterminatedQuote)
+    );
+}
+
+#[test]
+fn send_takes_the_rest_of_the_line_as_typed() {
+    assert_eq!(
+        Command::parse("send agents what's wrong?"),
+        Ok(Command::SendText("what's wrong?".into()))
+    );
+    assert_eq!(
+        Command::parse(r#"send  agents  'why  "this"?' and\ that"#),
+        Ok(Command::SendText(r#"'why  "this"?' and\ that"#.into()))
+    );
+    assert_eq!(
+        Command::parse("send agents   "),
+        Err(CommandError::MissingArgument { command: "send", expected: "text to send" })
+    );
+    assert_eq!(
+        Command::parse("send"),
+        Err(CommandError::MissingArgument { command: "send", expected: "agents" })
+    );
+    assert_eq!(
+        Command::parse("send general it's done"),
+        Err(CommandError::InvalidArgument {
+            command: "send",
+            argument: "general".into(),
+            expected: "agents",
+        })
+    );
+}
+
+#[test]
+fn reports_what_is_wrong() {
+    assert_eq!(Command::parse("   "), Err(CommandError::Empty));
+    assert_eq!(Command::parse("fly"), Err(CommandError::Unknown("fly".into())));
+    assert_eq!(
+        Command::parse("focus"),
+        Err(CommandError::MissingArgument { command: "focus", expected: "start or stop" })
+    );
+    assert_eq!(
+        Command::parse("send-output agents 0").unwrap_err().message(strings(Language::En)),
+        r#"send-output: "0" is not last, screen or a line count"#
+    );
+    assert_eq!(
+        Command::parse("quit now"),
+        Err(CommandError::UnexpectedArgument { command: "quit", argument: "now".into() })
+    );
+}
+
+#[test]
+fn help_covers_every_command() {
+    let en = strings(Language::En);
+    let lines = help_lines(None, en).unwrap();
+    assert_eq!(lines.len(), COMMANDS.len());
+    assert!(lines[0].starts_with(":quit"));
+    assert_eq!(
+        help_lines(Some("q"), en).unwrap(),
+        [":quit", "Leave augustinus", "Also :q"]
+    );
+    assert_eq!(help_lines(Some("nope"), en), Err(CommandError::Unknown("nope".into())));
+}
+
+fn submit(state: &mut AppState, input: &str) {
+    state.apply(Action::EnterCommandMode);
+    for ch in input.chars() {
+        state.apply(Action::CommandAppend(ch));
+    }
+    state.apply(Action::SubmitCommand);
+}
+
+#[test]
+fn submitting_runs_reports_or_defers() {
+    let mut state = AppState::new_for_test();
+
+    submit(&mut state, "tab new");
+    assert_eq!(state.general_tabs.len(), 2);
+    assert_eq!(state.command, None);
+    assert_eq!(state.last_command.as_deref(), Some("tab new"));
+
+    submit(&mut state, "agent nobody");
+    assert_eq!(state.command.as_deref(), Some("agent nobody"));
+    assert!(matches!(state.command_feedback, Some(CommandFeedback::Error(_))));
+    state.apply(Action::CommandBackspace);
+    assert_eq!(state.command_feedback, None);
+    state.apply(Action::ExitCommandMode);
+
+    submit(&mut state, "help");
+    assert_eq!(state.command.as_deref(), Some(""));
+    assert!(matches!(state.command_feedback, Some(CommandFeedback::Output(_))));
+    state.apply(Action::ExitCommandMode);
+
+    submit(&mut state, "q");
+    assert_eq!(state.command, None);
+    assert_eq!(state.take_command(), Some(Command::Quit));
+    assert_eq!(state.take_command(), None);
+}
@@ -0,0 +1,102 @@
+use augustinus_app::{
+    Action, AppState, CopyMode, CopyMotion, CopyPos, PaneId, SelectionKind, TerminalScreen,
+};
+
+fn history(lines: &[&str]) -> TerminalScreen {
+    let mut screen = TerminalScreen::new(lines.len() as u16, 12);
+    for (row, line) in lines.iter().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            screen.cell_mut(row as u16, col as u16).unwrap().symbol = ch.to_string();
+        }
+    }
+    screen
+}
+
+fn at(line: usize, col: u16) -> CopyPos {
+    CopyPos { line, col }
+}
+
+#[test]
+fn char_selection_spans_lines_from_anchor_to_cursor() {
+    let text = history(&["hello world", "second line", "third"]);
+    let mut copy = CopyMode::new(at(0, 6));
+    copy.toggle_selection(SelectionKind::Char);
+    copy.apply(CopyMotion::Down, 3, 12, 3);
+    copy.apply(CopyMotion::Left, 3, 12, 3);
+    assert_eq!(copy.selected_text(&text), "world\nsecond");
+}
+
+#[test]
+fn selection_works_backwards() {
+    let text = history(&["abc def"]);
+    let mut copy = CopyMode::new(at(0, 6));
+    copy.toggle_selection(SelectionKind::Char);
+    for _ in 0..2 {
+        copy.apply(CopyMotion::Left, 1, 12, 1);
+    }
+    assert_eq!(copy.selected_text(&text), "def");
+}
+
+#[test]
+fn line_and_block_selections() {
+    let text = history(&["a1234", "b5678", "c9"]);
+    let mut copy = CopyMode::new(at(0, 1));
+    copy.toggle_selection(SelectionKind::Line);
+    copy.apply(CopyMotion::Down, 3, 12, 3);
+    assert_eq!(copy.selected_text(&text), "a1234\nb5678");
+
+    copy.toggle_selection(SelectionKind::Block);
+    copy.apply(CopyMotion::Right, 3, 12, 3);
+    copy.apply(CopyMotion::Right, 3, 12, 3);
+    assert_eq!(copy.selected_text(&text), "123\n567");
+    assert!(copy.is_selected(at(1, 2)));
+    assert!(!copy.is_selected(at(1, 0)));
+
+    copy.toggle_selection(SelectionKind::Block);
+    assert_eq!(copy.selected_text(&text), "");
+}
+
+#[test]
+fn motions_stay_inside_history() {
+    let mut copy = CopyMode::new(at(1, 0));
+    copy.apply(CopyMotion::Left, 5, 10, 4);
+    copy.apply(CopyMotion::Up, 5, 10, 4);
+    copy.apply(CopyMotion::Up, 5, 10, 4);
+    assert_eq!(copy.cursor(), at(0, 0));
+    copy.apply(CopyMotion::PageDown, 5, 10, 4);
+    copy.apply(CopyMotion::PageDown, 5, 10, 4);
+    copy.apply(CopyMotion::LineEnd, 5, 10, 4);
+    assert_eq!(copy.cursor(), at(4, 9));
+}
+
+#[test]
+fn entering_starts_at_terminal_cursor_and_moving_up_scrolls_back() {
+    let mut s = AppState::new_for_test();
+    s.focused = PaneId::Agents;
+    let mut screen = TerminalScreen::new(10, 40);
+    screen.cursor_row = 9;
+    screen.cursor_col = 3;
+    s.agents_mut().screen = screen;
+    s.agents_mut().scroll.sync(0, 50, 10);
+
+    s.apply(Action::CopyEnter);
+    assert_eq!(s.agents_mut().copy.unwrap().cursor(), at(59, 3));
+
+    s.apply(Action::CopyMove(CopyMotion::Top));
+    assert!(s.agents_mut().scroll.is_active());
+    assert_eq!(s.agents_mut().scroll.offset(), 50);
+
+    s.apply(Action::CopyExit);
+    assert!(s.agents_mut().copy.is_none());
+    assert!(!s.agents_mut().scroll.is_active());
+}
+
+#[test]
+fn leaving_the_pane_leaves_copy_mode() {
+    let mut s = AppState::new_for_test();
+    s.focused = PaneId::General;
+    s.apply(Action::CopyEnter);
+    assert!(s.general().copy.is_some());
+    s.apply(Action::FocusPane(PaneId::Agents));
+    assert!(s.general().copy.is_none());
+}
@@ -0,0 +1,76 @@
+use augustinus_app::{Action, AppState, PaneId};
+
+#[test]
+fn starts_with_one_tab() {
+    let s = AppState::new_for_test();
+    assert_eq!(s.general_tabs.len(), 1);
+    assert_eq!(s.general_tabs.active_index(), 0);
+}
+
+#[test]
+fn new_tab_opens_after_active_and_becomes_active() {
+    let mut s = AppState::new_for_test();
+    let first = s.general().id();
+    s.apply(Action::TabNew);
+    s.apply(Action::TabNew);
+    assert_eq!(s.general_tabs.len(), 3);
+    assert_eq!(s.general_tabs.active_index(), 2);
+
+    s.apply(Action::TabSelect(0));
+    assert_eq!(s.general().id(), first);
+    s.apply(Action::TabNew);
+    assert_eq!(s.general_tabs.active_index(), 1);
+}
+
+#[test]
+fn next_and_prev_wrap_around() {
+    let mut s = AppState::new_for_test();
+    s.apply(Action::TabNew);
+    s.apply(Action::TabNew);
+    s.apply(Action::TabNext);
+    assert_eq!(s.general_tabs.active_index(), 0);
+    s.apply(Action::TabPrev);
+    assert_eq!(s.general_tabs.active_index(), 2);
+}
+
+#[test]
+fn select_out_of_range_is_ignored() {
+    let mut s = AppState::new_for_test();
+    s.apply(Action::TabNew);
+    s.apply(Action::TabSelect(5));
+    assert_eq!(s.general_tabs.active_index(), 1);
+}
+
+#[test]
+fn last_tab_cannot_be_closed() {
+    let mut s = AppState::new_for_test();
+    s.apply(Action::TabNew);
+    s.apply(Action::TabClose);
+    assert_eq!(s.general_tabs.len(), 1);
+    s.apply(Action::TabClose);
+    assert_eq!(s.general_tabs.len(), 1);
+}
+
+#[test]
+fn switching_tabs_leaves_scroll_mode() {
+    let mut s = AppState::new_for_test();
+    s.focused = PaneId::General;
+    s.general_mut().scroll.sync(0, 30, 10);
+    s.apply(Action::ScrollPageUp);
+    let scrolled = s.general().id();
+    s.apply(Action::TabNew);
+    assert!(!s.general().scroll.is_active());
+    s.apply(Action::TabPrev);
+    assert_eq!(s.general().id(), scrolled);
+    assert_eq!(s.general().scroll.offset(), 0);
+}
+
+#[test]
+fn each_tab_tracks_its_own_process() {
+    let mut s = AppState::new_for_test();
+    s.general_mut().process.on_exit(1);
+    s.apply(Action::TabNew);
+    assert_eq!(s.general().process.exit_code(), None);
+    s.apply(Action::TabPrev);
+    assert_eq!(s.general().process.exit_code(), Some(1));
+}
@@ -0,0 +1,147 @@
+use augustinus_app::{
+    parse_key_sequence, Binding, Key, KeyAction, KeyChord, KeyMatch, KeyMode, Keymap, KeymapError,
+};
+
+fn chord(text: &str) -> KeyChord {
+    KeyChord::parse(text).unwrap()
+}
+
+#[test]
+fn chords_parse_modifiers_and_names() {
+    assert_eq!(chord("Ctrl-a"), KeyChord::new(Key::Char('a'), true, false, false));
+    assert_eq!(chord("C-a"), chord("ctrl-a"));
+    assert_eq!(chord("Ctrl-A"), chord("Ctrl-a"));
+    assert_eq!(KeyChord::new(Key::Char('A'), true, false, false), chord("Ctrl-a"));
+    assert_eq!(chord("Ctrl-Shift-a").key, Key::Char('A'));
+    assert_eq!(chord("M-x"), KeyChord::new(Key::Char('x'), false, true, false));
+    assert_eq!(chord("Shift-g"), chord("G"));
+    assert_eq!(chord("Shift-Tab"), KeyChord::plain(Key::BackTab));
+    assert_eq!(chord("Ctrl--"), KeyChord::new(Key::Char('-'), true, false, false));
+    assert_eq!(chord("-"), KeyChord::plain(Key::Char('-')));
+    assert_eq!(chord("pgdn"), KeyChord::plain(Key::PageDown));
+    assert_eq!(chord("F5"), KeyChord::plain(Key::F(5)));
+    assert_eq!(chord("Space"), KeyChord::plain(Key::Char(' ')));
+    assert_eq!(KeyChord::parse("Ctrl-"), None);
+    assert_eq!(KeyChord::parse("Hyper-a"), None);
+    assert_eq!(KeyChord::parse("F99"), None);
+
+    assert_eq!(chord("c-s-pgup").to_string(), "Ctrl-Shift-PgUp");
+    assert_eq!(chord("alt-space").to_string(), "Alt-Space");
+    let sequence = parse_key_sequence(" Ctrl-a   f ").unwrap();
+    assert_eq!(sequence, [chord("Ctrl-a"), chord("f")]);
+    assert_eq!(parse_key_sequence("  "), None);
+}
+
+#[test]
+fn defaults_cover_the_built_in_keys() {
+    let keymap = Keymap::default();
+    let bound = |mode, keys| keymap.binding(mode, &parse_key_sequence(keys).unwrap()).cloned();
+    assert_eq!(bound(KeyMode::App, "h"), Some(Binding::Action(KeyAction::FocusLeft)));
+    assert_eq!(bound(KeyMode::App, "Ctrl-c"), Some(Binding::Action(KeyAction::Quit)));
+    assert_eq!(bound(KeyMode::App, "3"), Some(Binding::Action(KeyAction::Select(2))));
+    assert_eq!(bound(KeyMode::Terminal, "Ctrl-c"), None);
+    assert_eq!(bound(KeyMode::Terminal, "Esc"), Some(Binding::Action(KeyAction::ExitTerminal)));
+    assert_eq!(bound(KeyMode::Terminal, "Shift-Up"), None);
+    assert_eq!(bound(KeyMode::Terminal, "Shift-Home"), None);
+    assert_eq!(bound(KeyMode::Command, "Ctrl-w"), Some(Binding::Action(KeyAction::DeleteWord)));
+}
+
+#[test]
+fn sequences_wait_for_their_last_key() {
+    let mut keymap = Keymap::default();
+    keymap
+        .configure(KeyMode::Terminal, [("Ctrl-a f", "fullscreen"), ("Ctrl-a t", ":tab new")])
+        .unwrap();
+
+    assert_eq!(keymap.feed(KeyMode::Terminal, chord("Ctrl-a")), KeyMatch::Pending);
+    assert_eq!(keymap.pending(), [chord("Ctrl-a")]);
+    assert_eq!(
+        keymap.feed(KeyMode::Terminal, chord("f")),
+        KeyMatch::Bound(Binding::Action(KeyAction::Fullscreen))
+    );
+    assert!(keymap.pending().is_empty());
+
+    keymap.feed(KeyMode::Terminal, chord("Ctrl-a"));
+    assert_eq!(
+        keymap.feed(KeyMode::Terminal, chord("t")),
+        KeyMatch::Bound(Binding::Command("tab new".into()))
+    );
+
+    keymap.feed(KeyMode::Terminal, chord("Ctrl-a"));
+    assert_eq!(
+        keymap.feed(KeyMode::Terminal, chord("x")),
+        KeyMatch::Unbound(vec![chord("Ctrl-a"), chord("x")])
+    );
+    assert_eq!(
+        keymap.feed(KeyMode::Terminal, chord("x")),
+        KeyMatch::Unbound(vec![chord("x")])
+    );
+}
+
+#[test]
+fn switching_modes_drops_a_pending_sequence() {
+    let mut keymap = Keymap::default();
+    keymap.configure(KeyMode::App, [("g g", "tab-new")]).unwrap();
+    assert_eq!(keymap.feed(KeyMode::App, chord("g")), KeyMatch::Pending);
+    assert_eq!(
+        keymap.feed(KeyMode::Command, chord("g")),
+        KeyMatch::Unbound(vec![chord("g")])
+    );
+    assert_eq!(keymap.feed(KeyMode::App, chord("g")), KeyMatch::Pending);
+}
+
+#[test]
+fn user_bindings_replace_overlapping_defaults() {
+    let mut keymap = Keymap::default();
+    keymap
+        .configure(
+            KeyMode::App,
+            [("h x", "fullscreen"), ("Ctrl-c", "none"), ("Ctrl-q", "quit")],
+        )
+        .unwrap();
+    let sequence = |keys| parse_key_sequence(keys).unwrap();
+    assert_eq!(keymap.binding(KeyMode::App, &sequence("h")), None);
+    assert_eq!(keymap.binding(KeyMode::App, &sequence("Ctrl-c")), None);
+    assert_eq!(
+        keymap.binding(KeyMode::App, &sequence("Ctrl-q")),
+        Some(&Binding::Action(KeyAction::Quit))
+    );
+    assert_eq!(
+        keymap.binding(KeyMode::App, &sequence("j")),
+        Some(&Binding::Action(KeyAction::FocusDown))
+    );
+}
+
+#[test]
+fn bad_tables_are_rejected_whole() {
+    let mut keymap = Keymap::default();
+    let before = keymap.clone();
+
+    assert_eq!(
+        keymap.configure(KeyMode::App, [("Ctrl-a", "tab-new"), ("C-a f", "fullscreen")]),
+        Err(KeymapError::Conflict {
+            mode: KeyMode::App,
+            keys: "C-a f".into(),
+            other: "Ctrl-a".into(),
+        })
+    );
+    assert_eq!(
+        keymap.configure(KeyMode::App, [("x", "tab-new"), ("Ctrl-Hyper-x", "quit")]),
+        Err(KeymapError::InvalidKeys {
+            mode: KeyMode::App,
+            keys: "Ctrl-Hyper-x".into(),
+        })
+    );
+    assert_eq!(
+        keymap.configure(KeyMode::Command, [("Ctrl-f", "focus-left")]),
+        Err(KeymapError::InvalidBinding {
+            mode: KeyMode::Command,
+            keys: "Ctrl-f".into(),
+            binding: "focus-left".into(),
+        })
+    );
+    assert!(keymap.configure(KeyMode::App, [("x", ":nope")]).is_err());
+    assert!(keymap.configure(KeyMode::Terminal, [("x", "activate")]).is_err());
+    assert!(keymap.configure(KeyMode::App, [("x", "select-10")]).is_err());
+    assert_eq!(keymap, before);
+}
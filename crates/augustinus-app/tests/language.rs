@@ -0,0 +1,72 @@
+use augustinus_app::{help_lines, Action, AppState, Command, CommandFeedback, COMMANDS};
+use augustinus_i18n::{strings, Language};
+
+fn submit(state: &mut AppState, input: &str) {
+    state.apply(Action::EnterCommandMode);
+    for ch in input.chars() {
+        state.apply(Action::CommandAppend(ch));
+    }
+    state.apply(Action::SubmitCommand);
+}
+
+#[test]
+fn lang_command_switches_the_ui_and_is_left_to_the_runtime_to_save() {
+    let mut state = AppState::new_for_test();
+    let english_quote = state.motivation.quote();
+    assert_eq!(state.language(), Language::En);
+
+    submit(&mut state, "lang ja");
+    assert_eq!(state.language(), Language::Ja);
+    assert_eq!(state.strings().pane_stats, "統計");
+    assert!(strings(Language::Ja).encouraging_quotes.contains(&state.motivation.quote()));
+    assert_ne!(state.motivation.quote(), english_quote);
+    assert_eq!(state.take_command(), Some(Command::Lang(Language::Ja)));
+
+    state.apply(Action::SetLanguage(Language::En));
+    assert_eq!(state.motivation.quote(), english_quote);
+}
+
+#[test]
+fn command_feedback_follows_the_language() {
+    let mut state = AppState::new_for_test();
+    state.apply(Action::SetLanguage(Language::Fr));
+
+    submit(&mut state, "focus");
+    assert_eq!(
+        state.command_feedback,
+        Some(CommandFeedback::Error("focus : start ou stop attendu".into()))
+    );
+
+    state.apply(Action::ExitCommandMode);
+    submit(&mut state, "agent nobody");
+    assert_eq!(
+        state.command_feedback,
+        Some(CommandFeedback::Error("agent : aucun agent nommé \"nobody\"".into()))
+    );
+
+    state.apply(Action::ExitCommandMode);
+    submit(&mut state, "help quit");
+    assert_eq!(
+        state.command_feedback,
+        Some(CommandFeedback::Output(vec![
+            ":quit".into(),
+            "Quitter augustinus".into(),
+            "Aussi :q".into(),
+        ]))
+    );
+}
+
+#[test]
+fn every_command_has_a_summary_in_every_language() {
+    for language in [Language::En, Language::Fr, Language::Ja] {
+        let strings = strings(language);
+        for spec in COMMANDS {
+            assert!(
+                strings.command_summary(spec.name).is_some(),
+                "{language:?} lacks a summary for :{}",
+                spec.name
+            );
+        }
+        assert_eq!(help_lines(None, strings).unwrap().len(), COMMANDS.len());
+    }
+}
@@ -0,0 +1,83 @@
+use augustinus_app::{
+    Action, AppState, GeneralInputMode, PaneId, PaneLayout, PaneRect, SplitRatios, SPLIT_MAX,
+    SPLIT_MIN, SPLIT_STEP,
+};
+
+fn rect(x: u16, y: u16, width: u16, height: u16) -> PaneRect {
+    PaneRect {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+#[test]
+fn hit_tests_panes_and_translates_screen_cells() {
+    let mut layout = PaneLayout::default();
+    layout.set_pane(PaneId::Motivation, rect(0, 0, 40, 12));
+    layout.set_pane(PaneId::General, rect(40, 0, 40, 12));
+    layout.set_screen(PaneId::General, rect(41, 3, 38, 8), 2);
+
+    assert_eq!(layout.pane_at(10, 5), Some(PaneId::Motivation));
+    assert_eq!(layout.pane_at(40, 0), Some(PaneId::General));
+    assert_eq!(layout.pane_at(10, 20), None);
+
+    assert_eq!(layout.screen_cell_at(PaneId::General, 41, 3), Some((0, 2)));
+    assert_eq!(layout.screen_cell_at(PaneId::General, 45, 5), Some((4, 4)));
+    assert_eq!(layout.screen_cell_at(PaneId::General, 40, 3), None);
+}
+
+#[test]
+fn clicking_the_focused_pane_keeps_terminal_lock() {
+    let mut s = AppState::new_for_test();
+    s.apply(Action::FocusPane(PaneId::General));
+    assert_eq!(s.focused, PaneId::General);
+    s.apply(Action::EnterGeneralTerminalMode);
+
+    s.apply(Action::FocusPane(PaneId::General));
+    assert_eq!(s.general_input_mode, GeneralInputMode::TerminalLocked);
+
+    s.apply(Action::FocusPane(PaneId::Stats));
+    assert_eq!(s.focused, PaneId::Stats);
+    assert_eq!(s.general_input_mode, GeneralInputMode::AppControls);
+}
+
+#[test]
+fn splits_tile_the_screen_without_gaps() {
+    let screen = rect(0, 0, 81, 25);
+    let splits = SplitRatios::new(60, 30, 75);
+    assert_eq!(splits.pane_rect(screen, PaneId::Motivation), rect(0, 0, 24, 15));
+    assert_eq!(splits.pane_rect(screen, PaneId::General), rect(24, 0, 57, 15));
+    assert_eq!(splits.pane_rect(screen, PaneId::Agents), rect(0, 15, 61, 10));
+    assert_eq!(splits.pane_rect(screen, PaneId::Stats), rect(61, 15, 20, 10));
+
+    assert_eq!(SplitRatios::new(0, 100, 50), SplitRatios::new(SPLIT_MIN, SPLIT_MAX, 50));
+}
+
+#[test]
+fn resizing_grows_the_focused_pane_and_reports_the_change() {
+    let mut s = AppState::new_for_test();
+    assert_eq!(s.take_splits_change(), None);
+
+    s.apply(Action::FocusPane(PaneId::General));
+    s.apply(Action::GrowPaneWidth);
+    s.apply(Action::GrowPaneHeight);
+    assert_eq!(s.splits, SplitRatios::new(50 + SPLIT_STEP, 50 - SPLIT_STEP, 50));
+
+    s.apply(Action::FocusPane(PaneId::Stats));
+    s.apply(Action::ShrinkPaneWidth);
+    assert_eq!(s.splits.bottom, 50 + SPLIT_STEP);
+    assert_eq!(s.take_splits_change(), Some(s.splits));
+    assert_eq!(s.take_splits_change(), None);
+
+    for _ in 0..20 {
+        s.apply(Action::ShrinkPaneWidth);
+    }
+    assert_eq!(s.splits.bottom, SPLIT_MAX);
+    s.apply(Action::ResetSplits);
+    assert_eq!(s.splits, SplitRatios::default());
+    assert!(s.take_splits_change().is_some());
+    s.apply(Action::ResetSplits);
+    assert_eq!(s.take_splits_change(), None);
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use augustinus_app::{Action, AppState, PaneId, ProcessLifecycle, ProcessStatus};
+
+#[test]
+fn crash_loop_backs_off_exponentially() {
+    let mut p = ProcessLifecycle::new(true);
+    p.on_exit(1);
+    assert_eq!(p.status(), ProcessStatus::Exited { code: 1 });
+    assert_eq!(p.restart_in(), Some(Duration::from_secs(1)));
+
+    p.tick(Duration::from_secs(1));
+    assert!(p.take_restart_request());
+    p.on_spawned();
+
+    p.tick(Duration::from_secs(2));
+    p.on_exit(1);
+    assert_eq!(p.restart_in(), Some(Duration::from_secs(2)));
+    p.tick(Duration::from_secs(1));
+    assert!(!p.take_restart_request());
+}
+
+#[test]
+fn clean_exit_waits_for_manual_restart() {
+    let mut p = ProcessLifecycle::new(true);
+    p.on_exit(0);
+    assert_eq!(p.restart_in(), None);
+    p.tick(Duration::from_secs(120));
+    assert!(!p.take_restart_request());
+}
+
+#[test]
+fn respawn_action_requests_restart_for_pane() {
+    let mut s = AppState::new_for_test();
+    s.general_mut().process.on_exit(130);
+    assert_eq!(s.general().process.exit_code(), Some(130));
+    s.apply(Action::Respawn(PaneId::General));
+    assert!(s.general_mut().process.take_restart_request());
+    assert!(!s.agents_mut().process.take_restart_request());
+}
+
+#[test]
+fn failed_respawn_retries_after_the_next_backoff_step() {
+    let mut p = ProcessLifecycle::new(true);
+    p.on_exit(1);
+    p.tick(Duration::from_secs(1));
+    assert!(p.take_restart_request());
+
+    p.on_spawn_failed();
+    assert_eq!(p.status(), ProcessStatus::Exited { code: 1 });
+    assert_eq!(p.restart_in(), Some(Duration::from_secs(2)));
+    p.tick(Duration::from_secs(2));
+    assert!(p.take_restart_request());
+}
+
+#[test]
+fn start_failure_keeps_the_reason_until_a_spawn() {
+    let mut p = ProcessLifecycle::new(true);
+    p.on_start_failed("aider is not on PATH".into());
+    assert_eq!(p.exit_code(), Some(127));
+    assert_eq!(p.start_error(), Some("aider is not on PATH"));
+    p.tick(Duration::from_secs(120));
+    assert!(!p.take_restart_request());
+
+    p.request_restart();
+    assert!(p.take_restart_request());
+    p.on_spawned();
+    assert_eq!(p.status(), ProcessStatus::Running);
+    assert_eq!(p.start_error(), None);
+}
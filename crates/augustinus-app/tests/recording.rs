@@ -0,0 +1,33 @@
+use augustinus_app::{Action, AppState, PaneId};
+
+#[test]
+fn record_actions_queue_requests_for_the_runtime() {
+    let mut s = AppState::new_for_test();
+    s.apply(Action::RecordStart(PaneId::Agents));
+    assert_eq!(s.agents_mut().recording.take_request(), Some(true));
+    assert_eq!(s.agents_mut().recording.take_request(), None);
+
+    s.apply(Action::RecordStop(PaneId::General));
+    assert_eq!(s.general_mut().recording.take_request(), Some(false));
+}
+
+#[test]
+fn general_recording_follows_the_active_tab() {
+    let mut s = AppState::new_for_test();
+    s.apply(Action::RecordStart(PaneId::General));
+    s.general_mut().recording.take_request();
+    s.general_mut().recording.set_active(true);
+
+    s.apply(Action::TabNew);
+    assert!(!s.general().recording.is_active());
+    s.apply(Action::TabPrev);
+    assert!(s.general().recording.is_active());
+}
+
+#[test]
+fn non_terminal_panes_ignore_record_actions() {
+    let mut s = AppState::new_for_test();
+    s.apply(Action::RecordStart(PaneId::Stats));
+    assert_eq!(s.general_mut().recording.take_request(), None);
+    assert_eq!(s.agents_mut().recording.take_request(), None);
+}
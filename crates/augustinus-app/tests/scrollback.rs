@@ -0,0 +1,38 @@
+use augustinus_app::{Action, AppState, PaneId};
+
+#[test]
+fn page_up_enters_scroll_and_clamps_to_history() {
+    let mut s = AppState::new_for_test();
+    s.focused = PaneId::General;
+    s.general_mut().scroll.sync(0, 30, 20);
+
+    s.apply(Action::ScrollPageUp);
+    assert!(s.general().scroll.is_active());
+    assert_eq!(s.general().scroll.offset(), 20);
+
+    s.apply(Action::ScrollPageUp);
+    assert_eq!(s.general().scroll.offset(), 30);
+
+    s.apply(Action::ScrollLineDown);
+    assert_eq!(s.general().scroll.offset(), 29);
+
+    s.apply(Action::ScrollBottom);
+    assert_eq!(s.general().scroll.offset(), 0);
+    assert!(s.general().scroll.is_active());
+
+    s.apply(Action::ExitScroll);
+    assert!(!s.general().scroll.is_active());
+}
+
+#[test]
+fn leaving_the_pane_exits_scroll() {
+    let mut s = AppState::new_for_test();
+    s.focused = PaneId::Agents;
+    s.agents_mut().scroll.sync(0, 100, 10);
+    s.apply(Action::ScrollTop);
+    assert_eq!(s.agents_mut().scroll.offset(), 100);
+
+    s.apply(Action::FocusUp); // Agents -> Motivation
+    assert!(!s.agents_mut().scroll.is_active());
+    assert_eq!(s.agents_mut().scroll.offset(), 0);
+}
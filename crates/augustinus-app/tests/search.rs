@@ -0,0 +1,141 @@
+use augustinus_app::{Action, AppState, PaneId, ScrollState, SearchMatch, SearchState, TerminalScreen};
+
+fn history(lines: &[&str]) -> TerminalScreen {
+    let mut screen = TerminalScreen::new(lines.len() as u16, 20);
+    for (row, line) in lines.iter().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            screen.cell_mut(row as u16, col as u16).unwrap().symbol = ch.to_string();
+        }
+    }
+    screen
+}
+
+fn submit(search: &mut SearchState, query: &str) {
+    search.open_prompt();
+    for ch in query.chars() {
+        search.push(ch);
+    }
+    search.submit();
+}
+
+#[test]
+fn literal_query_finds_every_occurrence_and_starts_at_newest() {
+    let mut search = SearchState::default();
+    submit(&mut search, "err");
+    assert!(search.take_run_request());
+    search.run(&history(&["ok", "error: x", "x err err"]));
+
+    assert_eq!(
+        search.matches(),
+        &[
+            SearchMatch { line: 1, start_col: 0, end_col: 3 },
+            SearchMatch { line: 2, start_col: 2, end_col: 5 },
+            SearchMatch { line: 2, start_col: 6, end_col: 9 },
+        ]
+    );
+    assert_eq!(search.current_index(), Some(2));
+    assert_eq!(search.take_jump().map(|m| m.start_col), Some(6));
+    assert_eq!(search.take_jump(), None);
+}
+
+#[test]
+fn n_walks_towards_older_matches_and_wraps() {
+    let mut search = SearchState::default();
+    submit(&mut search, "a");
+    search.run(&history(&["a", "a", "a"]));
+
+    search.next();
+    assert_eq!(search.current_index(), Some(1));
+    search.next();
+    search.next();
+    assert_eq!(search.current_index(), Some(2));
+    search.prev();
+    assert_eq!(search.current_index(), Some(0));
+}
+
+#[test]
+fn smart_case_and_regex_mode() {
+    let mut search = SearchState::default();
+    submit(&mut search, "Warn");
+    search.run(&history(&["warn", "Warn"]));
+    assert_eq!(search.matches().len(), 1);
+
+    search.toggle_regex();
+    submit(&mut search, "e[0-9]+");
+    search.run(&history(&["e12 e", "e.5"]));
+    assert_eq!(search.matches().len(), 1);
+    assert_eq!(search.matches()[0].end_col, 3);
+
+    search.toggle_regex();
+    submit(&mut search, "e.5");
+    search.run(&history(&["e12 e", "e.5"]));
+    assert_eq!(search.matches()[0].line, 1);
+}
+
+#[test]
+fn invalid_regex_reports_an_error() {
+    let mut search = SearchState::default();
+    search.toggle_regex();
+    submit(&mut search, "(");
+    search.run(&history(&["("]));
+    assert!(search.error().is_some());
+    assert!(search.matches().is_empty());
+}
+
+#[test]
+fn wide_characters_map_to_screen_columns() {
+    let mut screen = TerminalScreen::new(1, 10);
+    screen.cell_mut(0, 0).unwrap().symbol = "日".to_string();
+    screen.cell_mut(0, 1).unwrap().wide_continuation = true;
+    screen.cell_mut(0, 2).unwrap().symbol = "x".to_string();
+    let mut search = SearchState::default();
+    submit(&mut search, "x");
+    search.run(&screen);
+    assert_eq!(search.matches()[0].start_col, 2);
+}
+
+#[test]
+fn reveal_scrolls_a_match_into_view() {
+    let mut scroll = ScrollState::default();
+    scroll.sync(0, 100, 10);
+    scroll.reveal(105);
+    assert!(!scroll.is_active());
+
+    scroll.reveal(20);
+    assert!(scroll.is_active());
+    assert_eq!(scroll.offset(), 85);
+    let top = scroll.max_offset() - scroll.offset();
+    assert!((top..top + 10).contains(&20));
+}
+
+#[test]
+fn search_actions_target_the_focused_terminal_and_focus_change_cancels_prompt() {
+    let mut s = AppState::new_for_test();
+    s.focused = PaneId::Agents;
+    s.apply(Action::SearchOpen);
+    s.apply(Action::SearchAppend('x'));
+    assert_eq!(s.agents_mut().search.prompt(), Some("x"));
+    assert!(!s.general().search.is_prompting());
+
+    s.apply(Action::FocusPane(PaneId::General));
+    assert!(!s.agents_mut().search.is_prompting());
+}
+
+#[test]
+fn matches_follow_lines_dropped_from_the_scrollback() {
+    let mut search = SearchState::default();
+    search.set_origin(10);
+    submit(&mut search, "x");
+    search.run(&history(&["x", "-", "x", "x"]));
+    search.prev();
+    assert_eq!(search.current_match().map(|m| m.line), Some(0));
+
+    search.set_origin(11);
+    let lines: Vec<usize> = search.matches().iter().map(|m| m.line).collect();
+    assert_eq!(lines, [1, 2]);
+    assert_eq!(search.current_match().map(|m| m.line), Some(1));
+
+    search.set_origin(13);
+    assert_eq!(search.matches().len(), 1);
+    assert_eq!(search.current_match().map(|m| m.line), Some(0));
+}
@@ -0,0 +1,93 @@
+use std::time::Instant;
+
+use augustinus_app::{
+    capture, frame_for_agent, Action, AgentSend, AppState, SendSource, ShellIntegration,
+    ShellMark, ShellMarkKind, TerminalScreen,
+};
+
+/// History with the cursor after the text of the last line and two blank
+/// rows below it, as on a fresh screen.
+fn history(lines: &[&str]) -> TerminalScreen {
+    let mut screen = TerminalScreen::new(lines.len() as u16 + 2, 30);
+    for (row, line) in lines.iter().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            screen.cell_mut(row as u16, col as u16).unwrap().symbol = ch.to_string();
+        }
+    }
+    screen.cursor_row = lines.len() as u16 - 1;
+    screen.cursor_col = lines.last().unwrap().chars().count() as u16;
+    screen
+}
+
+#[test]
+fn last_command_runs_from_previous_prompt_to_cursor() {
+    let screen = history(&[
+        "$ ls",
+        "a b",
+        "$ cargo test",
+        "test x ... FAILED",
+        "",
+        "$ ",
+    ]);
+    assert_eq!(
+        capture(&screen, 4, SendSource::LastCommand, &ShellIntegration::default()),
+        "$ cargo test\ntest x ... FAILED"
+    );
+}
+
+#[test]
+fn last_command_without_earlier_prompt_falls_back_to_screen() {
+    let screen = history(&["old", "boot log", "> "]);
+    let shell = ShellIntegration::default();
+    assert_eq!(capture(&screen, 4, SendSource::LastCommand, &shell), "boot log");
+}
+
+#[test]
+fn last_command_comes_from_shell_marks_when_loaded() {
+    let screen = history(&[
+        "> make",
+        "cc main.c",
+        "> main.c:3: warning",
+        "",
+        "> ",
+    ]);
+    let mark = |kind, line| ShellMark { kind, line, col: 0, at: Instant::now() };
+    let mut shell = ShellIntegration::default();
+    shell.on_mark(mark(ShellMarkKind::PromptStart, 0));
+    shell.on_mark(mark(ShellMarkKind::OutputStart { command: "make".into() }, 1));
+    shell.on_mark(mark(ShellMarkKind::CommandFinished { exit_code: Some(0) }, 4));
+    shell.on_mark(mark(ShellMarkKind::PromptStart, 4));
+    assert_eq!(
+        capture(&screen, 4, SendSource::LastCommand, &shell),
+        "> make\ncc main.c\n> main.c:3: warning"
+    );
+}
+
+#[test]
+fn screen_and_line_counts_cover_the_bottom_of_history() {
+    let screen = history(&["one", "two", "three", "four"]);
+    let shell = ShellIntegration::default();
+    assert_eq!(capture(&screen, 4, SendSource::Screen, &shell), "three\nfour");
+    assert_eq!(capture(&screen, 3, SendSource::Lines(2), &shell), "three\nfour");
+    assert_eq!(capture(&screen, 3, SendSource::Lines(99), &shell), "one\ntwo\nthree\nfour");
+}
+
+#[test]
+fn output_is_fenced_under_a_header() {
+    assert_eq!(
+        frame_for_agent("boom", SendSource::Lines(3)),
+        "Here are the last 3 lines of my terminal:\n```\nboom\n```\n"
+    );
+    assert!(frame_for_agent("```rust", SendSource::Screen).contains("~~~~\n```rust\n~~~~"));
+}
+
+#[test]
+fn send_output_is_queued_for_the_runtime() {
+    let mut state = AppState::new_for_test();
+    state.apply(Action::SendOutput(SendSource::Screen));
+    assert_eq!(
+        state.take_agent_send(),
+        Some(AgentSend::Output(SendSource::Screen))
+    );
+    assert_eq!(state.take_agent_send(), None);
+}
@@ -0,0 +1,118 @@
+use std::time::{Duration, Instant};
+
+use augustinus_app::{
+    Action, AppState, CopyPos, FinishedCommand, PaneId, ShellIntegration, ShellMark,
+    ShellMarkKind,
+};
+
+fn mark(kind: ShellMarkKind, line: u64, col: u16, at: Instant) -> ShellMark {
+    ShellMark { kind, line, col, at }
+}
+
+/// Prompts on lines 0, 10 and 30; `ls` prints lines 1..=9 and `false` prints
+/// nothing.
+fn three_prompts(shell: &mut ShellIntegration, start: Instant) {
+    let later = start + Duration::from_millis(250);
+    shell.on_mark(mark(ShellMarkKind::CommandFinished { exit_code: Some(0) }, 0, 0, start));
+    shell.on_mark(mark(ShellMarkKind::PromptStart, 0, 0, start));
+    shell.on_mark(mark(ShellMarkKind::CommandStart, 0, 2, start));
+    shell.on_mark(mark(ShellMarkKind::OutputStart { command: "ls".into() }, 1, 0, start));
+    shell.on_mark(mark(ShellMarkKind::CommandFinished { exit_code: Some(0) }, 10, 0, later));
+    shell.on_mark(mark(ShellMarkKind::PromptStart, 10, 0, later));
+    shell.on_mark(mark(ShellMarkKind::OutputStart { command: "false".into() }, 11, 0, later));
+    shell.on_mark(mark(ShellMarkKind::CommandFinished { exit_code: Some(1) }, 11, 0, later));
+    shell.on_mark(mark(ShellMarkKind::PromptStart, 30, 0, later));
+}
+
+#[test]
+fn finished_commands_are_reported_once() {
+    let start = Instant::now();
+    let mut shell = ShellIntegration::default();
+    three_prompts(&mut shell, start);
+
+    assert_eq!(
+        shell.take_finished(),
+        [
+            FinishedCommand {
+                command: "ls".into(),
+                exit_code: Some(0),
+                duration: Duration::from_millis(250),
+            },
+            FinishedCommand {
+                command: "false".into(),
+                exit_code: Some(1),
+                duration: Duration::ZERO,
+            },
+        ]
+    );
+    assert!(shell.take_finished().is_empty());
+}
+
+#[test]
+fn prompt_jumps_follow_the_history_origin() {
+    let mut shell = ShellIntegration::default();
+    three_prompts(&mut shell, Instant::now());
+
+    assert_eq!(shell.prev_prompt(20), Some(10));
+    assert_eq!(shell.next_prompt(10), Some(30));
+    assert_eq!(shell.next_prompt(30), None);
+
+    shell.set_origin(5);
+    assert_eq!(shell.prev_prompt(20), Some(5));
+    assert_eq!(shell.prev_prompt(5), None);
+}
+
+#[test]
+fn last_output_spans_the_latest_commands_output() {
+    let mut shell = ShellIntegration::default();
+    three_prompts(&mut shell, Instant::now());
+    // `false` printed nothing.
+    assert_eq!(shell.last_output(), None);
+
+    let mut shell = ShellIntegration::default();
+    let now = Instant::now();
+    shell.on_mark(mark(ShellMarkKind::PromptStart, 0, 0, now));
+    shell.on_mark(mark(ShellMarkKind::OutputStart { command: "ls".into() }, 1, 0, now));
+    shell.on_mark(mark(ShellMarkKind::CommandFinished { exit_code: Some(0) }, 4, 0, now));
+    assert_eq!(shell.last_output(), Some((1, 3)));
+
+    // Output without a trailing newline ends on the finish line.
+    shell.on_mark(mark(ShellMarkKind::PromptStart, 4, 0, now));
+    shell.on_mark(mark(ShellMarkKind::OutputStart { command: "printf x".into() }, 5, 0, now));
+    shell.on_mark(mark(ShellMarkKind::CommandFinished { exit_code: Some(0) }, 5, 1, now));
+    assert_eq!(shell.last_output(), Some((5, 5)));
+}
+
+#[test]
+fn prompt_actions_scroll_and_select_output() {
+    let mut s = AppState::new_for_test();
+    s.focused = PaneId::General;
+    s.general_mut().screen = augustinus_app::TerminalScreen::new(10, 40);
+    s.general_mut().scroll.sync(0, 30, 10);
+    three_prompts(&mut s.general_mut().shell, Instant::now());
+
+    // The view starts at history line 30, which is the last prompt.
+    s.apply(Action::PromptPrev);
+    assert_eq!(s.general().scroll.offset(), 20);
+    s.apply(Action::PromptPrev);
+    assert_eq!(s.general().scroll.offset(), 30);
+    s.apply(Action::PromptNext);
+    assert_eq!(s.general().scroll.offset(), 20);
+    s.apply(Action::PromptNext);
+    assert_eq!(s.general().scroll.offset(), 0);
+
+    let mut s = AppState::new_for_test();
+    s.focused = PaneId::General;
+    s.general_mut().screen = augustinus_app::TerminalScreen::new(10, 40);
+    let now = Instant::now();
+    let shell = &mut s.general_mut().shell;
+    shell.on_mark(mark(ShellMarkKind::PromptStart, 0, 0, now));
+    shell.on_mark(mark(ShellMarkKind::OutputStart { command: "ls".into() }, 1, 0, now));
+    shell.on_mark(mark(ShellMarkKind::CommandFinished { exit_code: Some(0) }, 4, 0, now));
+    s.apply(Action::SelectLastOutput);
+    let copy = s.general().copy.expect("copy mode");
+    assert!(copy.is_selected(CopyPos { line: 1, col: 0 }));
+    assert!(copy.is_selected(CopyPos { line: 3, col: 39 }));
+    assert!(!copy.is_selected(CopyPos { line: 0, col: 0 }));
+    assert!(!copy.is_selected(CopyPos { line: 4, col: 0 }));
+}
@@ -0,0 +1,169 @@
+use crate::Strings;
+
+pub(crate) const STRINGS: Strings = Strings {
+    app_name: "AUGUSTINUS",
+
+    pane_motivation: "MOTIVATION",
+    pane_general: "GENERAL",
+    pane_agents: "AI AGENTS",
+    pane_stats: "STATS",
+
+    agent_busy: "busy",
+    agent_waiting: "waiting",
+    agent_exited: "exited",
+    agent_unnamed: "agent",
+
+    general_locked_hint: "TERMINAL MODE (locked) — Esc to return to app controls",
+    general_focused_hint: "Enter: terminal mode; 1-9: tabs; /: search; [: copy; p: paste; s: send to agent; h/j/k/l Tab: focus; \":\" commands",
+    general_unfocused_hint: "Press Enter to fullscreen; Focus with h/j/k/l; \":\" commands",
+    starting_shell: "Starting shell…",
+
+    agents_locked_hint: "{0} LOCKED — Esc to return to pane controls",
+    agents_focused_hint: "Enter: control {0}  1-9: agents  /: search  [: copy  p: paste  h/j/k/l Tab: move focus",
+    agents_unfocused_hint: "Focus with h/j/k/l; Enter to lock; \":\" commands",
+    starting_agent: "Starting {0}…",
+    agent_not_found: "{0} is not on PATH",
+    picker_title: "No configured agent found on PATH. Start one of these:",
+    picker_hint: "↑/↓: move  Enter: start  1-9: pick",
+
+    scroll_header: "SCROLL — scrolled {0} lines",
+    scroll_hint: " of {0}  k/j PgUp/PgDn g/G  q: live",
+    copy_header: "COPY",
+    copy_selecting: "COPY — selecting",
+    copy_selecting_lines: "COPY — selecting lines",
+    copy_selecting_block: "COPY — selecting block",
+    copy_hint: "  h/j/k/l move  v/V/Ctrl-V select  y: yank  q: quit",
+    search_mode_regex: "regex",
+    search_mode_text: "text",
+    search_prompt_hint: "  [{0}]  Enter: search  Ctrl-R: regex  Esc: cancel",
+    search_invalid_regex: "invalid regex: {0}",
+    search_match: "match {0} of {1}",
+    search_no_matches: "no matches",
+    search_header: "SEARCH \"{0}\" — {1}",
+    search_hint: "  n/N: older/newer  Esc: clear",
+    process_exited: "[process exited with code {0}] — press r to restart",
+    process_start_failed: "[could not start: {0}] — press r to retry",
+    process_auto_restart: " (auto-restart in {0}s)",
+
+    tone_brutal: "Brutal",
+    tone_encouraging: "Encouraging",
+    tone_emperor: "Emperor",
+    badge_idle: "• IDLE",
+    badge_live: "• LIVE",
+    badge_focus_active: "FOCUS ACTIVE",
+    badge_focus_ready: "FOCUS READY",
+    banner_wake_up: "WAKE UP",
+    banner_lock_in: "LOCK IN",
+    banner_streak: "STREAK {0}",
+    banner_deep_work: "DEEP WORK",
+    quote_title: "NOW",
+    label_streak: "STREAK",
+    label_today: "TODAY",
+    label_goal: "GOAL",
+    days_count: "{0} day(s)",
+    ticker: "LOCK IN • NO MERCY • COMPOUND TODAY • STAY DANGEROUS • ONE MORE REP •",
+    brutal_quotes: &[
+        "Your biological clock advances whether you code or not.",
+        "Every unfocused hour is stolen from your future children.",
+        "Comfort is the tax you pay for mediocrity.",
+        "Entropy wins unless you resist it.",
+        "Your body is decaying in the background. Work anyway.",
+        "You will not get these years back. Stop negotiating.",
+        "If you drift today, your future self will pay interest.",
+        "The calendar doesn't care about your mood.",
+        "A soft day is a vote for a smaller life.",
+    ],
+    encouraging_quotes: &[
+        "You are building something compounding.",
+        "Consistency is intelligence applied daily.",
+        "One disciplined day changes your trajectory.",
+        "You are closer than you think.",
+        "Keep the chain unbroken. The future is watching.",
+        "Deep work is a promise you keep to your descendants.",
+        "Small clean sessions become a life you recognize.",
+        "Earn tomorrow by honoring the next 60 minutes.",
+    ],
+    emperor_quotes: &[
+        "You were not built for small outcomes.",
+        "Discipline is sovereignty.",
+        "AUGUSTINUS does not drift.",
+        "Legacy is constructed in silence.",
+        "Your name can outlive your biology. Act like it.",
+        "The empire is built in the hours nobody applauds.",
+        "You don't need permission to become inevitable.",
+        "Let the work be royal. Let the routine be law.",
+    ],
+
+    card_streak: "STREAK",
+    card_focus: "FOCUS",
+    card_loc: "LOC",
+    card_status: "STATUS",
+    days: "days",
+    loc_net: "net {0}",
+    loc_unavailable: "git diff: N/A",
+    compact_loc: "LOC: +{0} / -{1}",
+    compact_loc_unavailable: "LOC: N/A",
+    compact_streak: "Streak: {0} day(s)",
+    compact_focus: "Focus: {0} / {1}",
+    compact_commands: "Commands: {0}",
+    commands_summary: "{0} today / {1} failed",
+    status_key: "Key",
+    status_value: "Value",
+    status_focused: "Focused",
+    status_fullscreen: "Fullscreen",
+    status_focus_active: "Focus active",
+    status_commands: "Commands",
+    status_idle: "Idle",
+    status_agents: "Agents",
+    status_tone: "Tone",
+    status_last_command: "Last command",
+    status_motivation: "Motivation",
+    status_general: "General",
+    status_agents_pane: "Agents",
+    status_stats: "Stats",
+    yes: "Yes",
+    no: "No",
+
+    command_title: "COMMAND",
+    command_run: "run",
+    command_complete: "complete",
+    command_cancel: "cancel",
+    command_aliases: "Also {0}",
+    record_started: "record: recording to {0}",
+    record_saved: "record: saved {0}",
+    command_summaries: &[
+        ("quit", "Leave augustinus"),
+        ("help", "List commands, or describe one"),
+        ("focus", "Start or stop a focus session"),
+        ("tab", "Manage GENERAL tabs"),
+        ("respawn", "Restart a terminal's process"),
+        ("agent", "Switch to a configured agent"),
+        ("send", "Paste text into the agent"),
+        ("send-output", "Paste GENERAL output into the agent"),
+        ("record", "Record a terminal to an asciinema file"),
+        ("lang", "Set the interface language"),
+    ],
+    error_empty: "empty command",
+    error_unterminated_quote: "unterminated quote",
+    error_unknown: "unknown command {0}, see :help",
+    error_missing_argument: "{0}: expected {1}",
+    error_invalid_argument: "{0}: {1} is not {2}",
+    error_unexpected_argument: "{0}: unexpected argument {1}",
+    expected: &[],
+    error_no_agent: "agent: no agent named {0}",
+    error_no_focus_session: "focus: no focus session is running",
+    error_lang_save: "lang: could not save the config: {0}",
+    error_splits_save: "could not save the pane sizes: {0}",
+    error_keys_invalid: "[{0}] {1} is not a key sequence",
+    error_keys_binding: "[{0}] {1}: cannot bind {2} in this mode",
+    error_keys_conflict: "[{0}] {1} overlaps {2}",
+    error_waiting_pattern: "agent {0}: waiting pattern {1} is not a valid regex",
+    error_respawn: "respawn {0}: {1}",
+    error_record: "record: {0}",
+    error_stats_save: "could not save the command stats: {0}",
+    agent_waiting_notification: "{0} is waiting for you",
+
+    select_language: "Select language",
+    language: "Language",
+    language_hint: "j/k to move, Enter to confirm (↑/↓ also works)",
+};
@@ -0,0 +1,178 @@
+use crate::Strings;
+
+pub(crate) const STRINGS: Strings = Strings {
+    app_name: "AUGUSTINUS",
+
+    pane_motivation: "MOTIVATION",
+    pane_general: "GÉNÉRAL",
+    pane_agents: "AGENTS IA",
+    pane_stats: "STATS",
+
+    agent_busy: "occupé",
+    agent_waiting: "en attente",
+    agent_exited: "terminé",
+    agent_unnamed: "agent",
+
+    general_locked_hint: "MODE TERMINAL (verrouillé) — Échap pour revenir aux commandes de l'app",
+    general_focused_hint: "Entrée : mode terminal ; 1-9 : onglets ; / : chercher ; [ : copier ; p : coller ; s : envoyer à l'agent ; h/j/k/l Tab : focus ; \":\" commandes",
+    general_unfocused_hint: "Entrée pour le plein écran ; focus avec h/j/k/l ; \":\" commandes",
+    starting_shell: "Démarrage du shell…",
+
+    agents_locked_hint: "{0} VERROUILLÉ — Échap pour revenir aux commandes du panneau",
+    agents_focused_hint: "Entrée : contrôler {0}  1-9 : agents  / : chercher  [ : copier  p : coller  h/j/k/l Tab : déplacer le focus",
+    agents_unfocused_hint: "Focus avec h/j/k/l ; Entrée pour verrouiller ; \":\" commandes",
+    starting_agent: "Démarrage de {0}…",
+    agent_not_found: "{0} est introuvable dans le PATH",
+    picker_title: "Aucun agent configuré trouvé dans le PATH. Démarrez l'un de ceux-ci :",
+    picker_hint: "↑/↓ : déplacer  Entrée : démarrer  1-9 : choisir",
+
+    scroll_header: "DÉFILEMENT — {0} lignes plus haut",
+    scroll_hint: " sur {0}  k/j PgUp/PgDn g/G  q : direct",
+    copy_header: "COPIE",
+    copy_selecting: "COPIE — sélection",
+    copy_selecting_lines: "COPIE — sélection de lignes",
+    copy_selecting_block: "COPIE — sélection de bloc",
+    copy_hint: "  h/j/k/l déplacer  v/V/Ctrl-V sélectionner  y : copier  q : quitter",
+    search_mode_regex: "regex",
+    search_mode_text: "texte",
+    search_prompt_hint: "  [{0}]  Entrée : chercher  Ctrl-R : regex  Échap : annuler",
+    search_invalid_regex: "regex invalide : {0}",
+    search_match: "résultat {0} sur {1}",
+    search_no_matches: "aucun résultat",
+    search_header: "RECHERCHE « {0} » — {1}",
+    search_hint: "  n/N : plus ancien/plus récent  Échap : effacer",
+    process_exited: "[processus terminé avec le code {0}] — r pour redémarrer",
+    process_start_failed: "[démarrage impossible : {0}] — r pour réessayer",
+    process_auto_restart: " (redémarrage auto dans {0} s)",
+
+    tone_brutal: "Brutal",
+    tone_encouraging: "Encourageant",
+    tone_emperor: "Empereur",
+    badge_idle: "• INACTIF",
+    badge_live: "• ACTIF",
+    badge_focus_active: "FOCUS EN COURS",
+    badge_focus_ready: "FOCUS PRÊT",
+    banner_wake_up: "REVEILLE",
+    banner_lock_in: "CONCENTRE",
+    banner_streak: "SERIE {0}",
+    banner_deep_work: "AU TRAVAIL",
+    quote_title: "MAINTENANT",
+    label_streak: "SÉRIE",
+    label_today: "AUJOURD'HUI",
+    label_goal: "OBJECTIF",
+    days_count: "{0} jour(s)",
+    ticker: "CONCENTRE-TOI • SANS PITIÉ • CAPITALISE AUJOURD'HUI • RESTE REDOUTABLE • ENCORE UNE •",
+    brutal_quotes: &[
+        "Ton horloge biologique avance, que tu codes ou non.",
+        "Chaque heure dispersée est volée à tes futurs enfants.",
+        "Le confort est l'impôt que tu paies pour la médiocrité.",
+        "L'entropie gagne si tu ne lui résistes pas.",
+        "Ton corps se dégrade en arrière-plan. Travaille quand même.",
+        "Tu ne récupéreras pas ces années. Arrête de négocier.",
+        "Si tu dérives aujourd'hui, ton futur toi paiera les intérêts.",
+        "Le calendrier se moque de ton humeur.",
+        "Une journée molle est un vote pour une vie plus petite.",
+    ],
+    encouraging_quotes: &[
+        "Tu construis quelque chose qui se capitalise.",
+        "La constance, c'est l'intelligence appliquée chaque jour.",
+        "Une journée disciplinée change ta trajectoire.",
+        "Tu es plus près que tu ne le crois.",
+        "Ne brise pas la chaîne. L'avenir te regarde.",
+        "Le travail profond est une promesse faite à tes descendants.",
+        "De petites sessions propres font une vie que tu reconnais.",
+        "Mérite demain en honorant les 60 prochaines minutes.",
+    ],
+    emperor_quotes: &[
+        "Tu n'as pas été fait pour de petits résultats.",
+        "La discipline est souveraineté.",
+        "AUGUSTINUS ne dérive pas.",
+        "L'héritage se construit en silence.",
+        "Ton nom peut survivre à ta biologie. Agis en conséquence.",
+        "L'empire se bâtit dans les heures que personne n'applaudit.",
+        "Tu n'as pas besoin de permission pour devenir inévitable.",
+        "Que le travail soit royal. Que la routine soit loi.",
+    ],
+
+    card_streak: "SÉRIE",
+    card_focus: "FOCUS",
+    card_loc: "LIGNES",
+    card_status: "ÉTAT",
+    days: "jours",
+    loc_net: "net {0}",
+    loc_unavailable: "git diff : N/D",
+    compact_loc: "Lignes : +{0} / -{1}",
+    compact_loc_unavailable: "Lignes : N/D",
+    compact_streak: "Série : {0} jour(s)",
+    compact_focus: "Focus : {0} / {1}",
+    compact_commands: "Commandes : {0}",
+    commands_summary: "{0} aujourd'hui / {1} en échec",
+    status_key: "Clé",
+    status_value: "Valeur",
+    status_focused: "Panneau actif",
+    status_fullscreen: "Plein écran",
+    status_focus_active: "Focus en cours",
+    status_commands: "Commandes",
+    status_idle: "Inactif",
+    status_agents: "Agents",
+    status_tone: "Ton",
+    status_last_command: "Dernière cmd",
+    status_motivation: "Motivation",
+    status_general: "Général",
+    status_agents_pane: "Agents",
+    status_stats: "Stats",
+    yes: "Oui",
+    no: "Non",
+
+    command_title: "COMMANDE",
+    command_run: "exécuter",
+    command_complete: "compléter",
+    command_cancel: "annuler",
+    command_aliases: "Aussi {0}",
+    record_started: "record : enregistrement dans {0}",
+    record_saved: "record : enregistré dans {0}",
+    command_summaries: &[
+        ("quit", "Quitter augustinus"),
+        ("help", "Lister les commandes, ou en décrire une"),
+        ("focus", "Démarrer ou arrêter une session de focus"),
+        ("tab", "Gérer les onglets GÉNÉRAL"),
+        ("respawn", "Relancer le processus d'un terminal"),
+        ("agent", "Passer à un agent configuré"),
+        ("send", "Coller du texte dans l'agent"),
+        ("send-output", "Coller la sortie de GÉNÉRAL dans l'agent"),
+        ("record", "Enregistrer un terminal dans un fichier asciinema"),
+        ("lang", "Choisir la langue de l'interface"),
+    ],
+    error_empty: "commande vide",
+    error_unterminated_quote: "guillemet non fermé",
+    error_unknown: "commande inconnue {0}, voir :help",
+    error_missing_argument: "{0} : {1} attendu",
+    error_invalid_argument: "{0} : {1} n'est pas {2}",
+    error_unexpected_argument: "{0} : argument inattendu {1}",
+    expected: &[
+        ("start or stop", "start ou stop"),
+        ("new, close, next or prev", "new, close, next ou prev"),
+        ("general or agents", "general ou agents"),
+        ("an agent name", "un nom d'agent"),
+        ("text to send", "un texte à envoyer"),
+        ("last, screen or a line count", "last, screen ou un nombre de lignes"),
+        ("brutal, encouraging or emperor", "brutal, encouraging ou emperor"),
+        ("en, fr or ja", "en, fr ou ja"),
+    ],
+    error_no_agent: "agent : aucun agent nommé {0}",
+    error_no_focus_session: "focus : aucune session de focus en cours",
+    error_lang_save: "lang : impossible d'enregistrer la configuration : {0}",
+    error_splits_save: "impossible d'enregistrer la taille des panneaux : {0}",
+    error_keys_invalid: "[{0}] {1} n'est pas une séquence de touches",
+    error_keys_binding: "[{0}] {1} : impossible d'associer {2} dans ce mode",
+    error_keys_conflict: "[{0}] {1} chevauche {2}",
+    error_waiting_pattern: "agent {0} : le motif d'attente {1} n'est pas une regex valide",
+    error_respawn: "respawn {0} : {1}",
+    error_record: "record : {0}",
+    error_stats_save: "impossible d'enregistrer les statistiques des commandes : {0}",
+    agent_waiting_notification: "{0} vous attend",
+
+    select_language: "Choisir la langue",
+    language: "Langue",
+    language_hint: "j/k pour se déplacer, Entrée pour valider (↑/↓ aussi)",
+};
@@ -0,0 +1,178 @@
+use crate::Strings;
+
+pub(crate) const STRINGS: Strings = Strings {
+    app_name: "AUGUSTINUS",
+
+    pane_motivation: "モチベーション",
+    pane_general: "ターミナル",
+    pane_agents: "AIエージェント",
+    pane_stats: "統計",
+
+    agent_busy: "作業中",
+    agent_waiting: "入力待ち",
+    agent_exited: "終了",
+    agent_unnamed: "エージェント",
+
+    general_locked_hint: "ターミナルモード（ロック中）— Escでアプリ操作に戻る",
+    general_focused_hint: "Enter: ターミナルモード  1-9: タブ  /: 検索  [: コピー  p: 貼り付け  s: エージェントに送る  h/j/k/l Tab: フォーカス  \":\" コマンド",
+    general_unfocused_hint: "Enterで全画面  h/j/k/lでフォーカス  \":\" コマンド",
+    starting_shell: "シェルを起動中…",
+
+    agents_locked_hint: "{0} ロック中 — Escでペイン操作に戻る",
+    agents_focused_hint: "Enter: {0}を操作  1-9: エージェント  /: 検索  [: コピー  p: 貼り付け  h/j/k/l Tab: フォーカス移動",
+    agents_unfocused_hint: "h/j/k/lでフォーカス  Enterでロック  \":\" コマンド",
+    starting_agent: "{0}を起動中…",
+    agent_not_found: "{0}がPATHに見つかりません",
+    picker_title: "PATH上に設定済みのエージェントが見つかりません。次のいずれかを起動してください:",
+    picker_hint: "↑/↓: 移動  Enter: 起動  1-9: 選択",
+
+    scroll_header: "スクロール — {0}行さかのぼり中",
+    scroll_hint: " / {0}  k/j PgUp/PgDn g/G  q: ライブ",
+    copy_header: "コピー",
+    copy_selecting: "コピー — 選択中",
+    copy_selecting_lines: "コピー — 行を選択中",
+    copy_selecting_block: "コピー — 矩形を選択中",
+    copy_hint: "  h/j/k/l 移動  v/V/Ctrl-V 選択  y: ヤンク  q: 終了",
+    search_mode_regex: "正規表現",
+    search_mode_text: "テキスト",
+    search_prompt_hint: "  [{0}]  Enter: 検索  Ctrl-R: 正規表現  Esc: キャンセル",
+    search_invalid_regex: "正規表現が不正です: {0}",
+    search_match: "{1}件中{0}件目",
+    search_no_matches: "一致なし",
+    search_header: "検索「{0}」— {1}",
+    search_hint: "  n/N: 古い方/新しい方  Esc: クリア",
+    process_exited: "[プロセスがコード{0}で終了しました] — rで再起動",
+    process_start_failed: "[起動できませんでした: {0}] — rで再試行",
+    process_auto_restart: "（{0}秒後に自動再起動）",
+
+    tone_brutal: "辛口",
+    tone_encouraging: "励まし",
+    tone_emperor: "皇帝",
+    badge_idle: "• 放置中",
+    badge_live: "• 稼働中",
+    badge_focus_active: "集中セッション中",
+    badge_focus_ready: "集中準備OK",
+    banner_wake_up: "WAKE UP",
+    banner_lock_in: "LOCK IN",
+    banner_streak: "STREAK {0}",
+    banner_deep_work: "DEEP WORK",
+    quote_title: "いま",
+    label_streak: "連続",
+    label_today: "今日",
+    label_goal: "目標",
+    days_count: "{0}日",
+    ticker: "集中せよ • 容赦なく • 今日を積み上げろ • 牙を研げ • あと一回 •",
+    brutal_quotes: &[
+        "コードを書こうが書くまいが、体内時計は進み続ける。",
+        "集中しなかった一時間は、未来の子どもたちから奪った時間だ。",
+        "快適さは凡庸さに払う税金だ。",
+        "抗わなければエントロピーが勝つ。",
+        "体は裏で衰え続けている。それでも働け。",
+        "この歳月は二度と戻らない。交渉はやめろ。",
+        "今日流されれば、未来の自分が利子を払う。",
+        "カレンダーはお前の気分など気にしない。",
+        "ぬるい一日は、小さな人生への一票だ。",
+    ],
+    encouraging_quotes: &[
+        "あなたは複利で育つものを築いている。",
+        "継続とは、毎日発揮される知性だ。",
+        "規律ある一日が軌道を変える。",
+        "ゴールは思っているより近い。",
+        "鎖を途切れさせるな。未来が見ている。",
+        "深い仕事は、子孫と交わす約束だ。",
+        "小さく丁寧なセッションが、誇れる人生になる。",
+        "次の60分を大切にして、明日を勝ち取ろう。",
+    ],
+    emperor_quotes: &[
+        "お前は小さな成果のために生まれたのではない。",
+        "規律こそ主権である。",
+        "AUGUSTINUSは流されない。",
+        "遺産は静寂の中で築かれる。",
+        "名は肉体より長く生きうる。そのように振る舞え。",
+        "帝国は誰も拍手しない時間に築かれる。",
+        "必然となるのに許可はいらない。",
+        "仕事は王のごとく、習慣は法のごとく。",
+    ],
+
+    card_streak: "連続日数",
+    card_focus: "集中",
+    card_loc: "差分行数",
+    card_status: "状態",
+    days: "日",
+    loc_net: "正味 {0}",
+    loc_unavailable: "git diff: なし",
+    compact_loc: "差分: +{0} / -{1}",
+    compact_loc_unavailable: "差分: なし",
+    compact_streak: "連続: {0}日",
+    compact_focus: "集中: {0} / {1}",
+    compact_commands: "コマンド: {0}",
+    commands_summary: "今日 {0} / 失敗 {1}",
+    status_key: "項目",
+    status_value: "値",
+    status_focused: "フォーカス",
+    status_fullscreen: "全画面",
+    status_focus_active: "集中中",
+    status_commands: "コマンド",
+    status_idle: "放置",
+    status_agents: "エージェント",
+    status_tone: "トーン",
+    status_last_command: "直前のコマンド",
+    status_motivation: "モチベーション",
+    status_general: "ターミナル",
+    status_agents_pane: "エージェント",
+    status_stats: "統計",
+    yes: "はい",
+    no: "いいえ",
+
+    command_title: "コマンド",
+    command_run: "実行",
+    command_complete: "補完",
+    command_cancel: "キャンセル",
+    command_aliases: "別名 {0}",
+    record_started: "record: {0} に記録中",
+    record_saved: "record: {0} に保存しました",
+    command_summaries: &[
+        ("quit", "augustinusを終了する"),
+        ("help", "コマンドの一覧、または説明を表示する"),
+        ("focus", "集中セッションを開始・終了する"),
+        ("tab", "ターミナルのタブを操作する"),
+        ("respawn", "ターミナルのプロセスを再起動する"),
+        ("agent", "設定済みのエージェントに切り替える"),
+        ("send", "テキストをエージェントに貼り付ける"),
+        ("send-output", "ターミナルの出力をエージェントに貼り付ける"),
+        ("record", "ターミナルをasciinemaファイルに録画する"),
+        ("lang", "表示言語を設定する"),
+    ],
+    error_empty: "コマンドが空です",
+    error_unterminated_quote: "引用符が閉じていません",
+    error_unknown: "不明なコマンド {0}（:help を参照）",
+    error_missing_argument: "{0}: {1}が必要です",
+    error_invalid_argument: "{0}: {1} は{2}ではありません",
+    error_unexpected_argument: "{0}: 余分な引数 {1}",
+    expected: &[
+        ("start or stop", "start か stop"),
+        ("new, close, next or prev", "new、close、next、prev のいずれか"),
+        ("general or agents", "general か agents"),
+        ("an agent name", "エージェント名"),
+        ("text to send", "送信するテキスト"),
+        ("last, screen or a line count", "last、screen、または行数"),
+        ("brutal, encouraging or emperor", "brutal、encouraging、emperor のいずれか"),
+        ("en, fr or ja", "en、fr、ja のいずれか"),
+    ],
+    error_no_agent: "agent: {0} という名前のエージェントはありません",
+    error_no_focus_session: "focus: 実行中の集中セッションはありません",
+    error_lang_save: "lang: 設定を保存できませんでした: {0}",
+    error_splits_save: "ペインのサイズを保存できませんでした: {0}",
+    error_keys_invalid: "[{0}] {1} はキー操作として読めません",
+    error_keys_binding: "[{0}] {1}: このモードでは {2} を割り当てられません",
+    error_keys_conflict: "[{0}] {1} は {2} と重なっています",
+    error_waiting_pattern: "エージェント {0}: 待機パターン {1} は正しい正規表現ではありません",
+    error_respawn: "respawn {0}: {1}",
+    error_record: "record: {0}",
+    error_stats_save: "コマンドの統計を保存できませんでした: {0}",
+    agent_waiting_notification: "{0}が入力を待っています",
+
+    select_language: "言語を選択",
+    language: "言語",
+    language_hint: "j/kで移動、Enterで決定（↑/↓も使えます）",
+};
@@ -1,8 +1,10 @@
+mod en;
+mod fr;
+mod ja;
 mod strings;
 
-pub use strings::{Language, Strings};
+pub use strings::{fill, Language, Strings};
 
-pub fn strings(language: Language) -> Strings {
+pub fn strings(language: Language) -> &'static Strings {
     strings::strings(language)
 }
-
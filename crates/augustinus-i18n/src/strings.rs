@@ -1,5 +1,9 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+use crate::{en, fr, ja};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Language {
@@ -8,16 +12,236 @@ pub enum Language {
     Ja,
 }
 
+/// Every user-facing string of one language. Templates mark their values
+/// with `{0}`, `{1}`…, filled in by [`fill`], so translations can reorder
+/// them.
 #[derive(Debug, Clone, Copy)]
 pub struct Strings {
     pub app_name: &'static str,
+
+    // Pane titles.
+    pub pane_motivation: &'static str,
+    pub pane_general: &'static str,
+    pub pane_agents: &'static str,
+    pub pane_stats: &'static str,
+
+    // Agent states.
+    pub agent_busy: &'static str,
+    pub agent_waiting: &'static str,
+    pub agent_exited: &'static str,
+    /// Shown for an agent without a configured name.
+    pub agent_unnamed: &'static str,
+
+    // GENERAL headers.
+    pub general_locked_hint: &'static str,
+    pub general_focused_hint: &'static str,
+    pub general_unfocused_hint: &'static str,
+    pub starting_shell: &'static str,
+
+    // AI AGENTS headers; `{0}` is the agent's name.
+    pub agents_locked_hint: &'static str,
+    pub agents_focused_hint: &'static str,
+    pub agents_unfocused_hint: &'static str,
+    pub starting_agent: &'static str,
+    /// `{0}`: the agent program.
+    pub agent_not_found: &'static str,
+    pub picker_title: &'static str,
+    pub picker_hint: &'static str,
+
+    // Scroll, copy and search modes of a terminal pane.
+    /// `{0}`: lines scrolled back.
+    pub scroll_header: &'static str,
+    /// `{0}`: scrollback length.
+    pub scroll_hint: &'static str,
+    pub copy_header: &'static str,
+    pub copy_selecting: &'static str,
+    pub copy_selecting_lines: &'static str,
+    pub copy_selecting_block: &'static str,
+    pub copy_hint: &'static str,
+    pub search_mode_regex: &'static str,
+    pub search_mode_text: &'static str,
+    /// `{0}`: the search mode.
+    pub search_prompt_hint: &'static str,
+    /// `{0}`: the regex error.
+    pub search_invalid_regex: &'static str,
+    /// `{0}` of `{1}` matches.
+    pub search_match: &'static str,
+    pub search_no_matches: &'static str,
+    /// `{0}`: the query; `{1}`: the match status.
+    pub search_header: &'static str,
+    pub search_hint: &'static str,
+    /// `{0}`: exit code.
+    pub process_exited: &'static str,
+    /// `{0}`: why the process could not be started.
+    pub process_start_failed: &'static str,
+    /// `{0}`: seconds left.
+    pub process_auto_restart: &'static str,
+
+    // MOTIVATION.
+    pub tone_brutal: &'static str,
+    pub tone_encouraging: &'static str,
+    pub tone_emperor: &'static str,
+    pub badge_idle: &'static str,
+    pub badge_live: &'static str,
+    pub badge_focus_active: &'static str,
+    pub badge_focus_ready: &'static str,
+    // Banners are drawn in the 5x5 block font, which only has ASCII.
+    pub banner_wake_up: &'static str,
+    pub banner_lock_in: &'static str,
+    /// `{0}`: streak days.
+    pub banner_streak: &'static str,
+    pub banner_deep_work: &'static str,
+    pub quote_title: &'static str,
+    pub label_streak: &'static str,
+    pub label_today: &'static str,
+    pub label_goal: &'static str,
+    /// `{0}`: streak days.
+    pub days_count: &'static str,
+    pub ticker: &'static str,
+    pub brutal_quotes: &'static [&'static str],
+    pub encouraging_quotes: &'static [&'static str],
+    pub emperor_quotes: &'static [&'static str],
+
+    // STATS.
+    pub card_streak: &'static str,
+    pub card_focus: &'static str,
+    pub card_loc: &'static str,
+    pub card_status: &'static str,
+    pub days: &'static str,
+    /// `{0}`: lines added minus lines removed.
+    pub loc_net: &'static str,
+    pub loc_unavailable: &'static str,
+    /// `{0}` added, `{1}` removed.
+    pub compact_loc: &'static str,
+    pub compact_loc_unavailable: &'static str,
+    /// `{0}`: streak days.
+    pub compact_streak: &'static str,
+    /// `{0}` today of a `{1}` goal.
+    pub compact_focus: &'static str,
+    /// `{0}`: the commands summary.
+    pub compact_commands: &'static str,
+    /// `{0}` commands today, `{1}` of them failed.
+    pub commands_summary: &'static str,
+    pub status_key: &'static str,
+    pub status_value: &'static str,
+    pub status_focused: &'static str,
+    pub status_fullscreen: &'static str,
+    pub status_focus_active: &'static str,
+    pub status_commands: &'static str,
+    pub status_idle: &'static str,
+    pub status_agents: &'static str,
+    pub status_tone: &'static str,
+    pub status_last_command: &'static str,
+    pub status_motivation: &'static str,
+    pub status_general: &'static str,
+    pub status_agents_pane: &'static str,
+    pub status_stats: &'static str,
+    pub yes: &'static str,
+    pub no: &'static str,
+
+    // Command overlay.
+    pub command_title: &'static str,
+    pub command_run: &'static str,
+    pub command_complete: &'static str,
+    pub command_cancel: &'static str,
+    /// `{0}`: the aliases of a command.
+    pub command_aliases: &'static str,
+    /// `{0}`: the `.cast` file being written.
+    pub record_started: &'static str,
+    /// `{0}`: the finished `.cast` file.
+    pub record_saved: &'static str,
+    /// `(name, summary)` for each `:` command.
+    pub command_summaries: &'static [(&'static str, &'static str)],
+    pub error_empty: &'static str,
+    pub error_unterminated_quote: &'static str,
+    /// `{0}`: the unknown command.
+    pub error_unknown: &'static str,
+    /// `{0}`: command; `{1}`: what it expects.
+    pub error_missing_argument: &'static str,
+    /// `{0}`: command; `{1}`: the argument; `{2}`: what it expects.
+    pub error_invalid_argument: &'static str,
+    /// `{0}`: command; `{1}`: the argument.
+    pub error_unexpected_argument: &'static str,
+    /// `(English, translation)` of what arguments expect.
+    pub expected: &'static [(&'static str, &'static str)],
+    /// `{0}`: the agent name.
+    pub error_no_agent: &'static str,
+    pub error_no_focus_session: &'static str,
+    /// `{0}`: why the config could not be saved.
+    pub error_lang_save: &'static str,
+    /// `{0}`: why the config could not be saved.
+    pub error_splits_save: &'static str,
+    /// `{0}`: the `[keys]` table; `{1}`: the keys.
+    pub error_keys_invalid: &'static str,
+    /// `{0}`: the `[keys]` table; `{1}`: the keys; `{2}`: the binding.
+    pub error_keys_binding: &'static str,
+    /// `{0}`: the `[keys]` table; `{1}` and `{2}`: the overlapping keys.
+    pub error_keys_conflict: &'static str,
+    /// `{0}`: the agent; `{1}`: the pattern.
+    pub error_waiting_pattern: &'static str,
+    /// `{0}`: the pane; `{1}`: why its process could not be started.
+    pub error_respawn: &'static str,
+    /// `{0}`: why the recording could not start.
+    pub error_record: &'static str,
+    /// `{0}`: why the finished command could not be stored.
+    pub error_stats_save: &'static str,
+    /// `{0}`: the agent name.
+    pub agent_waiting_notification: &'static str,
+
+    // Language picker on first start.
+    pub select_language: &'static str,
+    pub language: &'static str,
+    pub language_hint: &'static str,
 }
 
-pub fn strings(language: Language) -> Strings {
+impl Strings {
+    /// The summary `:help` shows for `command`.
+    pub fn command_summary(&self, command: &str) -> Option<&'static str> {
+        self.command_summaries
+            .iter()
+            .find(|(name, _)| *name == command)
+            .map(|(_, summary)| *summary)
+    }
+
+    /// Translates what an argument expects, given in English.
+    pub fn expected<'a>(&self, expected: &'a str) -> &'a str {
+        self.expected
+            .iter()
+            .find(|(english, _)| *english == expected)
+            .map_or(expected, |(_, translated)| translated)
+    }
+}
+
+pub fn strings(language: Language) -> &'static Strings {
     match language {
-        Language::En => Strings { app_name: "AUGUSTINUS" },
-        Language::Fr => Strings { app_name: "AUGUSTINUS" },
-        Language::Ja => Strings { app_name: "AUGUSTINUS" },
+        Language::En => &en::STRINGS,
+        Language::Fr => &fr::STRINGS,
+        Language::Ja => &ja::STRINGS,
     }
 }
 
+/// Replaces `{0}`, `{1}`… in `template` with `values`.
+pub fn fill(template: &str, values: &[&dyn fmt::Display]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after
+            .find('}')
+            .and_then(|close| Some((after[..close].parse::<usize>().ok()?, close)))
+            .and_then(|(index, close)| Some((values.get(index)?, close)));
+        match value {
+            Some((value, close)) => {
+                out.push_str(&value.to_string());
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
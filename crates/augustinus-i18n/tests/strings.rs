@@ -0,0 +1,117 @@
+use augustinus_i18n::{fill, strings, Language, Strings};
+
+const LANGUAGES: [Language; 3] = [Language::En, Language::Fr, Language::Ja];
+
+/// `{0}`, `{1}`… used by `template`, sorted.
+fn placeholders(template: &str) -> Vec<String> {
+    let mut found: Vec<String> = template
+        .split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}'))
+        .map(|(index, _)| index.to_string())
+        .collect();
+    found.sort();
+    found
+}
+
+fn templates(strings: &Strings) -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("agents_locked_hint", strings.agents_locked_hint),
+        ("agents_focused_hint", strings.agents_focused_hint),
+        ("starting_agent", strings.starting_agent),
+        ("agent_not_found", strings.agent_not_found),
+        ("scroll_header", strings.scroll_header),
+        ("scroll_hint", strings.scroll_hint),
+        ("search_prompt_hint", strings.search_prompt_hint),
+        ("search_invalid_regex", strings.search_invalid_regex),
+        ("search_match", strings.search_match),
+        ("search_header", strings.search_header),
+        ("process_exited", strings.process_exited),
+        ("process_start_failed", strings.process_start_failed),
+        ("process_auto_restart", strings.process_auto_restart),
+        ("banner_streak", strings.banner_streak),
+        ("days_count", strings.days_count),
+        ("loc_net", strings.loc_net),
+        ("compact_loc", strings.compact_loc),
+        ("compact_streak", strings.compact_streak),
+        ("compact_focus", strings.compact_focus),
+        ("compact_commands", strings.compact_commands),
+        ("commands_summary", strings.commands_summary),
+        ("command_aliases", strings.command_aliases),
+        ("record_started", strings.record_started),
+        ("record_saved", strings.record_saved),
+        ("error_unknown", strings.error_unknown),
+        ("error_missing_argument", strings.error_missing_argument),
+        ("error_invalid_argument", strings.error_invalid_argument),
+        ("error_unexpected_argument", strings.error_unexpected_argument),
+        ("error_no_agent", strings.error_no_agent),
+        ("error_lang_save", strings.error_lang_save),
+        ("error_splits_save", strings.error_splits_save),
+        ("error_keys_invalid", strings.error_keys_invalid),
+        ("error_keys_binding", strings.error_keys_binding),
+        ("error_keys_conflict", strings.error_keys_conflict),
+        ("error_waiting_pattern", strings.error_waiting_pattern),
+        ("error_respawn", strings.error_respawn),
+        ("error_record", strings.error_record),
+        ("error_stats_save", strings.error_stats_save),
+        ("agent_waiting_notification", strings.agent_waiting_notification),
+    ]
+}
+
+#[test]
+fn translations_use_the_same_placeholders_as_english() {
+    let english = templates(strings(Language::En));
+    for language in LANGUAGES {
+        for ((name, expected), (_, template)) in english.iter().zip(templates(strings(language))) {
+            assert_eq!(
+                placeholders(template),
+                placeholders(expected),
+                "{language:?} {name}: {template:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn every_language_has_as_many_quotes_and_summaries_as_english() {
+    let english = strings(Language::En);
+    for language in LANGUAGES {
+        let strings = strings(language);
+        assert_eq!(strings.brutal_quotes.len(), english.brutal_quotes.len());
+        assert_eq!(strings.encouraging_quotes.len(), english.encouraging_quotes.len());
+        assert_eq!(strings.emperor_quotes.len(), english.emperor_quotes.len());
+        let names = |strings: &Strings| -> Vec<&str> {
+            strings.command_summaries.iter().map(|(name, _)| *name).collect()
+        };
+        assert_eq!(names(strings), names(english), "{language:?}");
+    }
+}
+
+#[test]
+fn banners_stay_within_the_block_font() {
+    for language in LANGUAGES {
+        let strings = strings(language);
+        for banner in [
+            strings.banner_wake_up,
+            strings.banner_lock_in,
+            strings.banner_streak,
+            strings.banner_deep_work,
+        ] {
+            assert!(banner.is_ascii(), "{language:?}: {banner:?}");
+        }
+    }
+}
+
+#[test]
+fn fill_places_values_by_index() {
+    assert_eq!(fill("{1}件中{0}件目", &[&2, &7]), "7件中2件目");
+    assert_eq!(fill("match {0} of {1}", &[&"a", &"b"]), "match a of b");
+    assert_eq!(fill("{0} {x} {2}", &[&1]), "1 {x} {2}");
+}
+
+#[test]
+fn expected_phrases_fall_back_to_english() {
+    let fr = strings(Language::Fr);
+    assert_eq!(fr.expected("start or stop"), "start ou stop");
+    assert_eq!(fr.expected("something new"), "something new");
+}
@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use portable_pty::CommandBuilder;
+
+use crate::PtySession;
+
+/// Describes a child process to run under a PTY.
+///
+/// ```no_run
+/// # use augustinus_pty::PtySession;
+/// let session = PtySession::builder("/bin/bash")
+///     .arg("-l")
+///     .cwd("/srv/project")
+///     .env("RUST_LOG", "debug")
+///     .size(120, 40)
+///     .spawn()?;
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct PtyBuilder {
+    pub(crate) program: String,
+    pub(crate) args: Vec<String>,
+    pub(crate) cwd: Option<PathBuf>,
+    pub(crate) env: Vec<(String, String)>,
+    pub(crate) term: String,
+    pub(crate) colorterm: Option<String>,
+    pub(crate) cols: u16,
+    pub(crate) rows: u16,
+}
+
+impl PtyBuilder {
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            cwd: None,
+            env: Vec::new(),
+            term: "xterm-256color".to_string(),
+            colorterm: None,
+            cols: 80,
+            rows: 24,
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Working directory of the child; the app's own cwd when unset.
+    pub fn cwd(mut self, cwd: impl Into<PathBuf>) -> Self {
+        self.cwd = Some(cwd.into());
+        self
+    }
+
+    /// Sets a variable on top of the inherited environment. Applied after
+    /// `TERM`/`COLORTERM`, so it can override them too.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// `TERM` for the child; `xterm-256color` by default.
+    pub fn term(mut self, term: impl Into<String>) -> Self {
+        self.term = term.into();
+        self
+    }
+
+    pub fn colorterm(mut self, colorterm: impl Into<String>) -> Self {
+        self.colorterm = Some(colorterm.into());
+        self
+    }
+
+    pub fn size(mut self, cols: u16, rows: u16) -> Self {
+        self.cols = cols;
+        self.rows = rows;
+        self
+    }
+
+    pub fn spawn(self) -> Result<PtySession> {
+        PtySession::local(&self)
+    }
+
+    /// Runs the child inside the PTY daemon listening on `socket` instead of
+    /// this process, so it outlives the UI. If the daemon already holds a
+    /// session under `key`, that one is attached instead and the rest of
+    /// the builder only contributes the size.
+    #[cfg(unix)]
+    pub fn attach(self, socket: impl AsRef<std::path::Path>, key: &str) -> Result<PtySession> {
+        PtySession::remote(&self, socket.as_ref(), key)
+    }
+
+    pub(crate) fn command(&self) -> Result<CommandBuilder> {
+        let mut cmd = CommandBuilder::new(&self.program);
+        cmd.args(&self.args);
+        if let Some(cwd) = &self.cwd {
+            if !cwd.is_dir() {
+                bail!("working directory {} does not exist", cwd.display());
+            }
+            cmd.cwd(cwd);
+        }
+        cmd.env("TERM", &self.term);
+        if let Some(colorterm) = &self.colorterm {
+            cmd.env("COLORTERM", colorterm);
+        }
+        for (key, value) in &self.env {
+            cmd.env(key, value);
+        }
+        Ok(cmd)
+    }
+
+    /// The value of `key` in the environment `command` gives the child.
+    pub(crate) fn child_env(&self, key: &str) -> Option<String> {
+        if let Some((_, value)) = self.env.iter().rev().find(|(name, _)| name == key) {
+            return Some(value.clone());
+        }
+        match key {
+            "TERM" => Some(self.term.clone()),
+            "COLORTERM" => self.colorterm.clone(),
+            _ => std::env::var(key).ok(),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::time::Instant;
+
+use augustinus_term::{ShellMark, ShellMarkKind};
+
+/// Marks kept until the runtime takes them; the daemon never does.
+const MAX_PENDING_MARKS: usize = 256;
+
+/// Collects the vt100 events that don't change the screen itself.
+#[derive(Debug)]
+pub(crate) struct SessionCallbacks {
+    pub(crate) title: Option<String>,
+    /// A BEL (or visual bell) arrived since the runtime last asked.
+    pub(crate) bell: bool,
+    /// OSC 133 marks since the runtime last asked.
+    pub(crate) marks: Vec<ShellMark>,
+    /// Lines scrolled off the top of the screen before the current chunk.
+    pub(crate) scrolled: u64,
+    /// Scrollback length when the current chunk started; see
+    /// `PtySession::process`.
+    pub(crate) chunk_scrollback: usize,
+    /// When the current chunk was read.
+    pub(crate) chunk_at: Instant,
+    /// Where the user started typing, from the last `OSC 133;B`.
+    command_start: Option<(u64, u16)>,
+}
+
+impl Default for SessionCallbacks {
+    fn default() -> Self {
+        Self {
+            title: None,
+            bell: false,
+            marks: Vec::new(),
+            scrolled: 0,
+            chunk_scrollback: 0,
+            chunk_at: Instant::now(),
+            command_start: None,
+        }
+    }
+}
+
+impl SessionCallbacks {
+    fn mark(&mut self, screen: &mut vt100::Screen, kind: ShellMarkKind) {
+        let (row, col) = screen.cursor_position();
+        self.marks.push(ShellMark {
+            kind,
+            line: self.cursor_line(screen, row),
+            col,
+            at: self.chunk_at,
+        });
+        if self.marks.len() > MAX_PENDING_MARKS {
+            self.marks.remove(0);
+        }
+    }
+
+    fn cursor_line(&self, screen: &mut vt100::Screen, row: u16) -> u64 {
+        self.scrolled + scrolled_in_chunk(screen, self.chunk_scrollback) + row as u64
+    }
+
+    /// What the user typed since `OSC 133;B`, up to the cursor.
+    fn typed_command(&self, screen: &mut vt100::Screen) -> String {
+        let Some((start_line, start_col)) = self.command_start else {
+            return String::new();
+        };
+        let (row, col) = screen.cursor_position();
+        let top = self.cursor_line(screen, 0);
+        let (start_row, start_col) = match start_line.checked_sub(top) {
+            Some(start_row) => (start_row.min(row as u64) as u16, start_col),
+            None => (0, 0),
+        };
+        let offset = screen.scrollback();
+        screen.set_scrollback(0);
+        let text = screen.contents_between(start_row, start_col, row, col);
+        screen.set_scrollback(offset);
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl vt100::Callbacks for SessionCallbacks {
+    fn set_window_title(&mut self, _: &mut vt100::Screen, title: &[u8]) {
+        let title: String = String::from_utf8_lossy(title)
+            .chars()
+            .filter(|ch| !ch.is_control())
+            .collect();
+        let title = title.trim();
+        self.title = (!title.is_empty()).then(|| title.to_string());
+    }
+
+    fn audible_bell(&mut self, _: &mut vt100::Screen) {
+        self.bell = true;
+    }
+
+    fn visual_bell(&mut self, _: &mut vt100::Screen) {
+        self.bell = true;
+    }
+
+    fn unhandled_osc(&mut self, screen: &mut vt100::Screen, params: &[&[u8]]) {
+        let [b"133", kind, rest @ ..] = params else {
+            return;
+        };
+        // Marks drawn on the alternate screen don't belong to the shell.
+        if screen.alternate_screen() {
+            return;
+        }
+        let kind = match *kind {
+            b"A" => ShellMarkKind::PromptStart,
+            b"B" => {
+                let (row, col) = screen.cursor_position();
+                self.command_start = Some((self.cursor_line(screen, row), col));
+                ShellMarkKind::CommandStart
+            }
+            b"C" => ShellMarkKind::OutputStart {
+                command: self.typed_command(screen),
+            },
+            b"D" => ShellMarkKind::CommandFinished {
+                exit_code: rest
+                    .first()
+                    .and_then(|code| std::str::from_utf8(code).ok())
+                    .and_then(|code| code.parse().ok()),
+            },
+            _ => return,
+        };
+        self.mark(screen, kind);
+    }
+}
+
+/// Lines scrolled off during the current chunk so far, given the scrollback
+/// length it started with. While a chunk is processed the scrollback offset
+/// is parked at 1 (0 if the scrollback was empty), and vt100 moves it along
+/// once per line it scrolls off.
+pub(crate) fn scrolled_in_chunk(screen: &mut vt100::Screen, chunk_scrollback: usize) -> u64 {
+    if chunk_scrollback > 0 {
+        screen.scrollback().saturating_sub(1) as u64
+    } else {
+        scrollback_len(screen) as u64
+    }
+}
+
+/// Lines held in the scrollback of the active grid.
+pub(crate) fn scrollback_len(screen: &mut vt100::Screen) -> usize {
+    let offset = screen.scrollback();
+    screen.set_scrollback(usize::MAX);
+    let len = screen.scrollback();
+    screen.set_scrollback(offset);
+    len
+}
@@ -0,0 +1,150 @@
+//! Client side of the PTY daemon: sessions whose child lives in the daemon.
+
+use std::{
+    net::Shutdown,
+    os::unix::net::UnixStream,
+    path::Path,
+    sync::{Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::builder::PtyBuilder;
+use crate::protocol::{Reply, Request};
+use crate::session::OutputSender;
+use crate::transport::Transport;
+
+/// How long `kill` waits for the daemon to report the child gone.
+const KILL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A session attached through the daemon. Dropping it only detaches; the
+/// child keeps running until it exits or is killed explicitly.
+pub(crate) struct RemotePty {
+    stream: UnixStream,
+    pid: Option<u32>,
+    exit_code: Arc<Mutex<Option<u32>>>,
+}
+
+impl RemotePty {
+    /// Attaches to session `key`, creating it from `spec` if needed, and
+    /// starts a thread forwarding its output (a replay of the current screen
+    /// first) to `tx`.
+    pub(crate) fn attach(
+        socket: &Path,
+        key: &str,
+        spec: &PtyBuilder,
+        tx: OutputSender,
+    ) -> Result<(Self, thread::JoinHandle<()>)> {
+        let mut stream = connect(socket)?;
+        Request::Open {
+            key: key.to_string(),
+            spec: spec.clone(),
+        }
+        .write_to(&mut stream)
+        .context("send open request")?;
+        let pid = match Reply::read_from(&mut stream).context("read attach reply")? {
+            Some(Reply::Attached { pid }) => pid,
+            Some(Reply::Error(message)) => bail!("{message}"),
+            _ => bail!("unexpected reply from pty daemon"),
+        };
+
+        let exit_code = Arc::new(Mutex::new(None));
+        let mut reader = stream.try_clone().context("clone daemon socket")?;
+        let reported = exit_code.clone();
+        let reader_thread = thread::spawn(move || {
+            let code = loop {
+                match Reply::read_from(&mut reader) {
+                    Ok(Some(Reply::Output(bytes))) => {
+                        if tx.send((Instant::now(), bytes)).is_err() {
+                            return;
+                        }
+                    }
+                    Ok(Some(Reply::Exited(code))) => break code,
+                    Ok(Some(_)) => {}
+                    // Losing the daemon takes the child with it as far as
+                    // this session can tell.
+                    Ok(None) | Err(_) => break 1,
+                }
+            };
+            if let Ok(mut exit_code) = reported.lock() {
+                exit_code.get_or_insert(code);
+            }
+        });
+
+        let pty = Self {
+            stream,
+            pid,
+            exit_code,
+        };
+        Ok((pty, reader_thread))
+    }
+
+    fn send(&mut self, request: &Request) -> Result<()> {
+        request
+            .write_to(&mut self.stream)
+            .context("write to pty daemon")
+    }
+}
+
+impl Transport for RemotePty {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<()> {
+        self.send(&Request::Input(bytes.to_vec()))
+    }
+
+    fn resize(&mut self, cols: u16, rows: u16) -> Result<()> {
+        self.send(&Request::Resize { cols, rows })
+    }
+
+    fn try_exit_code(&mut self) -> Option<u32> {
+        *self.exit_code.lock().ok()?
+    }
+
+    fn kill(&mut self) {
+        if self.try_exit_code().is_some() || self.send(&Request::Kill).is_err() {
+            return;
+        }
+        let deadline = Instant::now() + KILL_TIMEOUT;
+        while self.try_exit_code().is_none() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn foreground_pid(&self) -> Option<u32> {
+        let pid = self.pid?;
+        // The daemon's PTY master isn't ours to ask, but the child's stat
+        // names the terminal's foreground group (field 8, `tpgid`).
+        let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok();
+        let tpgid = stat.as_deref().and_then(|stat| {
+            let (_, fields) = stat.rsplit_once(')')?;
+            fields.split_whitespace().nth(5)?.parse::<i64>().ok()
+        });
+        match tpgid {
+            Some(tpgid) if tpgid > 0 => Some(tpgid as u32),
+            _ => Some(pid),
+        }
+    }
+}
+
+impl Drop for RemotePty {
+    fn drop(&mut self) {
+        // Detach: the daemon sees the hang-up and keeps the child.
+        let _ = self.stream.shutdown(Shutdown::Both);
+    }
+}
+
+/// Keys of the sessions the daemon on `socket` currently holds, sorted.
+pub fn daemon_sessions(socket: impl AsRef<Path>) -> Result<Vec<String>> {
+    let mut stream = connect(socket.as_ref())?;
+    Request::List.write_to(&mut stream).context("send list request")?;
+    match Reply::read_from(&mut stream).context("read session list")? {
+        Some(Reply::Sessions(keys)) => Ok(keys),
+        _ => bail!("unexpected reply from pty daemon"),
+    }
+}
+
+fn connect(socket: &Path) -> Result<UnixStream> {
+    UnixStream::connect(socket)
+        .with_context(|| format!("connect to pty daemon at {}", socket.display()))
+}
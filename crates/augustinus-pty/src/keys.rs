@@ -0,0 +1,128 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Encodes a key press the way xterm does. `application_cursor` is the
+/// DECCKM state of the child, which switches unmodified arrows and Home/End
+/// from `CSI` to `SS3` sequences.
+pub fn key_to_bytes(key: KeyEvent, application_cursor: bool) -> Option<Vec<u8>> {
+    let mods = key.modifiers;
+    let alt = mods.contains(KeyModifiers::ALT) || mods.contains(KeyModifiers::META);
+    let ctrl = mods.contains(KeyModifiers::CONTROL);
+    let modifier_param = xterm_modifier_param(mods);
+
+    let bytes = match key.code {
+        KeyCode::Enter => with_alt(alt, vec![b'\r']),
+        KeyCode::Tab if mods.contains(KeyModifiers::SHIFT) => b"\x1b[Z".to_vec(),
+        KeyCode::Tab => with_alt(alt, vec![b'\t']),
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Backspace if ctrl => with_alt(alt, vec![0x08]),
+        KeyCode::Backspace => with_alt(alt, vec![0x7f]),
+        KeyCode::Esc => with_alt(alt, vec![0x1b]),
+        KeyCode::Up => cursor_key(b'A', modifier_param, application_cursor),
+        KeyCode::Down => cursor_key(b'B', modifier_param, application_cursor),
+        KeyCode::Right => cursor_key(b'C', modifier_param, application_cursor),
+        KeyCode::Left => cursor_key(b'D', modifier_param, application_cursor),
+        KeyCode::Home => cursor_key(b'H', modifier_param, application_cursor),
+        KeyCode::End => cursor_key(b'F', modifier_param, application_cursor),
+        KeyCode::Insert => tilde_key(2, modifier_param),
+        KeyCode::Delete => tilde_key(3, modifier_param),
+        KeyCode::PageUp => tilde_key(5, modifier_param),
+        KeyCode::PageDown => tilde_key(6, modifier_param),
+        KeyCode::F(n) => function_key(n, modifier_param)?,
+        KeyCode::Char(ch) => {
+            if ctrl {
+                with_alt(alt, vec![ctrl_code(ch)?])
+            } else {
+                let mut buf = [0u8; 4];
+                let s = ch.encode_utf8(&mut buf);
+                with_alt(alt, s.as_bytes().to_vec())
+            }
+        }
+        KeyCode::Null => vec![0x00],
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+/// The `;m` parameter xterm appends to modified special keys, or `None` when
+/// no modifier is held.
+fn xterm_modifier_param(mods: KeyModifiers) -> Option<u8> {
+    let mut param = 1;
+    if mods.contains(KeyModifiers::SHIFT) {
+        param += 1;
+    }
+    if mods.contains(KeyModifiers::ALT) || mods.contains(KeyModifiers::META) {
+        param += 2;
+    }
+    if mods.contains(KeyModifiers::CONTROL) {
+        param += 4;
+    }
+    (param > 1).then_some(param)
+}
+
+fn with_alt(alt: bool, bytes: Vec<u8>) -> Vec<u8> {
+    if !alt {
+        return bytes;
+    }
+    let mut out = Vec::with_capacity(bytes.len() + 1);
+    out.push(0x1b);
+    out.extend(bytes);
+    out
+}
+
+fn cursor_key(final_byte: u8, modifier_param: Option<u8>, application_cursor: bool) -> Vec<u8> {
+    match modifier_param {
+        Some(m) => format!("\x1b[1;{m}{}", final_byte as char).into_bytes(),
+        None if application_cursor => vec![0x1b, b'O', final_byte],
+        None => vec![0x1b, b'[', final_byte],
+    }
+}
+
+fn tilde_key(code: u8, modifier_param: Option<u8>) -> Vec<u8> {
+    match modifier_param {
+        Some(m) => format!("\x1b[{code};{m}~").into_bytes(),
+        None => format!("\x1b[{code}~").into_bytes(),
+    }
+}
+
+fn function_key(n: u8, modifier_param: Option<u8>) -> Option<Vec<u8>> {
+    let ss3_final = match n {
+        1 => Some(b'P'),
+        2 => Some(b'Q'),
+        3 => Some(b'R'),
+        4 => Some(b'S'),
+        _ => None,
+    };
+    if let Some(final_byte) = ss3_final {
+        return Some(match modifier_param {
+            Some(m) => format!("\x1b[1;{m}{}", final_byte as char).into_bytes(),
+            None => vec![0x1b, b'O', final_byte],
+        });
+    }
+    let code = match n {
+        5 => 15,
+        6 => 17,
+        7 => 18,
+        8 => 19,
+        9 => 20,
+        10 => 21,
+        11 => 23,
+        12 => 24,
+        _ => return None,
+    };
+    Some(tilde_key(code, modifier_param))
+}
+
+fn ctrl_code(ch: char) -> Option<u8> {
+    match ch {
+        'a'..='z' => Some(ch as u8 - b'a' + 1),
+        'A'..='Z' => Some(ch as u8 - b'A' + 1),
+        ' ' | '@' | '2' => Some(0x00),
+        '[' | '3' => Some(0x1b),
+        '\\' | '4' => Some(0x1c),
+        ']' | '5' => Some(0x1d),
+        '^' | '6' => Some(0x1e),
+        '_' | '7' | '/' => Some(0x1f),
+        '8' | '?' => Some(0x7f),
+        _ => None,
+    }
+}
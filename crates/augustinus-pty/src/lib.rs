@@ -1,5 +1,24 @@
 //! PTY helpers used by the TUI runtime.
 
+mod builder;
+mod callbacks;
+#[cfg(unix)]
+mod client;
+mod keys;
+mod mouse;
+#[cfg(unix)]
+mod protocol;
+mod record;
+#[cfg(unix)]
+mod server;
 mod session;
+mod transport;
 
-pub use session::{PtySession, PtySnapshot};
+pub use builder::PtyBuilder;
+#[cfg(unix)]
+pub use client::daemon_sessions;
+pub use keys::key_to_bytes;
+pub use record::CastRecorder;
+#[cfg(unix)]
+pub use server::PtyServer;
+pub use session::PtySession;
@@ -0,0 +1,92 @@
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use vt100::{MouseProtocolEncoding, MouseProtocolMode};
+
+/// Encodes a mouse event for a child that enabled mouse reporting. `event`
+/// coordinates must already be relative to the child's screen. Returns `None`
+/// when the child's reporting mode does not include this kind of event.
+pub(crate) fn mouse_to_bytes(
+    event: MouseEvent,
+    mode: MouseProtocolMode,
+    encoding: MouseProtocolEncoding,
+) -> Option<Vec<u8>> {
+    let wanted = match event.kind {
+        MouseEventKind::Down(_)
+        | MouseEventKind::ScrollUp
+        | MouseEventKind::ScrollDown
+        | MouseEventKind::ScrollLeft
+        | MouseEventKind::ScrollRight => mode != MouseProtocolMode::None,
+        MouseEventKind::Up(_) => matches!(
+            mode,
+            MouseProtocolMode::PressRelease
+                | MouseProtocolMode::ButtonMotion
+                | MouseProtocolMode::AnyMotion
+        ),
+        MouseEventKind::Drag(_) => matches!(
+            mode,
+            MouseProtocolMode::ButtonMotion | MouseProtocolMode::AnyMotion
+        ),
+        MouseEventKind::Moved => mode == MouseProtocolMode::AnyMotion,
+    };
+    if !wanted {
+        return None;
+    }
+
+    let sgr = encoding == MouseProtocolEncoding::Sgr;
+    let mut code = match event.kind {
+        MouseEventKind::Down(button) => button_code(button),
+        MouseEventKind::Up(button) if sgr => button_code(button),
+        MouseEventKind::Up(_) => 3,
+        MouseEventKind::Drag(button) => button_code(button) + 32,
+        MouseEventKind::Moved => 3 + 32,
+        MouseEventKind::ScrollUp => 64,
+        MouseEventKind::ScrollDown => 65,
+        MouseEventKind::ScrollLeft => 66,
+        MouseEventKind::ScrollRight => 67,
+    };
+    if event.modifiers.contains(KeyModifiers::SHIFT) {
+        code += 4;
+    }
+    if event.modifiers.contains(KeyModifiers::ALT) {
+        code += 8;
+    }
+    if event.modifiers.contains(KeyModifiers::CONTROL) {
+        code += 16;
+    }
+
+    let col = u32::from(event.column) + 1;
+    let row = u32::from(event.row) + 1;
+    match encoding {
+        MouseProtocolEncoding::Sgr => {
+            let suffix = if matches!(event.kind, MouseEventKind::Up(_)) {
+                'm'
+            } else {
+                'M'
+            };
+            Some(format!("\x1b[<{code};{col};{row}{suffix}").into_bytes())
+        }
+        MouseProtocolEncoding::Utf8 => {
+            let mut out = b"\x1b[M".to_vec();
+            for value in [code + 32, col + 32, row + 32] {
+                let ch = char::from_u32(value).filter(|_| value <= 2047)?;
+                let mut buf = [0u8; 4];
+                out.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+            }
+            Some(out)
+        }
+        MouseProtocolEncoding::Default => {
+            let mut out = b"\x1b[M".to_vec();
+            for value in [code + 32, col + 32, row + 32] {
+                out.push(u8::try_from(value).ok()?);
+            }
+            Some(out)
+        }
+    }
+}
+
+fn button_code(button: MouseButton) -> u32 {
+    match button {
+        MouseButton::Left => 0,
+        MouseButton::Middle => 1,
+        MouseButton::Right => 2,
+    }
+}
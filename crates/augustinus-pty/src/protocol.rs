@@ -0,0 +1,248 @@
+//! Frames exchanged with the PTY daemon over its Unix socket.
+//!
+//! A frame is a tag byte, a little-endian `u32` payload length and the
+//! payload. Strings and byte strings inside a payload are length-prefixed
+//! the same way. A connection starts with `Open` (or `List`) and is then
+//! bound to that one session until either side hangs up.
+
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+use crate::builder::PtyBuilder;
+
+/// Largest payload accepted, so a corrupt length can't allocate gigabytes.
+const MAX_PAYLOAD: u32 = 16 << 20;
+
+#[derive(Debug, Clone)]
+pub(crate) enum Request {
+    /// Attaches to session `key`, starting `spec` under that key first if
+    /// there is no such session.
+    Open { key: String, spec: PtyBuilder },
+    List,
+    Input(Vec<u8>),
+    Resize { cols: u16, rows: u16 },
+    Kill,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Reply {
+    Attached { pid: Option<u32> },
+    Output(Vec<u8>),
+    Exited(u32),
+    Sessions(Vec<String>),
+    Error(String),
+}
+
+impl Request {
+    pub(crate) fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut payload = Encoder::default();
+        let tag = match self {
+            Self::Open { key, spec } => {
+                payload.str(key);
+                payload.str(&spec.program);
+                payload.strings(&spec.args);
+                payload.str(&spec.cwd.as_ref().map(|cwd| cwd.to_string_lossy()).unwrap_or_default());
+                payload.u32(spec.env.len() as u32);
+                for (key, value) in &spec.env {
+                    payload.str(key);
+                    payload.str(value);
+                }
+                payload.str(&spec.term);
+                payload.str(spec.colorterm.as_deref().unwrap_or_default());
+                payload.u16(spec.cols);
+                payload.u16(spec.rows);
+                b'o'
+            }
+            Self::List => b'l',
+            Self::Input(bytes) => {
+                payload.bytes(bytes);
+                b'i'
+            }
+            Self::Resize { cols, rows } => {
+                payload.u16(*cols);
+                payload.u16(*rows);
+                b'r'
+            }
+            Self::Kill => b'k',
+        };
+        write_frame(out, tag, &payload.0)
+    }
+
+    /// Reads the next request; `None` once the peer has hung up.
+    pub(crate) fn read_from(input: &mut impl Read) -> io::Result<Option<Self>> {
+        let Some((tag, payload)) = read_frame(input)? else {
+            return Ok(None);
+        };
+        let mut d = Decoder(&payload);
+        let request = match tag {
+            b'o' => {
+                let key = d.str()?;
+                let program = d.str()?;
+                let mut spec = PtyBuilder::new(program).args(d.strings()?);
+                let cwd = d.str()?;
+                if !cwd.is_empty() {
+                    spec = spec.cwd(PathBuf::from(cwd));
+                }
+                for _ in 0..d.u32()? {
+                    let key = d.str()?;
+                    spec = spec.env(key, d.str()?);
+                }
+                spec = spec.term(d.str()?);
+                let colorterm = d.str()?;
+                if !colorterm.is_empty() {
+                    spec = spec.colorterm(colorterm);
+                }
+                let cols = d.u16()?;
+                Self::Open { key, spec: spec.size(cols, d.u16()?) }
+            }
+            b'l' => Self::List,
+            b'i' => Self::Input(d.bytes()?.to_vec()),
+            b'r' => Self::Resize { cols: d.u16()?, rows: d.u16()? },
+            b'k' => Self::Kill,
+            _ => return Err(invalid("unknown request")),
+        };
+        Ok(Some(request))
+    }
+}
+
+impl Reply {
+    pub(crate) fn write_to(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut payload = Encoder::default();
+        let tag = match self {
+            Self::Attached { pid } => {
+                // 0 is never a child's pid, so it stands for "unknown".
+                payload.u32(pid.unwrap_or(0));
+                b'a'
+            }
+            Self::Output(bytes) => {
+                payload.bytes(bytes);
+                b'o'
+            }
+            Self::Exited(code) => {
+                payload.u32(*code);
+                b'x'
+            }
+            Self::Sessions(keys) => {
+                payload.strings(keys);
+                b's'
+            }
+            Self::Error(message) => {
+                payload.str(message);
+                b'e'
+            }
+        };
+        write_frame(out, tag, &payload.0)
+    }
+
+    /// Reads the next reply; `None` once the daemon has hung up.
+    pub(crate) fn read_from(input: &mut impl Read) -> io::Result<Option<Self>> {
+        let Some((tag, payload)) = read_frame(input)? else {
+            return Ok(None);
+        };
+        let mut d = Decoder(&payload);
+        let reply = match tag {
+            b'a' => Self::Attached { pid: Some(d.u32()?).filter(|pid| *pid != 0) },
+            b'o' => Self::Output(d.bytes()?.to_vec()),
+            b'x' => Self::Exited(d.u32()?),
+            b's' => Self::Sessions(d.strings()?),
+            b'e' => Self::Error(d.str()?),
+            _ => return Err(invalid("unknown reply")),
+        };
+        Ok(Some(reply))
+    }
+}
+
+fn write_frame(out: &mut impl Write, tag: u8, payload: &[u8]) -> io::Result<()> {
+    let mut frame = Vec::with_capacity(payload.len() + 5);
+    frame.push(tag);
+    frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    frame.extend_from_slice(payload);
+    out.write_all(&frame)?;
+    out.flush()
+}
+
+fn read_frame(input: &mut impl Read) -> io::Result<Option<(u8, Vec<u8>)>> {
+    let mut header = [0u8; 5];
+    match input.read_exact(&mut header) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+    let len = u32::from_le_bytes([header[1], header[2], header[3], header[4]]);
+    if len > MAX_PAYLOAD {
+        return Err(invalid("frame too large"));
+    }
+    let mut payload = vec![0u8; len as usize];
+    input.read_exact(&mut payload)?;
+    Ok(Some((header[0], payload)))
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[derive(Default)]
+struct Encoder(Vec<u8>);
+
+impl Encoder {
+    fn u16(&mut self, value: u16) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.u32(bytes.len() as u32);
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn str(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    fn strings(&mut self, values: &[String]) {
+        self.u32(values.len() as u32);
+        for value in values {
+            self.str(value);
+        }
+    }
+}
+
+struct Decoder<'a>(&'a [u8]);
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(invalid("truncated frame"));
+        }
+        let (head, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn u16(&mut self) -> io::Result<u16> {
+        let b = self.take(2)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        let b = self.take(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn bytes(&mut self) -> io::Result<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    fn str(&mut self) -> io::Result<String> {
+        String::from_utf8(self.bytes()?.to_vec()).map_err(|_| invalid("invalid utf-8"))
+    }
+
+    fn strings(&mut self) -> io::Result<Vec<String>> {
+        let count = self.u32()?;
+        (0..count).map(|_| self.str()).collect()
+    }
+}
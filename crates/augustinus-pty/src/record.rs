@@ -0,0 +1,138 @@
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+
+/// Writes PTY output to an asciicast v2 file: a JSON header line followed by
+/// one `[time, code, data]` event per line.
+pub struct CastRecorder {
+    path: PathBuf,
+    out: BufWriter<File>,
+    started: Instant,
+    /// Trailing bytes of an unfinished UTF-8 sequence, held for the next chunk.
+    pending: Vec<u8>,
+}
+
+impl CastRecorder {
+    /// Starts the file at `path` for a terminal of `cols`x`rows` whose child
+    /// runs with `term` and `shell` as `TERM` and `SHELL`.
+    pub fn create(
+        path: impl AsRef<Path>,
+        cols: u16,
+        rows: u16,
+        term: &str,
+        shell: Option<&str>,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("create recordings dir {}", parent.display()))?;
+        }
+        let file =
+            File::create(path).with_context(|| format!("create recording {}", path.display()))?;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let mut env = format!(r#""TERM":"{}""#, json_escape(term));
+        if let Some(shell) = shell {
+            env.push_str(&format!(r#","SHELL":"{}""#, json_escape(shell)));
+        }
+        let mut out = BufWriter::new(file);
+        writeln!(
+            out,
+            r#"{{"version":2,"width":{cols},"height":{rows},"timestamp":{timestamp},"env":{{{env}}}}}"#
+        )
+        .context("write cast header")?;
+        out.flush().context("flush cast header")?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            out,
+            started: Instant::now(),
+            pending: Vec::new(),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Records bytes the child wrote at `at`.
+    pub fn output(&mut self, at: Instant, bytes: &[u8]) -> Result<()> {
+        self.pending.extend_from_slice(bytes);
+        let text = take_utf8(&mut self.pending);
+        if text.is_empty() {
+            return Ok(());
+        }
+        self.event(at, "o", &text)
+    }
+
+    pub fn resize(&mut self, cols: u16, rows: u16) -> Result<()> {
+        self.event(Instant::now(), "r", &format!("{cols}x{rows}"))
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        self.out.flush().context("flush recording")
+    }
+
+    fn event(&mut self, at: Instant, code: &str, data: &str) -> Result<()> {
+        let time = at.saturating_duration_since(self.started).as_secs_f64();
+        writeln!(self.out, "[{time:.6}, \"{code}\", \"{}\"]", json_escape(data))
+            .context("write cast event")
+    }
+}
+
+/// Drains the longest decodable prefix of `buf`, replacing invalid bytes and
+/// leaving an incomplete trailing sequence in place.
+fn take_utf8(buf: &mut Vec<u8>) -> String {
+    let mut text = String::new();
+    let mut rest: &[u8] = buf;
+    loop {
+        match std::str::from_utf8(rest) {
+            Ok(valid) => {
+                text.push_str(valid);
+                rest = &[];
+                break;
+            }
+            Err(err) => {
+                let (valid, after) = rest.split_at(err.valid_up_to());
+                text.push_str(std::str::from_utf8(valid).unwrap_or_default());
+                match err.error_len() {
+                    Some(len) => {
+                        text.push(char::REPLACEMENT_CHARACTER);
+                        rest = &after[len..];
+                    }
+                    None => {
+                        rest = after;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    let consumed = buf.len() - rest.len();
+    buf.drain(..consumed);
+    text
+}
+
+fn json_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 || ch == '\u{7f}' => {
+                out.push_str(&format!("\\u{:04x}", ch as u32));
+            }
+            ch => out.push(ch),
+        }
+    }
+    out
+}
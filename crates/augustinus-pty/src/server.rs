@@ -0,0 +1,434 @@
+//! The PTY daemon: owns sessions so they outlive the UI. Each client
+//! connection attaches to one session by key, gets its current screen
+//! replayed and then streams its output, tmux-style.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Read},
+    os::unix::{
+        fs::{DirBuilderExt, MetadataExt, PermissionsExt},
+        net::{UnixListener, UnixStream},
+    },
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        mpsc::{self, Sender, SyncSender},
+        Arc, Mutex, MutexGuard,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use portable_pty::{Child, ChildKiller, MasterPty, PtySize};
+
+use crate::builder::PtyBuilder;
+use crate::callbacks::SessionCallbacks;
+use crate::protocol::{Reply, Request};
+use crate::session::SCROLLBACK_LINES;
+use crate::transport::{input_writer, OpenedPty};
+
+/// How long the daemon lingers with no sessions and no clients.
+const IDLE_EXIT: Duration = Duration::from_secs(5);
+/// Pause between accept attempts while checking for idleness.
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Replies a client may fall behind by before it stops hearing output.
+const CLIENT_QUEUE: usize = 1024;
+/// How long a killed child gets to exit after its hang-up.
+const KILL_GRACE: Duration = Duration::from_millis(250);
+
+type Sessions = Arc<Mutex<HashMap<String, Arc<HostedSession>>>>;
+
+/// Listens on a Unix socket and hosts PTY sessions for clients.
+///
+/// ```no_run
+/// # use augustinus_pty::PtyServer;
+/// PtyServer::bind("/run/user/1000/augustinus/pty.sock")?.run()?;
+/// # anyhow::Ok(())
+/// ```
+pub struct PtyServer {
+    listener: UnixListener,
+    path: PathBuf,
+    sessions: Sessions,
+    clients: Arc<AtomicUsize>,
+}
+
+/// One child hosted by the daemon. Input, kills and the wait for the exit
+/// each go through their own handle, so none of them waits on the screen
+/// state or on each other.
+struct HostedSession {
+    state: Mutex<Hosted>,
+    /// Input for the thread writing to the child.
+    input: Sender<Vec<u8>>,
+    killer: Mutex<Box<dyn ChildKiller + Send + Sync>>,
+    pid: Option<u32>,
+}
+
+/// A started child's output and handle, for the thread that reads it
+/// until it exits.
+struct ChildOutput {
+    reader: Box<dyn Read + Send>,
+    child: Box<dyn Child + Send + Sync>,
+}
+
+/// The screen side of a hosted session, with a parser mirroring the
+/// child's screen so a client attaching later can be brought up to date.
+struct Hosted {
+    master: Box<dyn MasterPty + Send>,
+    parser: vt100::Parser<SessionCallbacks>,
+    /// The attached client's connection id and reply queue.
+    client: Option<(u64, SyncSender<Reply>)>,
+    exit_code: Option<u32>,
+}
+
+impl PtyServer {
+    /// Binds `path`, creating its directory private to the user. An
+    /// existing directory must already be private. A socket left behind by
+    /// a dead daemon is replaced; a live one is an error.
+    pub fn bind(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(dir) = path.parent() {
+            fs::DirBuilder::new()
+                .recursive(true)
+                .mode(0o700)
+                .create(dir)
+                .with_context(|| format!("create {}", dir.display()))?;
+            check_private(dir)?;
+        }
+        if path.exists() {
+            if UnixStream::connect(&path).is_ok() {
+                bail!("a pty daemon is already listening on {}", path.display());
+            }
+            fs::remove_file(&path).with_context(|| format!("remove stale {}", path.display()))?;
+        }
+        let listener =
+            UnixListener::bind(&path).with_context(|| format!("bind {}", path.display()))?;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600))
+            .with_context(|| format!("restrict {}", path.display()))?;
+        listener.set_nonblocking(true).context("set listener non-blocking")?;
+        Ok(Self {
+            listener,
+            path,
+            sessions: Arc::default(),
+            clients: Arc::default(),
+        })
+    }
+
+    /// Serves clients until no session and no client has been left for a
+    /// while.
+    pub fn run(self) -> Result<()> {
+        let next_id = AtomicU64::new(0);
+        let mut idle_since = Instant::now();
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    stream.set_nonblocking(false).context("set client blocking")?;
+                    let id = next_id.fetch_add(1, Ordering::Relaxed);
+                    let sessions = self.sessions.clone();
+                    let clients = self.clients.clone();
+                    clients.fetch_add(1, Ordering::SeqCst);
+                    thread::spawn(move || {
+                        let _ = serve(stream, id, &sessions);
+                        clients.fetch_sub(1, Ordering::SeqCst);
+                    });
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    let idle = self.clients.load(Ordering::SeqCst) == 0
+                        && lock(&self.sessions).is_empty();
+                    if !idle {
+                        idle_since = Instant::now();
+                    } else if idle_since.elapsed() >= IDLE_EXIT {
+                        break;
+                    }
+                    thread::sleep(ACCEPT_INTERVAL);
+                }
+                Err(err) => return Err(err).context("accept client"),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for PtyServer {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn serve(mut stream: UnixStream, id: u64, sessions: &Sessions) -> Result<()> {
+    let Some(request) = Request::read_from(&mut stream)? else {
+        return Ok(());
+    };
+    let (key, spec) = match request {
+        Request::Open { key, spec } => (key, spec),
+        Request::List => {
+            let mut keys: Vec<String> = lock(sessions).keys().cloned().collect();
+            keys.sort();
+            Reply::Sessions(keys).write_to(&mut stream)?;
+            return Ok(());
+        }
+        _ => {
+            Reply::Error("expected an open request".to_string()).write_to(&mut stream)?;
+            return Ok(());
+        }
+    };
+
+    let hosted = match open_session(&key, &spec, sessions) {
+        Ok(hosted) => hosted,
+        Err(err) => {
+            Reply::Error(format!("{err:#}")).write_to(&mut stream)?;
+            return Ok(());
+        }
+    };
+
+    let replies = client_writer(stream.try_clone()?);
+    {
+        let mut session = lock(&hosted.state);
+        let (rows, cols) = session.parser.screen().size();
+        if (spec.cols, spec.rows) != (cols, rows) {
+            session.resize(spec.cols, spec.rows);
+        }
+        let replay = replay(&mut session.parser);
+        let _ = replies.try_send(Reply::Attached { pid: hosted.pid });
+        let _ = replies.try_send(Reply::Output(replay));
+        if let Some(code) = session.exit_code {
+            let _ = replies.try_send(Reply::Exited(code));
+            return Ok(());
+        }
+        // A newer client takes over; the old one just stops hearing output.
+        session.client = Some((id, replies));
+    }
+
+    while let Some(request) = Request::read_from(&mut stream)? {
+        match request {
+            Request::Input(bytes) => {
+                let _ = hosted.input.send(bytes);
+            }
+            Request::Resize { cols, rows } => lock(&hosted.state).resize(cols, rows),
+            Request::Kill => hosted.kill(),
+            _ => {}
+        }
+    }
+    let mut session = lock(&hosted.state);
+    if session.client.as_ref().is_some_and(|(client, _)| *client == id) {
+        session.client = None;
+    }
+    Ok(())
+}
+
+/// The session registered under `key`, started from `spec` if there is
+/// none. The child is spawned without holding the session map.
+fn open_session(key: &str, spec: &PtyBuilder, sessions: &Sessions) -> Result<Arc<HostedSession>> {
+    if let Some(hosted) = lock(sessions).get(key) {
+        return Ok(hosted.clone());
+    }
+    let (hosted, mut output) = HostedSession::start(spec)?;
+    {
+        let mut map = lock(sessions);
+        if let Some(current) = map.get(key) {
+            // Another client started the same key meanwhile.
+            let current = current.clone();
+            drop(map);
+            let _ = output.child.kill();
+            return Ok(current);
+        }
+        map.insert(key.to_string(), hosted.clone());
+    }
+    // Only once registered, so a child that exits right away is still
+    // unregistered.
+    hosted.spawn_reader(key, output, sessions);
+    Ok(hosted)
+}
+
+/// Starts a thread writing queued replies to a client, so one that stops
+/// reading holds up nobody else.
+fn client_writer(mut stream: UnixStream) -> SyncSender<Reply> {
+    let (tx, rx) = mpsc::sync_channel::<Reply>(CLIENT_QUEUE);
+    thread::spawn(move || {
+        for reply in rx {
+            if reply.write_to(&mut stream).is_err() {
+                break;
+            }
+        }
+    });
+    tx
+}
+
+impl HostedSession {
+    /// Starts `spec`; the caller registers the session, then hands its
+    /// output to [`Self::spawn_reader`].
+    fn start(spec: &PtyBuilder) -> Result<(Arc<Self>, ChildOutput)> {
+        let OpenedPty {
+            master,
+            writer,
+            reader,
+            child,
+        } = OpenedPty::open(spec)?;
+        let hosted = Arc::new(Self {
+            state: Mutex::new(Hosted {
+                master,
+                parser: vt100::Parser::new_with_callbacks(
+                    spec.rows,
+                    spec.cols,
+                    SCROLLBACK_LINES,
+                    SessionCallbacks::default(),
+                ),
+                client: None,
+                exit_code: None,
+            }),
+            input: input_writer(writer),
+            killer: Mutex::new(child.clone_killer()),
+            pid: child.process_id(),
+        });
+        Ok((hosted, ChildOutput { reader, child }))
+    }
+
+    /// Mirrors the child's output into the parser and to the attached
+    /// client; once the output ends, unregisters the session and reports
+    /// the exit code.
+    fn spawn_reader(
+        self: &Arc<Self>,
+        key: &str,
+        output: ChildOutput,
+        sessions: &Sessions,
+    ) {
+        let ChildOutput {
+            mut reader,
+            mut child,
+        } = output;
+        let key = key.to_string();
+        let sessions = sessions.clone();
+        let session = self.clone();
+        thread::spawn(move || {
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = match reader.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => n,
+                };
+                let mut hosted = lock(&session.state);
+                hosted.parser.process(&buf[..n]);
+                hosted.send(Reply::Output(buf[..n].to_vec()));
+            }
+            {
+                let mut map = lock(&sessions);
+                if map.get(&key).is_some_and(|current| Arc::ptr_eq(current, &session)) {
+                    map.remove(&key);
+                }
+            }
+            // Waits without the lock, so a child that closed its output but
+            // keeps running can still be killed. A failed wait means the
+            // child was already reaped elsewhere.
+            let code = child.wait().map_or(1, |status| status.exit_code());
+            let mut hosted = lock(&session.state);
+            hosted.exit_code = Some(code);
+            hosted.send(Reply::Exited(code));
+            hosted.client = None;
+        });
+    }
+
+    /// Hangs up on the child, then kills it if it is still there after
+    /// [`KILL_GRACE`]. Takes the session lock only to look at the exit code.
+    fn kill(&self) {
+        if lock(&self.killer).kill().is_err() {
+            return;
+        }
+        let deadline = Instant::now() + KILL_GRACE;
+        while lock(&self.state).exit_code.is_none() {
+            if Instant::now() >= deadline {
+                if let Some(pid) = self.pid {
+                    // SAFETY: `kill` only sends a signal.
+                    unsafe { libc::kill(pid as libc::pid_t, libc::SIGKILL) };
+                }
+                return;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+impl Hosted {
+    /// Queues `reply` for the attached client, dropping a client that has
+    /// fallen too far behind.
+    fn send(&mut self, reply: Reply) {
+        if let Some((_, client)) = &self.client
+            && client.try_send(reply).is_err()
+        {
+            self.client = None;
+        }
+    }
+
+    fn resize(&mut self, cols: u16, rows: u16) {
+        let (cols, rows) = (cols.max(1), rows.max(1));
+        let size = PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        };
+        if self.master.resize(size).is_ok() {
+            self.parser.screen_mut().set_size(rows, cols);
+        }
+    }
+}
+
+/// Bytes that rebuild `parser`'s state on a fresh terminal of the same
+/// size: the scrollback scrolled past, then the screen with its modes.
+fn replay(parser: &mut vt100::Parser<SessionCallbacks>) -> Vec<u8> {
+    let mut out = Vec::new();
+    if let Some(title) = &parser.callbacks().title {
+        out.extend_from_slice(format!("\x1b]2;{title}\x07").as_bytes());
+    }
+    let screen = parser.screen_mut();
+    let (rows, cols) = screen.size();
+    if screen.alternate_screen() {
+        out.extend_from_slice(b"\x1b[?1049h");
+    } else {
+        let saved = screen.scrollback();
+        screen.set_scrollback(usize::MAX);
+        let held = screen.scrollback();
+        // Walk the scrollback a screenful at a time, oldest first, keeping
+        // only rows that are above the live screen.
+        let mut offset = held;
+        while offset > 0 {
+            screen.set_scrollback(offset);
+            let take = offset.min(rows as usize);
+            for line in screen.rows_formatted(0, cols).take(take) {
+                out.extend_from_slice(&line);
+                out.extend_from_slice(b"\x1b[m\r\n");
+            }
+            offset -= take;
+        }
+        screen.set_scrollback(saved);
+        // Push the last of those lines off the screen too, so drawing the
+        // screen below can't clear them.
+        if held > 0 {
+            out.extend(b"\r\n".repeat(rows.saturating_sub(1) as usize));
+        }
+    }
+    out.extend_from_slice(&parser.screen().state_formatted());
+    out
+}
+
+/// Fails unless `dir` is owned by this user and closed to everyone else,
+/// so nobody else can reach the socket, even before it is restricted.
+fn check_private(dir: &Path) -> Result<()> {
+    let meta = fs::metadata(dir).with_context(|| format!("inspect {}", dir.display()))?;
+    // SAFETY: `getuid` has no preconditions and cannot fail.
+    let uid = unsafe { libc::getuid() };
+    if meta.uid() != uid {
+        bail!("{} belongs to another user", dir.display());
+    }
+    let mode = meta.permissions().mode() & 0o777;
+    if mode & 0o077 != 0 {
+        bail!("{} is open to other users (mode {mode:o}); expected 700", dir.display());
+    }
+    Ok(())
+}
+
+/// Locks `mutex`, carrying on with the data if a thread panicked holding it.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
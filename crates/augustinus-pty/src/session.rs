@@ -1,58 +1,79 @@
 use std::{
-    io::{Read, Write},
+    io::Read,
     sync::mpsc,
     thread,
+    time::{Duration, Instant},
 };
 
 use anyhow::{Context, Result};
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use portable_pty::{native_pty_system, CommandBuilder, PtySize};
+use augustinus_term::{ShellMark, TermCell, TermColor, TerminalScreen};
+use crossterm::event::{KeyEvent, MouseEvent};
 
-pub struct PtySnapshot {
-    pub contents: String,
-    pub cursor_row: u16,
-    pub cursor_col: u16,
-}
+use crate::builder::PtyBuilder;
+use crate::callbacks::{scrollback_len, scrolled_in_chunk, SessionCallbacks};
+#[cfg(unix)]
+use crate::client::RemotePty;
+use crate::keys::key_to_bytes;
+use crate::mouse::mouse_to_bytes;
+use crate::record::CastRecorder;
+use crate::transport::{LocalPty, Transport};
+
+/// Output chunks the reader thread may queue before it blocks, which in turn
+/// stalls the child instead of growing memory during an output flood.
+const OUTPUT_QUEUE_CHUNKS: usize = 64;
+
+/// Scrollback lines kept per session, here and in the daemon.
+pub(crate) const SCROLLBACK_LINES: usize = 2000;
+
+/// How often the foreground process is looked up while the PTY is quiet.
+const FOREGROUND_REFRESH: Duration = Duration::from_secs(1);
+/// Minimum gap between lookups while output keeps arriving.
+const FOREGROUND_MIN_GAP: Duration = Duration::from_millis(250);
+
+pub(crate) type OutputSender = mpsc::SyncSender<(Instant, Vec<u8>)>;
 
 pub struct PtySession {
-    master: Box<dyn portable_pty::MasterPty + Send>,
-    writer: Box<dyn Write + Send>,
-    parser: vt100::Parser,
-    rx: mpsc::Receiver<Vec<u8>>,
+    transport: Box<dyn Transport>,
+    /// Session key in the daemon; `None` for a local PTY.
+    daemon_key: Option<String>,
+    /// `TERM` and `SHELL` as the child was given them.
+    term: String,
+    shell: Option<String>,
+    parser: vt100::Parser<SessionCallbacks>,
+    rx: mpsc::Receiver<(Instant, Vec<u8>)>,
+    recorder: Option<CastRecorder>,
+    /// The screen may differ from the last `update_snapshot`.
+    dirty: bool,
+    /// When the foreground process was last looked up, and its name then.
+    foreground: Option<(Instant, Option<String>)>,
+    /// Output arrived since that lookup, so a new command may be running.
+    output_since_foreground: bool,
     _reader_thread: thread::JoinHandle<()>,
 }
 
 impl PtySession {
+    /// Starts configuring a child process; see [`PtyBuilder`].
+    pub fn builder(program: impl Into<String>) -> PtyBuilder {
+        PtyBuilder::new(program)
+    }
+
     pub fn spawn_command(program: &str, args: &[&str], cols: u16, rows: u16) -> Result<Self> {
-        let pty_system = native_pty_system();
-        let pair = pty_system
-            .openpty(PtySize {
-                rows,
-                cols,
-                pixel_width: 0,
-                pixel_height: 0,
-            })
-            .context("open pty")?;
-
-        let mut cmd = CommandBuilder::new(program);
-        cmd.args(args);
-        cmd.env("TERM", "xterm-256color");
-        let _child = pair
-            .slave
-            .spawn_command(cmd)
-            .with_context(|| format!("spawn command: {program}"))?;
-
-        let mut reader = pair.master.try_clone_reader().context("clone pty reader")?;
-        let writer = pair.master.take_writer().context("take pty writer")?;
-
-        let (tx, rx) = mpsc::channel::<Vec<u8>>();
+        Self::builder(program)
+            .args(args.iter().copied())
+            .size(cols, rows)
+            .spawn()
+    }
+
+    pub(crate) fn local(builder: &PtyBuilder) -> Result<Self> {
+        let (pty, mut reader) = LocalPty::open(builder)?;
+        let (tx, rx) = mpsc::sync_channel(OUTPUT_QUEUE_CHUNKS);
         let reader_thread = thread::spawn(move || {
             let mut buf = [0u8; 4096];
             loop {
                 match reader.read(&mut buf) {
                     Ok(0) => break,
                     Ok(n) => {
-                        if tx.send(buf[..n].to_vec()).is_err() {
+                        if tx.send((Instant::now(), buf[..n].to_vec())).is_err() {
                             break;
                         }
                     }
@@ -60,14 +81,41 @@ impl PtySession {
                 }
             }
         });
+        Ok(Self::new(Box::new(pty), None, rx, reader_thread, builder))
+    }
 
-        Ok(Self {
-            master: pair.master,
-            writer,
-            parser: vt100::Parser::new(rows, cols, 2000),
+    #[cfg(unix)]
+    pub(crate) fn remote(builder: &PtyBuilder, socket: &std::path::Path, key: &str) -> Result<Self> {
+        let (tx, rx) = mpsc::sync_channel(OUTPUT_QUEUE_CHUNKS);
+        let (pty, reader_thread) = RemotePty::attach(socket, key, builder, tx)?;
+        Ok(Self::new(Box::new(pty), Some(key.to_string()), rx, reader_thread, builder))
+    }
+
+    fn new(
+        transport: Box<dyn Transport>,
+        daemon_key: Option<String>,
+        rx: mpsc::Receiver<(Instant, Vec<u8>)>,
+        reader_thread: thread::JoinHandle<()>,
+        builder: &PtyBuilder,
+    ) -> Self {
+        Self {
+            transport,
+            daemon_key,
+            term: builder.child_env("TERM").unwrap_or_default(),
+            shell: builder.child_env("SHELL"),
+            parser: vt100::Parser::new_with_callbacks(
+                builder.rows,
+                builder.cols,
+                SCROLLBACK_LINES,
+                SessionCallbacks::default(),
+            ),
             rx,
+            recorder: None,
+            dirty: true,
+            foreground: None,
+            output_since_foreground: false,
             _reader_thread: reader_thread,
-        })
+        }
     }
 
     pub fn spawn(shell: &str, cols: u16, rows: u16) -> Result<Self> {
@@ -77,78 +125,354 @@ impl PtySession {
     pub fn resize(&mut self, cols: u16, rows: u16) -> Result<()> {
         let rows = rows.max(1);
         let cols = cols.max(1);
-        self.master
-            .resize(PtySize {
-                rows,
-                cols,
-                pixel_width: 0,
-                pixel_height: 0,
-            })
-            .context("resize pty")?;
-        self.parser.set_size(rows, cols);
+        self.transport.resize(cols, rows)?;
+        self.parser.screen_mut().set_size(rows, cols);
+        self.dirty = true;
+        if let Some(recorder) = &mut self.recorder
+            && recorder.resize(cols, rows).is_err()
+        {
+            self.recorder = None;
+        }
         Ok(())
     }
 
-    pub fn poll(&mut self) {
-        while let Ok(chunk) = self.rx.try_recv() {
-            self.parser.process(&chunk);
+    /// Terminal size as `(cols, rows)`.
+    pub fn size(&self) -> (u16, u16) {
+        let (rows, cols) = self.parser.screen().size();
+        (cols, rows)
+    }
+
+    /// Feeds pending output to the parser; returns whether there was any.
+    /// Takes at most one queue's worth per call so a flood can't stall the
+    /// caller; the rest waits for the next poll.
+    pub fn poll(&mut self) -> bool {
+        let mut received = false;
+        let mut recorded = false;
+        for _ in 0..OUTPUT_QUEUE_CHUNKS {
+            let Ok((at, chunk)) = self.rx.try_recv() else {
+                break;
+            };
+            received = true;
+            self.process(at, &chunk);
+            if let Some(recorder) = &mut self.recorder {
+                if recorder.output(at, &chunk).is_err() {
+                    self.recorder = None;
+                } else {
+                    recorded = true;
+                }
+            }
+        }
+        if recorded
+            && let Some(recorder) = &mut self.recorder
+            && recorder.flush().is_err()
+        {
+            self.recorder = None;
         }
+        self.dirty |= received;
+        self.output_since_foreground |= received;
+        received
     }
 
-    pub fn snapshot(&self) -> PtySnapshot {
-        let screen = self.parser.screen();
-        let (row, col) = screen.cursor_position();
-        PtySnapshot {
-            contents: screen.contents(),
-            cursor_row: row,
-            cursor_col: col,
+    /// Parses one chunk while counting the lines it scrolls off, so shell
+    /// marks get line numbers that survive scrollback trimming. vt100 only
+    /// reveals scrolling by moving a non-zero scrollback offset along, so
+    /// the offset is parked at 1 meanwhile and the view restored after.
+    fn process(&mut self, at: Instant, chunk: &[u8]) {
+        let screen = self.parser.screen_mut();
+        let view = screen.scrollback();
+        let chunk_scrollback = scrollback_len(screen);
+        screen.set_scrollback(1);
+        let callbacks = self.parser.callbacks_mut();
+        callbacks.chunk_scrollback = chunk_scrollback;
+        callbacks.chunk_at = at;
+        self.parser.process(chunk);
+
+        let screen = self.parser.screen_mut();
+        let scrolled = scrolled_in_chunk(screen, chunk_scrollback);
+        let len = scrollback_len(screen);
+        let view = if view > 0 {
+            (view + scrolled as usize).min(len)
+        } else {
+            0
+        };
+        screen.set_scrollback(view);
+        self.parser.callbacks_mut().scrolled += scrolled;
+    }
+
+    /// Tees all further output into `recorder`, replacing any current one.
+    pub fn start_recording(&mut self, recorder: CastRecorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Stops recording and hands the recorder back, e.g. to continue the same
+    /// file in a respawned session.
+    pub fn stop_recording(&mut self) -> Option<CastRecorder> {
+        let mut recorder = self.recorder.take()?;
+        let _ = recorder.flush();
+        Some(recorder)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    pub fn snapshot(&self) -> TerminalScreen {
+        let mut out = TerminalScreen::default();
+        copy_screen(self.parser.screen(), &mut out);
+        out
+    }
+
+    /// Brings `out` up to date with the screen, touching only cells that
+    /// changed. Returns `false` without looking at the screen when nothing
+    /// happened since the last call.
+    pub fn update_snapshot(&mut self, out: &mut TerminalScreen) -> bool {
+        let (rows, cols) = self.parser.screen().size();
+        if !self.dirty && out.rows() == rows && out.cols() == cols {
+            return false;
+        }
+        self.dirty = false;
+        copy_screen(self.parser.screen(), out)
+    }
+
+    /// Scrollback followed by the live screen as one tall screen; row `0` is
+    /// the oldest line still held. The cursor is moved into the same
+    /// coordinates.
+    pub fn history(&mut self) -> TerminalScreen {
+        let max_offset = self.scrollback_len();
+        let screen = self.parser.screen_mut();
+        let saved = screen.scrollback();
+        let (rows, cols) = screen.size();
+        let total = (max_offset + rows as usize).min(u16::MAX as usize) as u16;
+        let mut out = TerminalScreen::new(total, cols);
+        let mut offset = max_offset;
+        loop {
+            screen.set_scrollback(offset);
+            let top = max_offset - offset;
+            for row in 0..rows {
+                let Ok(line) = u16::try_from(top + row as usize) else {
+                    break;
+                };
+                for col in 0..cols {
+                    if let (Some(src), Some(dst)) = (screen.cell(row, col), out.cell_mut(line, col)) {
+                        *dst = convert_cell(src);
+                    }
+                }
+            }
+            if offset == 0 {
+                break;
+            }
+            offset = offset.saturating_sub(rows as usize);
+        }
+        let (cursor_row, cursor_col) = screen.cursor_position();
+        out.cursor_row = (max_offset + cursor_row as usize).min(total.saturating_sub(1) as usize) as u16;
+        out.cursor_col = cursor_col;
+        out.hide_cursor = screen.hide_cursor();
+        screen.set_scrollback(saved);
+        out
+    }
+
+    /// Number of lines currently held in the scrollback buffer.
+    pub fn scrollback_len(&mut self) -> usize {
+        scrollback_len(self.parser.screen_mut())
+    }
+
+    /// Absolute line number (as in [`ShellMark::line`]) of history line `0`.
+    pub fn history_origin(&mut self) -> u64 {
+        let scrolled = self.parser.callbacks().scrolled;
+        scrolled.saturating_sub(self.scrollback_len() as u64)
+    }
+
+    pub fn scrollback(&self) -> usize {
+        self.parser.screen().scrollback()
+    }
+
+    /// Shows the screen `offset` lines back in history; `0` is the live screen.
+    pub fn set_scrollback(&mut self, offset: usize) {
+        let screen = self.parser.screen_mut();
+        if screen.scrollback() != offset {
+            screen.set_scrollback(offset);
+            self.dirty = true;
         }
     }
 
+    /// Window title the child set with OSC 0/2, if any.
+    pub fn title(&self) -> Option<&str> {
+        self.parser.callbacks().title.as_deref()
+    }
+
+    /// Whether the child rang the bell since the last call.
+    pub fn take_bell(&mut self) -> bool {
+        std::mem::take(&mut self.parser.callbacks_mut().bell)
+    }
+
+    /// Shell integration marks (OSC 133) received since the last call.
+    pub fn take_shell_marks(&mut self) -> Vec<ShellMark> {
+        std::mem::take(&mut self.parser.callbacks_mut().marks)
+    }
+
+    /// Name of the PTY's foreground process group leader, e.g. `vim` while
+    /// the shell runs it. Read from `/proc`, so `None` elsewhere. Looked up
+    /// again after output or once a second, not on every call.
+    pub fn foreground_process(&mut self) -> Option<String> {
+        let due = self.foreground.as_ref().is_none_or(|(at, _)| {
+            let since = at.elapsed();
+            since >= FOREGROUND_REFRESH
+                || (self.output_since_foreground && since >= FOREGROUND_MIN_GAP)
+        });
+        if due {
+            self.foreground = Some((Instant::now(), self.read_foreground_process()));
+            self.output_since_foreground = false;
+        }
+        self.foreground.as_ref().and_then(|(_, name)| name.clone())
+    }
+
+    fn read_foreground_process(&self) -> Option<String> {
+        let pid = self.transport.foreground_pid()?;
+        let comm = std::fs::read_to_string(format!("/proc/{pid}/comm")).ok()?;
+        let comm = comm.trim();
+        (!comm.is_empty()).then(|| comm.to_string())
+    }
+
+    /// Exit code of the child once it has terminated; `None` while running.
+    pub fn exit_code(&mut self) -> Option<u32> {
+        self.transport.try_exit_code()
+    }
+
+    pub fn is_running(&mut self) -> bool {
+        self.exit_code().is_none()
+    }
+
+    /// Terminates the child. Needed to end a daemon session, which merely
+    /// detaches when dropped; a local child is also killed on drop.
+    pub fn kill(&mut self) {
+        self.transport.kill();
+    }
+
+    /// Key of the daemon session this is attached to, if any.
+    pub fn daemon_key(&self) -> Option<&str> {
+        self.daemon_key.as_deref()
+    }
+
+    /// `TERM` the child was started with.
+    pub fn term(&self) -> &str {
+        &self.term
+    }
+
+    /// `SHELL` the child was started with, if set.
+    pub fn shell(&self) -> Option<&str> {
+        self.shell.as_deref()
+    }
+
     pub fn send_key(&mut self, key: KeyEvent) -> Result<()> {
-        if let Some(bytes) = key_to_bytes(key) {
-            self.writer.write_all(&bytes).context("write key bytes")?;
-            self.writer.flush().ok();
+        let application_cursor = self.parser.screen().application_cursor();
+        if let Some(bytes) = key_to_bytes(key, application_cursor) {
+            self.set_scrollback(0);
+            self.transport.write_all(&bytes).context("write key bytes")?;
         }
         Ok(())
     }
 
+    /// Whether the child has turned on mouse reporting.
+    pub fn wants_mouse(&self) -> bool {
+        self.parser.screen().mouse_protocol_mode() != vt100::MouseProtocolMode::None
+    }
+
+    /// Forwards a mouse event whose coordinates are relative to this screen.
+    /// Returns `false` when the child's reporting mode ignores the event.
+    pub fn send_mouse(&mut self, event: MouseEvent) -> Result<bool> {
+        let screen = self.parser.screen();
+        let Some(bytes) = mouse_to_bytes(
+            event,
+            screen.mouse_protocol_mode(),
+            screen.mouse_protocol_encoding(),
+        ) else {
+            return Ok(false);
+        };
+        self.transport.write_all(&bytes).context("write mouse bytes")?;
+        Ok(true)
+    }
+
     pub fn send_bytes(&mut self, bytes: &[u8]) -> Result<()> {
-        self.writer.write_all(bytes).context("write bytes")?;
-        self.writer.flush().ok();
+        self.set_scrollback(0);
+        self.transport.write_all(bytes).context("write bytes")?;
         Ok(())
     }
 
+    /// Sends pasted text, wrapped in `ESC[200~`/`ESC[201~` when the child
+    /// has enabled bracketed paste (mode 2004).
     pub fn send_paste(&mut self, text: &str) -> Result<()> {
-        self.send_bytes(text.as_bytes())
+        let text = text.replace("\r\n", "\r").replace('\n', "\r");
+        if !self.parser.screen().bracketed_paste() {
+            return self.send_bytes(text.as_bytes());
+        }
+        // Drop any end marker inside the text so it can't terminate the paste early.
+        let text = text.replace("\x1b[201~", "");
+        let mut bytes = Vec::with_capacity(text.len() + 12);
+        bytes.extend_from_slice(b"\x1b[200~");
+        bytes.extend_from_slice(text.as_bytes());
+        bytes.extend_from_slice(b"\x1b[201~");
+        self.send_bytes(&bytes)
     }
 }
 
-fn key_to_bytes(key: KeyEvent) -> Option<Vec<u8>> {
-    let mods = key.modifiers;
-    match key.code {
-        KeyCode::Enter => Some(vec![b'\r']),
-        KeyCode::Tab => Some(vec![b'\t']),
-        KeyCode::Backspace => Some(vec![0x7f]),
-        KeyCode::Esc => Some(vec![0x1b]),
-        KeyCode::Up => Some(b"\x1b[A".to_vec()),
-        KeyCode::Down => Some(b"\x1b[B".to_vec()),
-        KeyCode::Right => Some(b"\x1b[C".to_vec()),
-        KeyCode::Left => Some(b"\x1b[D".to_vec()),
-        KeyCode::Char(ch) => {
-            if mods.contains(KeyModifiers::CONTROL) {
-                Some(vec![ctrl_code(ch)])
-            } else {
-                let mut buf = [0u8; 4];
-                let s = ch.encode_utf8(&mut buf);
-                Some(s.as_bytes().to_vec())
+/// Copies `screen` into `out`, resizing it if needed; returns whether any
+/// cell or the cursor changed.
+fn copy_screen(screen: &vt100::Screen, out: &mut TerminalScreen) -> bool {
+    let (rows, cols) = screen.size();
+    let mut changed = false;
+    if out.rows() != rows || out.cols() != cols {
+        *out = TerminalScreen::new(rows, cols);
+        changed = true;
+    }
+    for row in 0..rows {
+        for col in 0..cols {
+            let (Some(src), Some(dst)) = (screen.cell(row, col), out.cell_mut(row, col)) else {
+                continue;
+            };
+            if !cell_matches(src, dst) {
+                *dst = convert_cell(src);
+                changed = true;
             }
         }
-        _ => None,
+    }
+    let (cursor_row, cursor_col) = screen.cursor_position();
+    let hide_cursor = screen.hide_cursor() || screen.scrollback() > 0;
+    changed |= (out.cursor_row, out.cursor_col, out.hide_cursor)
+        != (cursor_row, cursor_col, hide_cursor);
+    out.cursor_row = cursor_row;
+    out.cursor_col = cursor_col;
+    out.hide_cursor = hide_cursor;
+    changed
+}
+
+fn cell_matches(src: &vt100::Cell, dst: &TermCell) -> bool {
+    dst.symbol == src.contents()
+        && dst.fg == convert_color(src.fgcolor())
+        && dst.bg == convert_color(src.bgcolor())
+        && dst.bold == src.bold()
+        && dst.italic == src.italic()
+        && dst.underline == src.underline()
+        && dst.inverse == src.inverse()
+        && dst.wide_continuation == src.is_wide_continuation()
+}
+
+fn convert_cell(cell: &vt100::Cell) -> TermCell {
+    TermCell {
+        symbol: cell.contents().to_string(),
+        fg: convert_color(cell.fgcolor()),
+        bg: convert_color(cell.bgcolor()),
+        bold: cell.bold(),
+        italic: cell.italic(),
+        underline: cell.underline(),
+        inverse: cell.inverse(),
+        wide_continuation: cell.is_wide_continuation(),
     }
 }
 
-fn ctrl_code(ch: char) -> u8 {
-    let upper = ch.to_ascii_uppercase() as u8;
-    upper & 0x1f
+fn convert_color(color: vt100::Color) -> TermColor {
+    match color {
+        vt100::Color::Default => TermColor::Default,
+        vt100::Color::Idx(idx) => TermColor::Indexed(idx),
+        vt100::Color::Rgb(r, g, b) => TermColor::Rgb(r, g, b),
+    }
 }
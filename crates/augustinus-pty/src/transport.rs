@@ -0,0 +1,156 @@
+use std::{
+    io::{Read, Write},
+    sync::mpsc,
+    thread,
+};
+
+use anyhow::{anyhow, Context, Result};
+use portable_pty::{native_pty_system, Child, MasterPty, PtySize};
+
+use crate::builder::PtyBuilder;
+
+/// The side of a session that talks to the child: a PTY owned by this
+/// process, or one held by the daemon on the other end of a socket.
+pub(crate) trait Transport: Send {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<()>;
+    fn resize(&mut self, cols: u16, rows: u16) -> Result<()>;
+    /// Exit code once the child has terminated; `None` while running.
+    fn try_exit_code(&mut self) -> Option<u32>;
+    /// Terminates the child and waits until it is gone.
+    fn kill(&mut self);
+    /// Pid of the PTY's foreground process group leader, falling back to
+    /// the child itself.
+    fn foreground_pid(&self) -> Option<u32>;
+}
+
+/// A PTY just opened with its child started, in pieces for whoever takes
+/// it over.
+pub(crate) struct OpenedPty {
+    pub(crate) master: Box<dyn MasterPty + Send>,
+    pub(crate) writer: Box<dyn Write + Send>,
+    pub(crate) reader: Box<dyn Read + Send>,
+    pub(crate) child: Box<dyn Child + Send + Sync>,
+}
+
+impl OpenedPty {
+    /// Opens a PTY and starts `builder`'s command on it.
+    pub(crate) fn open(builder: &PtyBuilder) -> Result<Self> {
+        let cmd = builder.command()?;
+        let pair = native_pty_system()
+            .openpty(PtySize {
+                rows: builder.rows,
+                cols: builder.cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .context("open pty")?;
+
+        let child = pair
+            .slave
+            .spawn_command(cmd)
+            .with_context(|| format!("spawn command: {}", builder.program))?;
+
+        let reader = pair.master.try_clone_reader().context("clone pty reader")?;
+        let writer = pair.master.take_writer().context("take pty writer")?;
+        Ok(Self {
+            master: pair.master,
+            writer,
+            reader,
+            child,
+        })
+    }
+}
+
+/// Starts a thread writing queued input to `writer`. A child that stops
+/// reading then holds up only the input queued behind it, never the caller,
+/// which goes on draining the child's output.
+pub(crate) fn input_writer(mut writer: Box<dyn Write + Send>) -> mpsc::Sender<Vec<u8>> {
+    let (tx, rx) = mpsc::channel::<Vec<u8>>();
+    thread::spawn(move || {
+        for bytes in rx {
+            if writer.write_all(&bytes).and_then(|()| writer.flush()).is_err() {
+                break;
+            }
+        }
+    });
+    tx
+}
+
+/// A PTY opened in this process. Dropping it kills the child.
+pub(crate) struct LocalPty {
+    master: Box<dyn MasterPty + Send>,
+    input: mpsc::Sender<Vec<u8>>,
+    child: Box<dyn Child + Send + Sync>,
+    exit_code: Option<u32>,
+}
+
+impl LocalPty {
+    /// Opens a PTY and starts `builder`'s command on it; also returns the
+    /// reader for the child's output.
+    pub(crate) fn open(builder: &PtyBuilder) -> Result<(Self, Box<dyn Read + Send>)> {
+        let OpenedPty {
+            master,
+            writer,
+            reader,
+            child,
+        } = OpenedPty::open(builder)?;
+        let pty = Self {
+            master,
+            input: input_writer(writer),
+            child,
+            exit_code: None,
+        };
+        Ok((pty, reader))
+    }
+}
+
+impl Transport for LocalPty {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<()> {
+        self.input
+            .send(bytes.to_vec())
+            .map_err(|_| anyhow!("write to pty: the child stopped taking input"))
+    }
+
+    fn resize(&mut self, cols: u16, rows: u16) -> Result<()> {
+        self.master
+            .resize(PtySize {
+                rows,
+                cols,
+                pixel_width: 0,
+                pixel_height: 0,
+            })
+            .context("resize pty")
+    }
+
+    fn try_exit_code(&mut self) -> Option<u32> {
+        if self.exit_code.is_none()
+            && let Ok(Some(status)) = self.child.try_wait()
+        {
+            self.exit_code = Some(status.exit_code());
+        }
+        self.exit_code
+    }
+
+    fn kill(&mut self) {
+        if self.try_exit_code().is_none() {
+            let _ = self.child.kill();
+            if let Ok(status) = self.child.wait() {
+                self.exit_code = Some(status.exit_code());
+            }
+        }
+    }
+
+    fn foreground_pid(&self) -> Option<u32> {
+        #[cfg(unix)]
+        if let Some(pid) = self.master.process_group_leader() {
+            return Some(pid as u32);
+        }
+        self.child.process_id()
+    }
+}
+
+impl Drop for LocalPty {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
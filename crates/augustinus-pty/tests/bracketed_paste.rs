@@ -0,0 +1,25 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use augustinus_pty::PtySession;
+
+    use crate::common::wait_for;
+
+    #[test]
+    fn paste_is_wrapped_once_child_enables_mode_2004() {
+        let mut session = PtySession::spawn_command(
+            "/bin/sh",
+            &["-c", "printf '\\033[?2004hREADY\\n'; exec cat -v"],
+            80,
+            24,
+        )
+        .unwrap();
+        wait_for(&mut session, "READY");
+
+        session.send_paste("hi\n").unwrap();
+        wait_for(&mut session, "^[[200~hi");
+        wait_for(&mut session, "^[[201~");
+    }
+}
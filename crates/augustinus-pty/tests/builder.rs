@@ -0,0 +1,32 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use augustinus_pty::PtySession;
+
+    use crate::common::wait_for;
+
+    #[test]
+    fn builder_sets_cwd_env_and_term() {
+        let mut session = PtySession::builder("/bin/sh")
+            .args(["-c", "echo \"$(pwd) $GREETING $TERM $COLORTERM\"; sleep 0.2"])
+            .cwd("/")
+            .env("GREETING", "hi")
+            .term("xterm")
+            .colorterm("truecolor")
+            .size(80, 10)
+            .spawn()
+            .unwrap();
+
+        wait_for(&mut session, "/ hi xterm truecolor");
+    }
+
+    #[test]
+    fn missing_cwd_is_an_error() {
+        let result = PtySession::builder("/bin/sh")
+            .cwd("/definitely/not/here")
+            .spawn();
+        assert!(result.is_err());
+    }
+}
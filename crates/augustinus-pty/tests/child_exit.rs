@@ -0,0 +1,17 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use augustinus_pty::PtySession;
+
+    use crate::common::wait_until;
+
+    #[test]
+    fn reports_child_exit_code() {
+        let mut session = PtySession::spawn_command("/bin/sh", &["-c", "exit 3"], 80, 24).unwrap();
+
+        wait_until(&mut session, |session| !session.is_running());
+        assert_eq!(session.exit_code(), Some(3));
+    }
+}
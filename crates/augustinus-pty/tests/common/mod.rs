@@ -0,0 +1,28 @@
+//! Helpers shared by the PTY integration tests. Each test binary uses only
+//! some of them.
+#![allow(dead_code)]
+
+use std::time::{Duration, Instant};
+
+use augustinus_pty::PtySession;
+
+/// Polls `session` until `done` holds, failing after two seconds.
+pub fn wait_until(session: &mut PtySession, mut done: impl FnMut(&mut PtySession) -> bool) {
+    let deadline = Instant::now() + Duration::from_millis(2000);
+    loop {
+        session.poll();
+        if done(session) {
+            return;
+        }
+        if Instant::now() >= deadline {
+            panic!("condition not met; history:\n{}", session.history().contents());
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Polls `session` until `needle` shows up on its screen or in its
+/// scrollback.
+pub fn wait_for(session: &mut PtySession, needle: &str) {
+    wait_until(session, |session| session.history().contents().contains(needle));
+}
@@ -0,0 +1,99 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, Instant};
+
+    use augustinus_pty::{daemon_sessions, PtyServer, PtySession};
+
+    use crate::common::{wait_for, wait_until};
+
+    fn start_daemon(name: &str) -> PathBuf {
+        let socket = std::env::temp_dir()
+            .join(format!("augustinus-daemon-{}-{name}", std::process::id()))
+            .join("pty.sock");
+        let server = PtyServer::bind(&socket).unwrap();
+        std::thread::spawn(move || server.run());
+        socket
+    }
+
+    fn attach(socket: &Path, key: &str) -> PtySession {
+        PtySession::builder("/bin/sh")
+            .env("PS1", "$ ")
+            .size(40, 10)
+            .attach(socket, key)
+            .unwrap()
+    }
+
+    #[test]
+    fn shell_survives_detach_and_replays_its_screen() {
+        let socket = start_daemon("replay");
+
+        let mut first = attach(&socket, "general-0");
+        assert_eq!(first.daemon_key(), Some("general-0"));
+        first.send_bytes(b"seq 1 30; FOO=kept\r").unwrap();
+        wait_for(&mut first, "\n30\n$");
+        let before = first.history();
+        drop(first);
+
+        assert_eq!(daemon_sessions(&socket).unwrap(), vec!["general-0".to_string()]);
+
+        let mut second = attach(&socket, "general-0");
+        wait_for(&mut second, "\n30\n$");
+        assert!(second.scrollback_len() > 0, "scrollback was not replayed");
+        let after = second.history();
+        for row in 0..before.rows() {
+            assert_eq!(after.row_text(row), before.row_text(row), "row {row}");
+        }
+        second.send_bytes(b"echo \"<$FOO>\"\r").unwrap();
+        wait_for(&mut second, "<kept>");
+        assert!(second.is_running());
+    }
+
+    #[test]
+    fn killing_ends_the_daemon_session() {
+        let socket = start_daemon("kill");
+
+        let mut session = attach(&socket, "agent-codex");
+        session.kill();
+        assert!(!session.is_running());
+
+        let deadline = Instant::now() + Duration::from_millis(1000);
+        while !daemon_sessions(&socket).unwrap().is_empty() {
+            if Instant::now() >= deadline {
+                panic!("killed session still listed");
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn socket_directory_must_be_private() {
+        let dir = std::env::temp_dir()
+            .join(format!("augustinus-daemon-{}-open", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let error = PtyServer::bind(dir.join("pty.sock"))
+            .err()
+            .expect("bound in an open directory");
+        assert!(format!("{error:#}").contains("open to other users"), "{error:#}");
+        assert!(!dir.join("pty.sock").exists());
+    }
+
+    #[test]
+    fn child_exit_reaches_the_client() {
+        let socket = start_daemon("exit");
+
+        let mut session = PtySession::builder("/bin/sh")
+            .args(["-c", "exit 4"])
+            .attach(&socket, "general-0")
+            .unwrap();
+
+        wait_until(&mut session, |session| !session.is_running());
+        assert_eq!(session.exit_code(), Some(4));
+    }
+}
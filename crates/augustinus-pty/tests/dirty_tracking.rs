@@ -0,0 +1,54 @@
+#[cfg(unix)]
+mod unix {
+    use std::time::{Duration, Instant};
+
+    use augustinus_pty::PtySession;
+    use augustinus_term::TerminalScreen;
+
+    #[test]
+    fn snapshot_updates_only_after_changes() {
+        let mut session =
+            PtySession::spawn_command("/bin/sh", &["-c", "echo ready; sleep 2"], 80, 24).unwrap();
+        let mut screen = TerminalScreen::default();
+        assert!(session.update_snapshot(&mut screen), "first update sizes the screen");
+
+        let deadline = Instant::now() + Duration::from_millis(1000);
+        while !screen.contents().contains("ready") {
+            assert!(Instant::now() < deadline, "no output; got:\n{}", screen.contents());
+            std::thread::sleep(Duration::from_millis(10));
+            if session.poll() {
+                assert!(session.update_snapshot(&mut screen));
+            }
+        }
+        session.poll();
+        session.update_snapshot(&mut screen);
+        assert!(!session.update_snapshot(&mut screen));
+        assert_eq!(screen, session.snapshot());
+
+        session.resize(40, 10).unwrap();
+        assert!(session.update_snapshot(&mut screen));
+        assert_eq!((screen.cols(), screen.rows()), (40, 10));
+    }
+
+    #[test]
+    fn output_flood_is_drained_in_bounded_steps() {
+        let mut session = PtySession::spawn_command(
+            "/bin/sh",
+            &["-c", "head -c 4000000 /dev/zero | tr '\\0' x; echo; echo done"],
+            80,
+            24,
+        )
+        .unwrap();
+        let deadline = Instant::now() + Duration::from_secs(20);
+        loop {
+            let started = Instant::now();
+            session.poll();
+            assert!(started.elapsed() < Duration::from_secs(2), "poll stalled");
+            if session.snapshot().contents().contains("done") {
+                return;
+            }
+            assert!(Instant::now() < deadline, "flood never finished");
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+}
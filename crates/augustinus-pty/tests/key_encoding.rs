@@ -0,0 +1,52 @@
+use augustinus_pty::key_to_bytes;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+fn enc(code: KeyCode, mods: KeyModifiers) -> Vec<u8> {
+    key_to_bytes(KeyEvent::new(code, mods), false).unwrap()
+}
+
+#[test]
+fn arrows_follow_application_cursor_mode() {
+    let up = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+    assert_eq!(key_to_bytes(up, false).unwrap(), b"\x1b[A");
+    assert_eq!(key_to_bytes(up, true).unwrap(), b"\x1bOA");
+
+    let home = KeyEvent::new(KeyCode::Home, KeyModifiers::NONE);
+    assert_eq!(key_to_bytes(home, true).unwrap(), b"\x1bOH");
+}
+
+#[test]
+fn modified_arrows_use_csi_parameters() {
+    assert_eq!(enc(KeyCode::Right, KeyModifiers::CONTROL), b"\x1b[1;5C");
+    assert_eq!(enc(KeyCode::Left, KeyModifiers::ALT), b"\x1b[1;3D");
+    assert_eq!(
+        enc(KeyCode::Up, KeyModifiers::SHIFT | KeyModifiers::CONTROL),
+        b"\x1b[1;6A"
+    );
+}
+
+#[test]
+fn editing_and_function_keys() {
+    assert_eq!(enc(KeyCode::Delete, KeyModifiers::NONE), b"\x1b[3~");
+    assert_eq!(enc(KeyCode::Insert, KeyModifiers::NONE), b"\x1b[2~");
+    assert_eq!(enc(KeyCode::PageUp, KeyModifiers::NONE), b"\x1b[5~");
+    assert_eq!(enc(KeyCode::PageDown, KeyModifiers::CONTROL), b"\x1b[6;5~");
+    assert_eq!(enc(KeyCode::End, KeyModifiers::NONE), b"\x1b[F");
+    assert_eq!(enc(KeyCode::F(1), KeyModifiers::NONE), b"\x1bOP");
+    assert_eq!(enc(KeyCode::F(4), KeyModifiers::SHIFT), b"\x1b[1;2S");
+    assert_eq!(enc(KeyCode::F(5), KeyModifiers::NONE), b"\x1b[15~");
+    assert_eq!(enc(KeyCode::F(12), KeyModifiers::NONE), b"\x1b[24~");
+    assert_eq!(enc(KeyCode::BackTab, KeyModifiers::SHIFT), b"\x1b[Z");
+}
+
+#[test]
+fn alt_prefixes_escape_and_ctrl_maps_to_c0() {
+    assert_eq!(enc(KeyCode::Char('b'), KeyModifiers::ALT), b"\x1bb");
+    assert_eq!(enc(KeyCode::Backspace, KeyModifiers::ALT), b"\x1b\x7f");
+    assert_eq!(enc(KeyCode::Char('c'), KeyModifiers::CONTROL), [0x03]);
+    assert_eq!(enc(KeyCode::Char(' '), KeyModifiers::CONTROL), [0x00]);
+    assert_eq!(
+        enc(KeyCode::Char('w'), KeyModifiers::CONTROL | KeyModifiers::ALT),
+        b"\x1b\x17"
+    );
+}
@@ -0,0 +1,25 @@
+#[cfg(unix)]
+mod unix {
+    use std::time::{Duration, Instant};
+
+    use augustinus_pty::PtySession;
+
+    #[test]
+    fn large_paste_into_an_echoing_child_keeps_output_flowing() {
+        let mut session = PtySession::builder("/bin/cat").size(80, 24).spawn().unwrap();
+        // About 1 MiB, in lines so the tty's line buffer never fills up.
+        let mut text = format!("{}\n", "x".repeat(1023)).repeat(1024);
+        text.push_str("end-of-paste\n");
+
+        let started = Instant::now();
+        session.send_paste(&text).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(1), "paste blocked the caller");
+
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while !session.snapshot().contents().contains("end-of-paste") {
+            assert!(Instant::now() < deadline, "paste never came back from cat");
+            while session.poll() {}
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+}
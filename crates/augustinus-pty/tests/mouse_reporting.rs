@@ -0,0 +1,45 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use augustinus_pty::PtySession;
+    use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+    use crate::common::wait_for;
+
+    fn click(column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn mouse_is_ignored_until_child_enables_reporting() {
+        let mut session =
+            PtySession::spawn_command("/bin/sh", &["-c", "echo READY; exec cat -v"], 80, 24)
+                .unwrap();
+        wait_for(&mut session, "READY");
+        assert!(!session.wants_mouse());
+        assert!(!session.send_mouse(click(0, 0)).unwrap());
+    }
+
+    #[test]
+    fn sgr_reports_use_one_based_coordinates() {
+        let mut session = PtySession::spawn_command(
+            "/bin/sh",
+            &["-c", "printf '\\033[?1000h\\033[?1006hREADY\\n'; exec cat -v"],
+            80,
+            24,
+        )
+        .unwrap();
+        wait_for(&mut session, "READY");
+        assert!(session.wants_mouse());
+
+        assert!(session.send_mouse(click(4, 2)).unwrap());
+        wait_for(&mut session, "^[[<0;5;3M");
+    }
+}
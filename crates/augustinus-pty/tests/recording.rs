@@ -0,0 +1,46 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use augustinus_pty::{CastRecorder, PtySession};
+
+    use crate::common::wait_for;
+
+    #[test]
+    fn records_output_and_resizes_as_asciicast() {
+        let dir = std::env::temp_dir().join(format!("augustinus-cast-{}", std::process::id()));
+        let path = dir.join("nested").join("session.cast");
+
+        let mut session = PtySession::builder("/bin/sh")
+            .args(["-c", r#"printf 'caf\303\251 "q"\n'; sleep 0.2"#])
+            .term("xterm")
+            .env("SHELL", "/bin/zsh")
+            .size(80, 24)
+            .spawn()
+            .unwrap();
+        let recorder =
+            CastRecorder::create(&path, 80, 24, session.term(), session.shell()).unwrap();
+        session.start_recording(recorder);
+        assert!(session.is_recording());
+
+        wait_for(&mut session, "café");
+        session.resize(100, 30).unwrap();
+        let recorder = session.stop_recording().unwrap();
+        assert_eq!(recorder.path(), path);
+        assert!(!session.is_recording());
+
+        let cast = std::fs::read_to_string(&path).unwrap();
+        let mut lines = cast.lines();
+        let header = lines.next().unwrap();
+        assert!(header.starts_with(r#"{"version":2,"width":80,"height":24,"timestamp":"#));
+        assert!(header.ends_with(r#""env":{"TERM":"xterm","SHELL":"/bin/zsh"}}"#));
+        let events: Vec<&str> = lines.collect();
+        assert!(events
+            .iter()
+            .any(|e| e.contains(r#""o", "caf"#) && e.contains(r#"\"q\"\r\n"#)));
+        assert!(events.last().unwrap().ends_with(r#""r", "100x30"]"#));
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use augustinus_pty::PtySession;
+
+    use crate::common::wait_for;
+
+    #[test]
+    fn scrollback_reaches_lines_above_the_screen() {
+        let mut session =
+            PtySession::spawn_command("/usr/bin/seq", &["1", "100"], 40, 10).unwrap();
+
+        wait_for(&mut session, "100");
+
+        let len = session.scrollback_len();
+        assert!(len >= 90, "scrollback too short: {len}");
+
+        session.set_scrollback(len);
+        assert_eq!(session.scrollback(), len);
+        let top = session.snapshot();
+        assert_eq!(top.row_text(0), "1");
+        assert!(top.hide_cursor);
+
+        session.set_scrollback(0);
+        assert!(session.snapshot().contents().contains("100"));
+    }
+
+    #[test]
+    fn history_joins_scrollback_and_screen_in_order() {
+        let mut session =
+            PtySession::spawn_command("/usr/bin/seq", &["1", "35"], 40, 10).unwrap();
+
+        wait_for(&mut session, "35");
+        session.set_scrollback(3);
+
+        let history = session.history();
+        let lines: Vec<String> = (0..history.rows()).map(|row| history.row_text(row)).collect();
+        let expected: Vec<String> = (1..=35).map(|n| n.to_string()).collect();
+        assert_eq!(&lines[..35], &expected[..]);
+        assert_eq!((history.cursor_row, history.cursor_col), (35, 0));
+        assert_eq!(session.scrollback(), 3);
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(unix)]
+mod unix {
+    use std::time::{Duration, Instant};
+
+    use augustinus_pty::PtySession;
+    use augustinus_term::{ShellMark, ShellMarkKind};
+
+    /// Polls until `count` marks arrived.
+    fn collect_marks(session: &mut PtySession, count: usize) -> Vec<ShellMark> {
+        let deadline = Instant::now() + Duration::from_millis(3000);
+        let mut marks = Vec::new();
+        while marks.len() < count {
+            session.poll();
+            marks.extend(session.take_shell_marks());
+            if Instant::now() >= deadline {
+                panic!("got {marks:?}; snapshot:\n{}", session.snapshot().contents());
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        marks
+    }
+
+    #[test]
+    fn osc_133_marks_carry_command_and_exit_code() {
+        let mut session = PtySession::spawn_command(
+            "/bin/sh",
+            &[
+                "-c",
+                r"printf '\033]133;A\007$ \033]133;B\007make test\r\n\033]133;C\007'
+                  echo built
+                  printf '\033]133;D;2\007'; sleep 0.2",
+            ],
+            80,
+            24,
+        )
+        .unwrap();
+        let marks = collect_marks(&mut session, 4);
+        let kinds: Vec<&ShellMarkKind> = marks.iter().map(|mark| &mark.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                &ShellMarkKind::PromptStart,
+                &ShellMarkKind::CommandStart,
+                &ShellMarkKind::OutputStart {
+                    command: "make test".to_string()
+                },
+                &ShellMarkKind::CommandFinished { exit_code: Some(2) },
+            ]
+        );
+        assert_eq!((marks[0].line, marks[0].col), (0, 0));
+        assert_eq!((marks[1].line, marks[1].col), (0, 2));
+        assert_eq!((marks[2].line, marks[2].col), (1, 0));
+        assert_eq!((marks[3].line, marks[3].col), (2, 0));
+    }
+
+    #[test]
+    fn mark_lines_survive_scrollback_trimming() {
+        let mut session = PtySession::spawn_command(
+            "/bin/sh",
+            &[
+                "-c",
+                r"printf '\033]133;A\007$ \033]133;B\007seq\r\n\033]133;C\007'
+                  seq 1 2500
+                  printf '\033]133;D;0\007\033]133;A\007$ '; sleep 0.2",
+            ],
+            80,
+            24,
+        )
+        .unwrap();
+        let marks = collect_marks(&mut session, 5);
+        let origin = session.history_origin();
+        assert!(origin > 0, "scrollback should have been trimmed");
+        assert!(marks[0].line < origin);
+
+        let history = session.history();
+        let prompt = (marks[4].line - origin) as u16;
+        assert_eq!(history.row_text(prompt), "$");
+        assert_eq!(history.row_text(prompt - 1), "2500");
+        assert_eq!(marks[3].line, marks[4].line);
+    }
+}
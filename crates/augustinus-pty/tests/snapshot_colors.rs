@@ -0,0 +1,33 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use augustinus_pty::PtySession;
+    use augustinus_term::TermColor;
+
+    use crate::common::wait_for;
+
+    #[test]
+    fn snapshot_carries_sgr_colors_and_attributes() {
+        let mut session = PtySession::spawn_command(
+            "/usr/bin/printf",
+            &["\\033[1;31mRED\\033[0m \\033[38;2;1;2;3;7mRGB\\033[0m"],
+            80,
+            24,
+        )
+        .unwrap();
+
+        wait_for(&mut session, "RGB");
+        let screen = session.snapshot();
+        let red = screen.cell(0, 0).unwrap();
+        assert_eq!(red.symbol, "R");
+        assert_eq!(red.fg, TermColor::Indexed(1));
+        assert!(red.bold);
+
+        let rgb = screen.cell(0, 4).unwrap();
+        assert_eq!(rgb.fg, TermColor::Rgb(1, 2, 3));
+        assert!(rgb.inverse);
+        assert!(!rgb.bold);
+    }
+}
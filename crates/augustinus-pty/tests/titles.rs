@@ -0,0 +1,47 @@
+#[cfg(unix)]
+mod common;
+
+#[cfg(unix)]
+mod unix {
+    use augustinus_pty::PtySession;
+
+    use crate::common::wait_until;
+
+    #[test]
+    fn osc_title_is_reported() {
+        let mut session = PtySession::spawn_command(
+            "/bin/sh",
+            &["-c", r"printf '\033]2;build: ok\007'; sleep 0.2"],
+            80,
+            24,
+        )
+        .unwrap();
+        wait_until(&mut session, |s| s.title().is_some());
+        assert_eq!(session.title(), Some("build: ok"));
+    }
+
+    #[test]
+    fn bell_is_reported_once() {
+        let mut session = PtySession::spawn_command(
+            "/bin/sh",
+            &["-c", r"printf '\007'; sleep 0.2"],
+            80,
+            24,
+        )
+        .unwrap();
+        let mut rang = false;
+        wait_until(&mut session, |s| {
+            rang |= s.take_bell();
+            rang
+        });
+        assert!(!session.take_bell());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn foreground_process_name_comes_from_proc() {
+        let mut session =
+            PtySession::spawn_command("/bin/sh", &["-c", "exec sleep 2"], 80, 24).unwrap();
+        wait_until(&mut session, |s| s.foreground_process().as_deref() == Some("sleep"));
+    }
+}
@@ -0,0 +1,193 @@
+use anyhow::Context;
+use augustinus_app::{Action, AgentPicker, AppState, TerminalScreen};
+use augustinus_i18n::{fill, Strings};
+use augustinus_pty::PtySession;
+use augustinus_store::config::{AgentConfig, AppConfig, PaneConfig, KNOWN_AGENT_CLIS};
+use regex::{Regex, RegexBuilder};
+
+use crate::launcher::PtyFactory;
+use crate::runtime::pane_builder;
+
+/// Daemon session keys of agents: the prefix followed by the agent's name.
+const AGENT_KEY: &str = "agent-";
+
+/// Picker entry that opens the user's shell instead of an agent.
+const SHELL_CHOICE: &str = "shell";
+
+/// Starts the configured agents, one AI AGENTS tab each, when any of them is
+/// installed; one that is missing or fails to start gets an exited tab with
+/// the reason. Otherwise agents picked in an earlier run are reattached if
+/// the daemon still holds them (`restored`). Failing both, the pane shows a
+/// picker of agent CLIs found on `PATH`. Invalid waiting patterns are shown
+/// in the command overlay.
+pub(crate) fn start_agents(
+    config: &AppConfig,
+    ptys: &dyn PtyFactory,
+    restored: &[String],
+    state: &mut AppState,
+    sessions: &mut Vec<(u32, PtySession)>,
+    specs: &mut Vec<AgentSpec>,
+    (cols, rows): (u16, u16),
+) {
+    check_waiting_patterns(config, state);
+    let mut agents = config.agent_configs();
+    let installed = |agent: &AgentConfig| agent.command.first().is_some_and(|p| is_on_path(p));
+    if !agents.iter().any(installed) {
+        agents = restored
+            .iter()
+            .filter_map(|key| key.strip_prefix(AGENT_KEY))
+            .map(|name| picked_agent(config, name.to_string()))
+            .collect();
+    }
+    for agent in agents {
+        let id = if specs.is_empty() {
+            state.agents().id()
+        } else {
+            state.agent_tabs.open()
+        };
+        let started = start_agent(config, ptys, &agent, state.strings(), cols, rows);
+        let tab = state.agents_mut();
+        tab.name = Some(agent.name.clone());
+        match started {
+            Ok(pty) => sessions.push((id, pty)),
+            Err(reason) => tab.process.on_start_failed(reason),
+        }
+        specs.push(AgentSpec::new(id, agent));
+    }
+    state.apply(Action::AgentSelect(0));
+    if specs.is_empty() {
+        let mut choices: Vec<String> = KNOWN_AGENT_CLIS
+            .iter()
+            .filter(|program| is_on_path(program))
+            .map(|program| program.to_string())
+            .collect();
+        choices.push(SHELL_CHOICE.to_string());
+        state.agent_picker = Some(AgentPicker::new(choices));
+    }
+}
+
+/// An agent tab's `[[agents]]` entry, kept for respawns, with its waiting
+/// patterns compiled. Invalid patterns, reported by `start_agents`, are left
+/// out.
+pub(crate) struct AgentSpec {
+    pub(crate) id: u32,
+    pub(crate) config: AgentConfig,
+    waiting: Vec<Regex>,
+}
+
+impl AgentSpec {
+    pub(crate) fn new(id: u32, config: AgentConfig) -> Self {
+        let waiting = config
+            .waiting_patterns
+            .iter()
+            .filter_map(|pattern| waiting_pattern(pattern).ok())
+            .collect();
+        Self {
+            id,
+            config,
+            waiting,
+        }
+    }
+
+    pub(crate) fn is_waiting(&self, screen: &TerminalScreen) -> bool {
+        if self.waiting.is_empty() {
+            return false;
+        }
+        let text = screen.contents();
+        self.waiting.iter().any(|pattern| pattern.is_match(&text))
+    }
+}
+
+fn waiting_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).multi_line(true).build()
+}
+
+fn check_waiting_patterns(config: &AppConfig, state: &mut AppState) {
+    for agent in config.agent_configs() {
+        for pattern in &agent.waiting_patterns {
+            if waiting_pattern(pattern).is_err() {
+                let message = fill(
+                    state.strings().error_waiting_pattern,
+                    &[&agent.name, &format!("{pattern:?}")],
+                );
+                state.command_failed(message);
+            }
+        }
+    }
+}
+
+pub(crate) fn picked_agent(config: &AppConfig, choice: String) -> AgentConfig {
+    let command = if choice == SHELL_CHOICE {
+        vec![config.shell.clone()]
+    } else {
+        vec![choice.clone()]
+    };
+    AgentConfig {
+        name: choice,
+        command,
+        cwd: None,
+        waiting_patterns: Vec::new(),
+    }
+}
+
+/// Spawns `agent`, or says why it cannot run, in the UI language.
+pub(crate) fn start_agent(
+    config: &AppConfig,
+    ptys: &dyn PtyFactory,
+    agent: &AgentConfig,
+    strings: &Strings,
+    cols: u16,
+    rows: u16,
+) -> Result<PtySession, String> {
+    if let Some(program) = agent.command.first()
+        && !is_on_path(program)
+    {
+        return Err(fill(strings.agent_not_found, &[program]));
+    }
+    spawn_agent(config, ptys, agent, cols, rows).map_err(|error| format!("{error:#}"))
+}
+
+pub(crate) fn spawn_agent(
+    config: &AppConfig,
+    ptys: &dyn PtyFactory,
+    agent: &AgentConfig,
+    cols: u16,
+    rows: u16,
+) -> anyhow::Result<PtySession> {
+    let Some((program, args)) = agent.command.split_first() else {
+        anyhow::bail!("agent {} has no command", agent.name);
+    };
+    // `[agent_pane].args` belong to the `agents_cmd` shorthand, which the agent
+    // list already folds into `command`.
+    let mut pane = PaneConfig {
+        args: Vec::new(),
+        ..config.agent_pane.clone()
+    };
+    if agent.cwd.is_some() {
+        pane.cwd = agent.cwd.clone();
+    }
+    let builder = pane_builder(config, &pane, program, args, cols, rows);
+    ptys.spawn(builder, &format!("{AGENT_KEY}{}", agent.name))
+        .with_context(|| format!("spawn agent {}", agent.name))
+}
+
+fn is_on_path(program: &str) -> bool {
+    let is_executable = |path: &std::path::Path| {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            path.metadata()
+                .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+        }
+        #[cfg(not(unix))]
+        {
+            path.is_file()
+        }
+    };
+    if program.contains('/') {
+        return is_executable(std::path::Path::new(program));
+    }
+    std::env::var_os("PATH").is_some_and(|paths| {
+        std::env::split_paths(&paths).any(|dir| is_executable(&dir.join(program)))
+    })
+}
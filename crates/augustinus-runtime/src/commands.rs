@@ -0,0 +1,52 @@
+use std::{io, time::Instant};
+
+use augustinus_app::{AppState, Command};
+use augustinus_i18n::fill;
+use augustinus_store::{config::AppConfig, db::Store};
+
+use crate::runtime::anyhow_to_io;
+
+/// Carries out the commands the reducer leaves to the runtime; `now` stamps
+/// focus sessions. Returns `true` on `:quit`.
+pub(crate) fn handle_command(
+    command: Command,
+    state: &mut AppState,
+    config: &mut AppConfig,
+    store: &Store,
+    now: Instant,
+) -> io::Result<bool> {
+    match command {
+        Command::Quit => return Ok(true),
+        Command::FocusStart => {
+            store.insert_event("focus_start", "{}").map_err(anyhow_to_io)?;
+            if state.focus.start(now) {
+                state.motivation.on_focus_start();
+            }
+        }
+        Command::FocusStop => {
+            if let Some(elapsed) = state.focus.stop(now) {
+                let secs = elapsed.as_secs().min(i64::MAX as u64) as i64;
+                store
+                    .insert_event("focus_stop", &format!(r#"{{"seconds":{secs}}}"#))
+                    .map_err(anyhow_to_io)?;
+                store.add_focus_seconds_today(secs).map_err(anyhow_to_io)?;
+                state.focus.add_focus_seconds_today(secs.max(0) as u64);
+                let streak = store.streak_days_ending_today().map_err(anyhow_to_io)?;
+                state.focus.set_streak_days(streak);
+                state.motivation.on_focus_stop();
+            } else {
+                state.command_failed(state.strings().error_no_focus_session.to_string());
+            }
+        }
+        // The reducer already switched the UI; only saving is left.
+        Command::Lang(language) => {
+            config.language = language;
+            if let Err(err) = config.save() {
+                let message = fill(state.strings().error_lang_save, &[&format!("{err:#}")]);
+                state.command_failed(message);
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
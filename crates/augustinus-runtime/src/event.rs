@@ -0,0 +1,61 @@
+use std::{cell::RefCell, collections::VecDeque, io, rc::Rc, time::Duration};
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent};
+
+/// Where the runtime gets keys, pastes, mouse and resize events from.
+pub trait EventSource {
+    /// Waits up to `timeout` for the next event; `None` if there was none.
+    fn next(&mut self, timeout: Duration) -> io::Result<Option<Event>>;
+}
+
+/// Events from the host terminal through crossterm.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TerminalEvents;
+
+impl EventSource for TerminalEvents {
+    fn next(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        if event::poll(timeout)? {
+            event::read().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// A queue of events handed out one per call without waiting. Clones share
+/// the queue, so a test can keep one and feed the runtime's.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedEvents {
+    queue: Rc<RefCell<VecDeque<Event>>>,
+}
+
+impl ScriptedEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, event: Event) {
+        self.queue.borrow_mut().push_back(event);
+    }
+
+    pub fn push_key(&self, key: impl Into<KeyEvent>) {
+        self.push(Event::Key(key.into()));
+    }
+
+    /// Queues each character of `text` as a key press.
+    pub fn push_str(&self, text: &str) {
+        for ch in text.chars() {
+            self.push_key(KeyCode::Char(ch));
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.borrow().is_empty()
+    }
+}
+
+impl EventSource for ScriptedEvents {
+    fn next(&mut self, _timeout: Duration) -> io::Result<Option<Event>> {
+        Ok(self.queue.borrow_mut().pop_front())
+    }
+}
@@ -0,0 +1,543 @@
+use std::io::Write;
+
+use augustinus_app::{
+    Action, AgentsInputMode, AppState, Binding, CopyMotion, GeneralInputMode, Key, KeyAction,
+    KeyChord, KeyMatch, KeyMode, PaneId, SelectionKind, SendSource,
+};
+use augustinus_pty::PtySession;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+
+const MOUSE_WHEEL_LINES: usize = 3;
+
+fn agent_picker_action(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Up => Some(Action::AgentPickerUp),
+        KeyCode::Down => Some(Action::AgentPickerDown),
+        KeyCode::Enter => Some(Action::AgentPickerConfirm(None)),
+        KeyCode::Char(ch @ '1'..='9') => {
+            Some(Action::AgentPickerConfirm(Some(ch as usize - '1' as usize)))
+        }
+        _ => None,
+    }
+}
+
+/// Handles a key press; returns `true` when the app should quit. Yanked
+/// text also goes to the host clipboard through `host`. Keys go through
+/// the keymap of the current mode, except inside the search prompt, copy
+/// mode, the agent picker and active scroll or search, which keep their own
+/// keys while no sequence is pending.
+pub(crate) fn handle_key(
+    key: KeyEvent,
+    state: &mut AppState,
+    mut pty: Option<&mut PtySession>,
+    mut agents_pty: Option<&mut PtySession>,
+    host: &mut dyn Write,
+) -> bool {
+    let chord = key_chord(key);
+
+    if state.command.is_some() {
+        let Some(chord) = chord else {
+            return false;
+        };
+        match state.keymap.feed(KeyMode::Command, chord) {
+            KeyMatch::Bound(Binding::Action(action)) => command_key_action(action, state),
+            KeyMatch::Bound(Binding::Command(_)) | KeyMatch::Pending => {}
+            KeyMatch::Unbound(chords) => {
+                for chord in chords {
+                    if let Key::Char(ch) = chord.key
+                        && is_printable(ch)
+                        && !chord.ctrl
+                        && !chord.alt
+                    {
+                        state.apply(Action::CommandAppend(ch));
+                    }
+                }
+            }
+        }
+        return false;
+    }
+
+    if state
+        .terminal(state.focused)
+        .is_some_and(|terminal| terminal.search.is_prompting())
+    {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => state.apply(Action::SearchCancel),
+            KeyCode::Enter => state.apply(Action::SearchSubmit),
+            KeyCode::Backspace => state.apply(Action::SearchBackspace),
+            KeyCode::Char('r') if ctrl => state.apply(Action::SearchToggleRegex),
+            KeyCode::Char(ch) if is_printable(ch) && !ctrl => {
+                state.apply(Action::SearchAppend(ch));
+            }
+            _ => {}
+        }
+        return false;
+    }
+
+    if let Some(copy) = state.terminal(state.focused).and_then(|terminal| terminal.copy) {
+        if let Some(action) = copy_mode_action(key, copy.selection_kind().is_some()) {
+            state.apply(action);
+        } else if matches!(key.code, KeyCode::Char('y') | KeyCode::Enter) {
+            let session = match state.focused {
+                PaneId::General => pty.as_deref_mut(),
+                _ => agents_pty.as_deref_mut(),
+            };
+            if let Some(session) = session {
+                let text = copy.selected_text(&session.history());
+                if !text.is_empty() {
+                    copy_to_host_clipboard(host, &text);
+                    state.register = Some(text);
+                }
+            }
+            state.apply(Action::CopyExit);
+        }
+        return false;
+    }
+
+    if state.focused == PaneId::Agents
+        && state.agent_picker.is_some()
+        && let Some(action) = agent_picker_action(key)
+    {
+        state.apply(action);
+        return false;
+    }
+
+    let pending = !state.keymap.pending().is_empty();
+    let locked = match state.focused {
+        PaneId::General => state.general_input_mode == GeneralInputMode::TerminalLocked,
+        PaneId::Agents => state.agents_input_mode == AgentsInputMode::CodexLocked,
+        _ => false,
+    };
+    if locked {
+        let (scrolling, exited) = state.terminal(state.focused).map_or((false, false), |terminal| {
+            (terminal.scroll.is_active(), terminal.process.exit_code().is_some())
+        });
+        if key.code == KeyCode::Esc && scrolling && !pending {
+            state.apply(Action::ExitScroll);
+            return false;
+        }
+        let session = match state.focused {
+            PaneId::General => pty,
+            _ => agents_pty,
+        };
+        let unbound = match chord.map(|chord| state.keymap.feed(KeyMode::Terminal, chord)) {
+            Some(KeyMatch::Bound(binding)) => return run_binding(binding, state, session),
+            Some(KeyMatch::Pending) => return false,
+            Some(KeyMatch::Unbound(chords)) => chords.into_iter().map(key_event).collect(),
+            None => vec![key],
+        };
+        if exited {
+            if unbound == [KeyEvent::from(KeyCode::Char('r'))] {
+                state.apply(Action::Respawn(state.focused));
+            }
+            return false;
+        }
+        state.apply(Action::ExitScroll);
+        if let Some(session) = session {
+            for key in unbound {
+                let _ = session.send_key(key);
+            }
+        }
+        return false;
+    }
+
+    if !pending {
+        let searching = state
+            .terminal(state.focused)
+            .map(|terminal| terminal.search.is_active());
+        if let Some(searching) = searching
+            && let Some(action) = pane_search_action(key, searching)
+        {
+            state.apply(action);
+            return false;
+        }
+
+        let scrolling = state
+            .focused_scroll_mut()
+            .is_some_and(|scroll| scroll.is_active());
+        if scrolling
+            && let Some(action) = pane_scroll_action(key)
+            && matches!(state.focused, PaneId::General | PaneId::Agents)
+        {
+            state.apply(action);
+            return false;
+        }
+    }
+
+    let Some(chord) = chord else {
+        return false;
+    };
+    let session = match state.focused {
+        PaneId::General => pty,
+        _ => agents_pty,
+    };
+    match state.keymap.feed(KeyMode::App, chord) {
+        KeyMatch::Bound(binding) => run_binding(binding, state, session),
+        KeyMatch::Pending | KeyMatch::Unbound(_) => false,
+    }
+}
+
+/// Carries out a binding of the app or terminal keymap; returns `true` on
+/// `quit`. `session` is the focused pane's PTY, for `paste`.
+fn run_binding(binding: Binding, state: &mut AppState, session: Option<&mut PtySession>) -> bool {
+    let action = match binding {
+        Binding::Command(line) => {
+            state.run_command(&line);
+            return false;
+        }
+        Binding::Action(action) => action,
+    };
+    let terminal = state.terminal(state.focused);
+    let is_terminal = terminal.is_some();
+    let exited = terminal.is_some_and(|terminal| terminal.process.exit_code().is_some());
+    let action = match action {
+        KeyAction::Quit => return true,
+        KeyAction::FocusLeft => Action::FocusLeft,
+        KeyAction::FocusDown => Action::FocusDown,
+        KeyAction::FocusUp => Action::FocusUp,
+        KeyAction::FocusRight => Action::FocusRight,
+        KeyAction::RotateFocus => Action::RotateFocus,
+        KeyAction::Activate => match state.focused {
+            PaneId::Agents if state.agents_input_mode == AgentsInputMode::PaneControls => {
+                Action::EnterAgentsTerminalMode
+            }
+            PaneId::General if state.general_input_mode == GeneralInputMode::AppControls => {
+                Action::EnterGeneralTerminalMode
+            }
+            _ => Action::EnterFullscreen,
+        },
+        KeyAction::Fullscreen => Action::EnterFullscreen,
+        KeyAction::ExitFullscreen => Action::ExitFullscreen,
+        KeyAction::GrowWidth => Action::GrowPaneWidth,
+        KeyAction::ShrinkWidth => Action::ShrinkPaneWidth,
+        KeyAction::GrowHeight => Action::GrowPaneHeight,
+        KeyAction::ShrinkHeight => Action::ShrinkPaneHeight,
+        KeyAction::ResetSplits => Action::ResetSplits,
+        KeyAction::ExitTerminal => match state.focused {
+            PaneId::General => Action::ExitGeneralTerminalMode,
+            _ => Action::ExitAgentsTerminalMode,
+        },
+        KeyAction::CommandMode => Action::EnterCommandMode,
+        KeyAction::TabNew => Action::TabNew,
+        KeyAction::TabClose => Action::TabClose,
+        KeyAction::TabNext => Action::TabNext,
+        KeyAction::TabPrev => Action::TabPrev,
+        KeyAction::Select(index) if state.focused == PaneId::General => Action::TabSelect(index),
+        KeyAction::Select(index) if state.focused == PaneId::Agents => Action::AgentSelect(index),
+        KeyAction::SendOutput if state.focused == PaneId::General => {
+            Action::SendOutput(SendSource::LastCommand)
+        }
+        KeyAction::Respawn if exited => Action::Respawn(state.focused),
+        KeyAction::Paste => {
+            if let (false, Some(text), Some(session)) = (exited, &state.register, session)
+                && is_terminal
+            {
+                let _ = session.send_paste(text);
+            }
+            return false;
+        }
+        _ if !is_terminal => return false,
+        KeyAction::CopyMode => Action::CopyEnter,
+        KeyAction::PromptPrev => Action::PromptPrev,
+        KeyAction::PromptNext => Action::PromptNext,
+        KeyAction::SelectOutput => Action::SelectLastOutput,
+        KeyAction::Search => Action::SearchOpen,
+        KeyAction::ScrollPageUp => Action::ScrollPageUp,
+        KeyAction::ScrollPageDown => Action::ScrollPageDown,
+        KeyAction::ScrollLineUp => Action::ScrollLineUp,
+        KeyAction::ScrollLineDown => Action::ScrollLineDown,
+        KeyAction::ScrollTop => Action::ScrollTop,
+        KeyAction::ScrollBottom => Action::ScrollBottom,
+        _ => return false,
+    };
+    state.apply(action);
+    false
+}
+
+/// Carries out a binding of the `:` prompt.
+fn command_key_action(action: KeyAction, state: &mut AppState) {
+    let action = match action {
+        KeyAction::Cancel if state.command_line.completion().is_some() => {
+            Action::CommandCompleteCancel
+        }
+        KeyAction::Cancel => Action::ExitCommandMode,
+        KeyAction::Submit => Action::SubmitCommand,
+        KeyAction::Backspace => Action::CommandBackspace,
+        KeyAction::DeleteWord => Action::CommandDeleteWord,
+        KeyAction::CursorLeft => Action::CommandCursorLeft,
+        KeyAction::CursorRight => Action::CommandCursorRight,
+        KeyAction::CursorHome => Action::CommandCursorHome,
+        KeyAction::CursorEnd => Action::CommandCursorEnd,
+        KeyAction::HistoryPrev => Action::CommandHistoryPrev,
+        KeyAction::HistoryNext => Action::CommandHistoryNext,
+        KeyAction::Complete => Action::CommandComplete,
+        KeyAction::CompleteBack => Action::CommandCompleteBack,
+        _ => return,
+    };
+    state.apply(action);
+}
+
+/// The keymap's view of a key press; `None` for keys it cannot bind.
+fn key_chord(key: KeyEvent) -> Option<KeyChord> {
+    let code = match key.code {
+        KeyCode::Char(ch) => Key::Char(ch),
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::BackTab => Key::BackTab,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::Insert => Key::Insert,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::F(number) => Key::F(number),
+        _ => return None,
+    };
+    Some(KeyChord::new(
+        code,
+        key.modifiers.contains(KeyModifiers::CONTROL),
+        key.modifiers.contains(KeyModifiers::ALT),
+        key.modifiers.contains(KeyModifiers::SHIFT),
+    ))
+}
+
+/// Turns a chord back into a key press, to hand unbound keys to a PTY.
+fn key_event(chord: KeyChord) -> KeyEvent {
+    let code = match chord.key {
+        Key::Char(ch) => KeyCode::Char(ch),
+        Key::Enter => KeyCode::Enter,
+        Key::Esc => KeyCode::Esc,
+        Key::Tab => KeyCode::Tab,
+        Key::BackTab => KeyCode::BackTab,
+        Key::Backspace => KeyCode::Backspace,
+        Key::Delete => KeyCode::Delete,
+        Key::Insert => KeyCode::Insert,
+        Key::Left => KeyCode::Left,
+        Key::Right => KeyCode::Right,
+        Key::Up => KeyCode::Up,
+        Key::Down => KeyCode::Down,
+        Key::Home => KeyCode::Home,
+        Key::End => KeyCode::End,
+        Key::PageUp => KeyCode::PageUp,
+        Key::PageDown => KeyCode::PageDown,
+        Key::F(number) => KeyCode::F(number),
+    };
+    let mut modifiers = KeyModifiers::NONE;
+    modifiers.set(KeyModifiers::CONTROL, chord.ctrl);
+    modifiers.set(KeyModifiers::ALT, chord.alt);
+    modifiers.set(KeyModifiers::SHIFT, chord.shift || chord.key == Key::BackTab);
+    KeyEvent::new(code, modifiers)
+}
+
+/// Routes a host paste to whatever currently owns the keyboard. Pastes never
+/// reach app bindings, so stray characters can't move focus or quit.
+pub(crate) fn handle_paste(
+    text: &str,
+    state: &mut AppState,
+    pty: Option<&mut PtySession>,
+    agents_pty: Option<&mut PtySession>,
+) {
+    if state.command.is_some() {
+        for ch in text.chars() {
+            let ch = if ch == '\n' || ch == '\t' { ' ' } else { ch };
+            if is_printable(ch) {
+                state.apply(Action::CommandAppend(ch));
+            }
+        }
+        return;
+    }
+
+    if state
+        .terminal(state.focused)
+        .is_some_and(|terminal| terminal.search.is_prompting())
+    {
+        for ch in text.chars().filter(|&ch| is_printable(ch)) {
+            state.apply(Action::SearchAppend(ch));
+        }
+        return;
+    }
+
+    if state.focused == PaneId::General
+        && state.general_input_mode == GeneralInputMode::TerminalLocked
+        && state.general().process.exit_code().is_none()
+    {
+        state.apply(Action::ExitScroll);
+        if let Some(pty) = pty {
+            let _ = pty.send_paste(text);
+        }
+        return;
+    }
+
+    if state.focused == PaneId::Agents
+        && state.agents_input_mode == AgentsInputMode::CodexLocked
+        && state.agents().process.exit_code().is_none()
+    {
+        state.apply(Action::ExitScroll);
+        if let Some(pty) = agents_pty {
+            let _ = pty.send_paste(text);
+        }
+    }
+}
+
+/// Clicks focus the pane under the pointer. Over a PTY pane, events go to the
+/// child when it has mouse reporting on; otherwise the wheel drives scrollback.
+/// Returns `false` when the event changed nothing and reached no child, as
+/// plain pointer motion mostly does.
+pub(crate) fn handle_mouse(
+    event: MouseEvent,
+    state: &mut AppState,
+    pty: Option<&mut PtySession>,
+    agents_pty: Option<&mut PtySession>,
+) -> bool {
+    if state.command.is_some() {
+        return false;
+    }
+    let Some(pane) = state.layout.pane_at(event.column, event.row) else {
+        return false;
+    };
+    let clicked = matches!(event.kind, MouseEventKind::Down(_));
+    if clicked {
+        state.apply(Action::FocusPane(pane));
+    }
+
+    let (session, scrolling, exited) = match pane {
+        PaneId::General => {
+            let Some(pty) = pty else {
+                return clicked;
+            };
+            (
+                pty,
+                state.general().scroll.is_active(),
+                state.general().process.exit_code().is_some(),
+            )
+        }
+        PaneId::Agents => {
+            let Some(pty) = agents_pty else {
+                return clicked;
+            };
+            (
+                pty,
+                state.agents().scroll.is_active(),
+                state.agents().process.exit_code().is_some(),
+            )
+        }
+        _ => return clicked,
+    };
+
+    if session.wants_mouse() && !scrolling && !exited {
+        let sent = state
+            .layout
+            .screen_cell_at(pane, event.column, event.row)
+            .is_some_and(|(column, row)| {
+                session
+                    .send_mouse(MouseEvent {
+                        column,
+                        row,
+                        ..event
+                    })
+                    .unwrap_or(false)
+            });
+        return clicked || sent;
+    }
+
+    let action = match event.kind {
+        MouseEventKind::ScrollUp => Action::ScrollLineUp,
+        MouseEventKind::ScrollDown => Action::ScrollLineDown,
+        _ => return clicked,
+    };
+    state.apply(Action::FocusPane(pane));
+    for _ in 0..MOUSE_WHEEL_LINES {
+        state.apply(action);
+    }
+    if state
+        .focused_scroll_mut()
+        .is_some_and(|scroll| scroll.offset() == 0)
+    {
+        state.apply(Action::ExitScroll);
+    }
+    true
+}
+
+/// Keys inside copy mode. Esc drops the selection first, then leaves; `y` and
+/// Enter yank and are handled by the caller since they need the PTY history.
+fn copy_mode_action(key: KeyEvent, selecting: bool) -> Option<Action> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let motion = match key.code {
+        KeyCode::Char('h') | KeyCode::Left => CopyMotion::Left,
+        KeyCode::Char('l') | KeyCode::Right => CopyMotion::Right,
+        KeyCode::Char('k') | KeyCode::Up => CopyMotion::Up,
+        KeyCode::Char('j') | KeyCode::Down => CopyMotion::Down,
+        KeyCode::Char('0') | KeyCode::Home => CopyMotion::LineStart,
+        KeyCode::Char('$') | KeyCode::End => CopyMotion::LineEnd,
+        KeyCode::Char('u') if ctrl => CopyMotion::HalfPageUp,
+        KeyCode::Char('d') if ctrl => CopyMotion::HalfPageDown,
+        KeyCode::Char('b') if ctrl => CopyMotion::PageUp,
+        KeyCode::Char('f') if ctrl => CopyMotion::PageDown,
+        KeyCode::PageUp => CopyMotion::PageUp,
+        KeyCode::PageDown => CopyMotion::PageDown,
+        KeyCode::Char('g') => CopyMotion::Top,
+        KeyCode::Char('G') => CopyMotion::Bottom,
+        KeyCode::Char('v') if ctrl => return Some(Action::CopySelect(SelectionKind::Block)),
+        KeyCode::Char('v') => return Some(Action::CopySelect(SelectionKind::Char)),
+        KeyCode::Char('V') => return Some(Action::CopySelect(SelectionKind::Line)),
+        KeyCode::Esc if selecting => return Some(Action::CopyClearSelection),
+        KeyCode::Esc | KeyCode::Char('q') => return Some(Action::CopyExit),
+        _ => return None,
+    };
+    Some(Action::CopyMove(motion))
+}
+
+/// Sets the host terminal's clipboard with OSC 52.
+fn copy_to_host_clipboard(host: &mut dyn Write, text: &str) {
+    let _ = write!(host, "\x1b]52;c;{}\x07", BASE64.encode(text));
+    let _ = host.flush();
+}
+
+/// Desktop notification through the host terminal (OSC 9, understood by
+/// iTerm2, kitty, WezTerm, foot and others), plus a bell for the rest.
+pub(crate) fn notify_host(host: &mut dyn Write, message: &str) {
+    let message: String = message.chars().filter(|ch| !ch.is_control()).collect();
+    let _ = write!(host, "\x1b]9;{message}\x07\x07");
+    let _ = host.flush();
+}
+
+/// Search keys in app-controls mode while a query is active: `n`/`N` step
+/// to older/newer matches and Esc clears it.
+fn pane_search_action(key: KeyEvent, searching: bool) -> Option<Action> {
+    match key.code {
+        KeyCode::Char('n') if searching => Some(Action::SearchNext),
+        KeyCode::Char('N') if searching => Some(Action::SearchPrev),
+        KeyCode::Esc if searching => Some(Action::SearchClear),
+        _ => None,
+    }
+}
+
+/// Keys of an active scroll in app-controls mode; the keymap's
+/// `scroll-page-up` (PgUp) starts one.
+fn pane_scroll_action(key: KeyEvent) -> Option<Action> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::PageUp => Some(Action::ScrollPageUp),
+        KeyCode::PageDown => Some(Action::ScrollPageDown),
+        KeyCode::Char('b') if ctrl => Some(Action::ScrollPageUp),
+        KeyCode::Char('f') if ctrl => Some(Action::ScrollPageDown),
+        KeyCode::Char('k') | KeyCode::Up => Some(Action::ScrollLineUp),
+        KeyCode::Char('j') | KeyCode::Down => Some(Action::ScrollLineDown),
+        KeyCode::Char('g') | KeyCode::Home => Some(Action::ScrollTop),
+        KeyCode::Char('G') | KeyCode::End => Some(Action::ScrollBottom),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Action::ExitScroll),
+        _ => None,
+    }
+}
+
+fn is_printable(ch: char) -> bool {
+    !ch.is_control()
+}
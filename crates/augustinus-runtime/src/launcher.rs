@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::time::{Duration, Instant};
+
+#[cfg(unix)]
+use augustinus_pty::daemon_sessions;
+use augustinus_pty::{PtyBuilder, PtySession};
+
+/// How long to wait for a freshly started PTY daemon to accept connections.
+#[cfg(unix)]
+const DAEMON_START_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Starts the PTYs behind GENERAL tabs and agents.
+pub trait PtyFactory {
+    /// Starts `builder`'s child. `key` names the session, e.g. `general-0`
+    /// or `agent-codex`, so a persistent factory can hand back the one an
+    /// earlier run left behind.
+    fn spawn(&self, builder: PtyBuilder, key: &str) -> anyhow::Result<PtySession>;
+
+    /// Keys of the sessions left running by an earlier run.
+    fn sessions(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// PTYs owned by this process; they end with it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalPtys;
+
+impl PtyFactory for LocalPtys {
+    fn spawn(&self, builder: PtyBuilder, _key: &str) -> anyhow::Result<PtySession> {
+        builder.spawn()
+    }
+}
+
+/// PTYs held by the `augustinus daemon` process, so they survive the UI
+/// quitting or crashing (`persistent_sessions`).
+#[derive(Debug, Clone)]
+pub struct DaemonPtys {
+    socket: PathBuf,
+}
+
+impl DaemonPtys {
+    /// Reaches the daemon on `socket`, starting `exe daemon` in the
+    /// background when none answers. `None` if it can't be reached.
+    pub fn connect(socket: &Path, exe: &Path) -> Option<Self> {
+        ensure_daemon(socket, exe).then(|| Self {
+            socket: socket.to_path_buf(),
+        })
+    }
+}
+
+impl PtyFactory for DaemonPtys {
+    #[cfg(unix)]
+    fn spawn(&self, builder: PtyBuilder, key: &str) -> anyhow::Result<PtySession> {
+        builder.attach(&self.socket, key)
+    }
+
+    #[cfg(not(unix))]
+    fn spawn(&self, builder: PtyBuilder, _key: &str) -> anyhow::Result<PtySession> {
+        builder.spawn()
+    }
+
+    #[cfg(unix)]
+    fn sessions(&self) -> Vec<String> {
+        daemon_sessions(&self.socket).unwrap_or_default()
+    }
+}
+
+#[cfg(unix)]
+fn ensure_daemon(socket: &Path, exe: &Path) -> bool {
+    use std::os::unix::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    if daemon_sessions(socket).is_ok() {
+        return true;
+    }
+    // Its own process group keeps Ctrl-C in this terminal away from it.
+    let Ok(mut daemon) = Command::new(exe)
+        .arg("daemon")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .process_group(0)
+        .spawn()
+    else {
+        return false;
+    };
+    std::thread::spawn(move || daemon.wait());
+    let deadline = Instant::now() + DAEMON_START_TIMEOUT;
+    while Instant::now() < deadline {
+        if daemon_sessions(socket).is_ok() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    false
+}
+
+#[cfg(not(unix))]
+fn ensure_daemon(_socket: &Path, _exe: &Path) -> bool {
+    false
+}
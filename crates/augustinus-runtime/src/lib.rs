@@ -0,0 +1,15 @@
+//! The app's event loop. Its input, clock, terminal backend and PTYs are
+//! injectable, so whole scenarios can be scripted against a `TestBackend`.
+
+mod agents;
+mod clock;
+mod commands;
+mod event;
+mod input;
+mod launcher;
+mod runtime;
+
+pub use clock::{Clock, ManualClock, SystemClock};
+pub use event::{EventSource, ScriptedEvents, TerminalEvents};
+pub use launcher::{DaemonPtys, LocalPtys, PtyFactory};
+pub use runtime::{Runtime, RuntimeBuilder};
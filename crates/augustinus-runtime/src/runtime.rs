@@ -0,0 +1,902 @@
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use augustinus_app::{
+    capture, frame_for_agent, Action, AgentPicker, AgentSend, AgentsInputMode, AppState,
+    CommandStats, KeyMode, LocDelta, PaneId, PaneRect, SplitRatios, TerminalTab, TerminalTabs,
+    COMMAND_HISTORY_LEN,
+};
+use augustinus_i18n::fill;
+use augustinus_pty::{CastRecorder, PtyBuilder, PtySession};
+use augustinus_store::config::{AppConfig, PaneConfig, SplitsConfig};
+use augustinus_store::db::Store;
+use augustinus_store::paths;
+use crossterm::event::Event;
+use ratatui::{backend::Backend, Terminal};
+
+use crate::agents::{picked_agent, spawn_agent, start_agent, start_agents, AgentSpec};
+use crate::clock::{Clock, SystemClock};
+use crate::commands::handle_command;
+use crate::event::{EventSource, TerminalEvents};
+use crate::input::{handle_key, handle_mouse, handle_paste, notify_host};
+use crate::launcher::{LocalPtys, PtyFactory};
+
+/// Redraw at least this often so clocks and countdowns stay current when
+/// nothing else changes.
+const IDLE_REDRAW: Duration = Duration::from_secs(1);
+const TICK_RATE: Duration = Duration::from_millis(33);
+/// Redraw interval for the ticker, pulse and particles of the motivation
+/// pane when nothing else changes.
+const MOTIVATION_FRAME: Duration = Duration::from_millis(100);
+const GIT_POLL: Duration = Duration::from_secs(30);
+/// Resized splits are saved once no resize came for this long.
+const SPLITS_SAVE_DELAY: Duration = Duration::from_secs(1);
+/// Daemon session keys of GENERAL tabs: the prefix followed by a number.
+const GENERAL_KEY: &str = "general-";
+
+/// Configures a [`Runtime`]; everything not set talks to the real host.
+///
+/// ```no_run
+/// # use augustinus_runtime::{ManualClock, RuntimeBuilder, ScriptedEvents};
+/// # use augustinus_store::{config::AppConfig, db::Store};
+/// # use ratatui::{backend::TestBackend, Terminal};
+/// # fn demo(config: AppConfig) -> anyhow::Result<()> {
+/// let events = ScriptedEvents::new();
+/// let store = Store::open_in_memory()?;
+/// let mut runtime = RuntimeBuilder::new(config, store)
+///     .events(events.clone())
+///     .clock(ManualClock::new())
+///     .host_output(std::io::sink())
+///     .start(Terminal::new(TestBackend::new(120, 40))?)?;
+/// events.push_str(":focus start");
+/// runtime.step()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RuntimeBuilder {
+    config: AppConfig,
+    store: Store,
+    events: Box<dyn EventSource>,
+    clock: Box<dyn Clock>,
+    ptys: Box<dyn PtyFactory>,
+    host: Box<dyn Write>,
+}
+
+impl RuntimeBuilder {
+    pub fn new(config: AppConfig, store: Store) -> Self {
+        Self {
+            config,
+            store,
+            events: Box::new(TerminalEvents),
+            clock: Box::new(SystemClock),
+            ptys: Box::new(LocalPtys),
+            host: Box::new(io::stdout()),
+        }
+    }
+
+    pub fn events(mut self, events: impl EventSource + 'static) -> Self {
+        self.events = Box::new(events);
+        self
+    }
+
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Where GENERAL shells and agents are started; local PTYs by default.
+    pub fn ptys(mut self, ptys: impl PtyFactory + 'static) -> Self {
+        self.ptys = Box::new(ptys);
+        self
+    }
+
+    /// Receives escape sequences meant for the host terminal itself, like
+    /// clipboard writes and notifications; stdout by default.
+    pub fn host_output(mut self, host: impl Write + 'static) -> Self {
+        self.host = Box::new(host);
+        self
+    }
+
+    /// Loads today's stats from the store and starts the GENERAL shell
+    /// (plus any tabs the PTY factory still holds) and the agents.
+    pub fn start<B: Backend>(self, terminal: Terminal<B>) -> io::Result<Runtime<B>>
+    where
+        B::Error: Send + Sync + 'static,
+    {
+        let Self {
+            config,
+            store,
+            events,
+            clock,
+            ptys,
+            host,
+        } = self;
+        let mut state = AppState::new_for_test();
+        state.apply(Action::SetLanguage(config.language));
+        configure_keys(&config, &mut state);
+        state.splits = split_ratios(config.splits);
+        state
+            .general_tabs
+            .set_silence_after(config.general.silence_alert_secs.map(Duration::from_secs));
+        state
+            .agent_tabs
+            .set_silence_after(config.agent_pane.silence_alert_secs.map(Duration::from_secs));
+        load_stats(&store, &mut state)?;
+
+        let size = terminal.size().map_err(io::Error::other)?;
+        let general_size = general_pty_size(&state, size.width, size.height);
+        let agents_size = pane_pty_size(&state, size.width, size.height, PaneId::Agents);
+
+        let restored = ptys.sessions();
+        let mut general_keys: Vec<&str> = restored
+            .iter()
+            .map(String::as_str)
+            .filter(|key| key.starts_with(GENERAL_KEY))
+            .collect();
+        general_keys.sort_by_key(|key| key[GENERAL_KEY.len()..].parse::<u32>().unwrap_or(u32::MAX));
+        let first_key = general_keys
+            .first()
+            .map_or_else(|| format!("{GENERAL_KEY}0"), |key| key.to_string());
+        let (cols, rows) = general_size;
+        let mut general_ptys = vec![(
+            state.general().id(),
+            spawn_general(&config, ptys.as_ref(), &first_key, cols, rows).map_err(anyhow_to_io)?,
+        )];
+        for key in general_keys.iter().skip(1) {
+            if let Ok(pty) = spawn_general(&config, ptys.as_ref(), key, cols, rows) {
+                general_ptys.push((state.general_tabs.open(), pty));
+            }
+        }
+        state.apply(Action::TabSelect(0));
+
+        let mut agent_ptys = Vec::new();
+        let mut agent_specs = Vec::new();
+        start_agents(
+            &config,
+            ptys.as_ref(),
+            &restored,
+            &mut state,
+            &mut agent_ptys,
+            &mut agent_specs,
+            agents_size,
+        );
+
+        let now = clock.now();
+        Ok(Runtime {
+            terminal,
+            config,
+            store,
+            events,
+            clock,
+            ptys,
+            host,
+            state,
+            general_ptys,
+            agent_ptys,
+            agent_specs,
+            general_size,
+            agents_size,
+            last_tick: now,
+            last_draw: now,
+            needs_redraw: true,
+            splits_changed_at: None,
+            // Poll git on the first tick.
+            git_poll_elapsed: GIT_POLL,
+        })
+    }
+}
+
+/// The event loop: draws `AppState`, feeds it input and keeps the PTYs of
+/// GENERAL tabs and agents in sync with it.
+pub struct Runtime<B: Backend> {
+    terminal: Terminal<B>,
+    config: AppConfig,
+    store: Store,
+    events: Box<dyn EventSource>,
+    clock: Box<dyn Clock>,
+    ptys: Box<dyn PtyFactory>,
+    host: Box<dyn Write>,
+    state: AppState,
+    general_ptys: Vec<(u32, PtySession)>,
+    agent_ptys: Vec<(u32, PtySession)>,
+    agent_specs: Vec<AgentSpec>,
+    /// PTY sizes as `(cols, rows)` for the current layout.
+    general_size: (u16, u16),
+    agents_size: (u16, u16),
+    last_tick: Instant,
+    last_draw: Instant,
+    needs_redraw: bool,
+    /// When the splits were last resized, while that is not saved yet.
+    splits_changed_at: Option<Instant>,
+    git_poll_elapsed: Duration,
+}
+
+impl<B: Backend> Runtime<B>
+where
+    B::Error: Send + Sync + 'static,
+{
+    /// Runs until the user quits.
+    pub fn run(&mut self) -> io::Result<()> {
+        while !self.step()? {}
+        Ok(())
+    }
+
+    /// One pass of the loop: redraw if needed, sync the PTYs, handle at most
+    /// one event and tick once a tick is due. Returns `true` on quit.
+    pub fn step(&mut self) -> io::Result<bool> {
+        if self.needs_redraw
+            || self.clock.now().saturating_duration_since(self.last_draw) >= IDLE_REDRAW
+        {
+            self.draw()?;
+        }
+
+        self.sync_ptys();
+        self.sync_sizes()?;
+
+        let since_tick = self.clock.now().saturating_duration_since(self.last_tick);
+        if let Some(event) = self.events.next(TICK_RATE.saturating_sub(since_tick))?
+            && self.handle_event(event)?
+        {
+            self.save_splits();
+            return Ok(true);
+        }
+
+        if let Some(send) = self.state.take_agent_send() {
+            let pty = active_pty(&self.state.general_tabs, &mut self.general_ptys);
+            let agents_pty = active_pty(&self.state.agent_tabs, &mut self.agent_ptys);
+            send_to_agent(send, &mut self.state, pty, agents_pty);
+        }
+
+        let dt = self.clock.now().saturating_duration_since(self.last_tick);
+        if dt >= TICK_RATE {
+            self.tick(dt);
+        }
+        Ok(false)
+    }
+
+    /// Renders the current state right away.
+    pub fn draw(&mut self) -> io::Result<()> {
+        let state = &mut self.state;
+        self.terminal
+            .draw(|frame| augustinus_tui::render(frame, state))
+            .map_err(io::Error::other)?;
+        self.needs_redraw = false;
+        self.last_draw = self.clock.now();
+        Ok(())
+    }
+
+    /// Frames drawn so far.
+    pub fn frame_count(&mut self) -> usize {
+        self.terminal.get_frame().count()
+    }
+
+    pub fn state(&self) -> &AppState {
+        &self.state
+    }
+
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    pub fn backend(&self) -> &B {
+        self.terminal.backend()
+    }
+
+    pub fn backend_mut(&mut self) -> &mut B {
+        self.terminal.backend_mut()
+    }
+
+    /// Spawns and kills PTYs to match the tabs, then copies their output
+    /// into the state.
+    fn sync_ptys(&mut self) {
+        let config = &self.config;
+        let ptys = self.ptys.as_ref();
+        let state = &mut self.state;
+        let (general_cols, general_rows) = self.general_size;
+        let (agents_cols, agents_rows) = self.agents_size;
+        let mut notices = Vec::new();
+
+        sync_general_tabs(
+            config,
+            ptys,
+            state,
+            &mut self.general_ptys,
+            general_cols,
+            general_rows,
+        );
+        for (id, pty) in &mut self.general_ptys {
+            let visible = state.is_terminal_visible(PaneId::General, *id);
+            let Some(tab) = state.general_tabs.get_mut(*id) else {
+                continue;
+            };
+            let key = pty.daemon_key().unwrap_or_default().to_string();
+            self.needs_redraw |= sync_terminal(
+                pty,
+                tab,
+                visible,
+                general_rows,
+                "general",
+                &mut notices,
+                || spawn_general(config, ptys, &key, general_cols, general_rows),
+            );
+            for command in tab.shell.take_finished() {
+                match self.store.record_command(
+                    &command.command,
+                    command.exit_code,
+                    command.duration,
+                ) {
+                    Ok(()) => state.commands.record(command.exit_code),
+                    Err(error) => notices.push(TerminalNotice::StatsFailed(error)),
+                }
+                self.needs_redraw = true;
+            }
+        }
+        if let Some(choice) = state.agent_picker.as_mut().and_then(AgentPicker::take_choice) {
+            let agent = picked_agent(config, choice);
+            if let Ok(pty) = spawn_agent(config, ptys, &agent, agents_cols, agents_rows) {
+                let id = state.agents().id();
+                state.agents_mut().name = Some(agent.name.clone());
+                state.agent_picker = None;
+                self.agent_ptys.push((id, pty));
+                self.agent_specs.push(AgentSpec::new(id, agent));
+            }
+        }
+        // Agents that could not start have a tab but no PTY until a restart
+        // succeeds.
+        let strings = state.strings();
+        for tab in state.agent_tabs.iter_mut() {
+            if self.agent_ptys.iter().any(|(id, _)| *id == tab.id())
+                || !tab.process.take_restart_request()
+            {
+                continue;
+            }
+            let Some(spec) = self.agent_specs.iter().find(|spec| spec.id == tab.id()) else {
+                continue;
+            };
+            match start_agent(config, ptys, &spec.config, strings, agents_cols, agents_rows) {
+                Ok(pty) => {
+                    tab.process.on_spawned();
+                    self.agent_ptys.push((tab.id(), pty));
+                }
+                Err(reason) => tab.process.on_start_failed(reason),
+            }
+            self.needs_redraw = true;
+        }
+        for (id, pty) in &mut self.agent_ptys {
+            let visible = state.is_terminal_visible(PaneId::Agents, *id);
+            let Some(tab) = state.agent_tabs.get_mut(*id) else {
+                continue;
+            };
+            let Some(spec) = self.agent_specs.iter().find(|spec| spec.id == *id) else {
+                continue;
+            };
+            let changed = sync_terminal(
+                pty,
+                tab,
+                visible,
+                agents_rows,
+                "agents",
+                &mut notices,
+                || spawn_agent(config, ptys, &spec.config, agents_cols, agents_rows),
+            );
+            if changed {
+                tab.agent_status.on_screen_change(spec.is_waiting(&tab.screen));
+            }
+            self.needs_redraw |= changed;
+        }
+        for notice in notices {
+            notice.show(state);
+            self.needs_redraw = true;
+        }
+        for name in state.take_agent_notifications() {
+            let message = fill(state.strings().agent_waiting_notification, &[&name]);
+            notify_host(self.host.as_mut(), &message);
+        }
+    }
+
+    /// Resizes the PTYs when the terminal or the layout changed.
+    fn sync_sizes(&mut self) -> io::Result<()> {
+        let size = self.terminal.size().map_err(io::Error::other)?;
+        let general_size = general_pty_size(&self.state, size.width, size.height);
+        if general_size != self.general_size {
+            for (_, pty) in &mut self.general_ptys {
+                let _ = pty.resize(general_size.0, general_size.1);
+            }
+            self.general_size = general_size;
+            self.needs_redraw = true;
+        }
+
+        let agents_size = pane_pty_size(&self.state, size.width, size.height, PaneId::Agents);
+        if agents_size != self.agents_size {
+            for (_, pty) in &mut self.agent_ptys {
+                let _ = pty.resize(agents_size.0, agents_size.1);
+            }
+            self.agents_size = agents_size;
+            self.needs_redraw = true;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` when the event asks to quit.
+    fn handle_event(&mut self, event: Event) -> io::Result<bool> {
+        let state = &mut self.state;
+        // Mouse events decide for themselves, so pointer motion nobody
+        // tracks redraws nothing.
+        self.needs_redraw |= !matches!(event, Event::Mouse(_));
+        match event {
+            Event::Key(key) => {
+                state.on_activity();
+                let pty = active_pty(&state.general_tabs, &mut self.general_ptys);
+                let agents_pty = active_pty(&state.agent_tabs, &mut self.agent_ptys);
+                if handle_key(key, state, pty, agents_pty, self.host.as_mut()) {
+                    return Ok(true);
+                }
+                if state.take_splits_change().is_some() {
+                    self.splits_changed_at = Some(self.clock.now());
+                }
+                for command in state.command_line.take_unsaved() {
+                    self.store
+                        .add_command_history(&command, COMMAND_HISTORY_LEN)
+                        .map_err(anyhow_to_io)?;
+                }
+                if let Some(command) = state.take_command()
+                    && handle_command(
+                        command,
+                        state,
+                        &mut self.config,
+                        &self.store,
+                        self.clock.now(),
+                    )?
+                {
+                    return Ok(true);
+                }
+            }
+            Event::Paste(text) => {
+                state.on_activity();
+                let pty = active_pty(&state.general_tabs, &mut self.general_ptys);
+                let agents_pty = active_pty(&state.agent_tabs, &mut self.agent_ptys);
+                handle_paste(&text, state, pty, agents_pty);
+            }
+            Event::Mouse(mouse) => {
+                let pty = active_pty(&state.general_tabs, &mut self.general_ptys);
+                let agents_pty = active_pty(&state.agent_tabs, &mut self.agent_ptys);
+                if handle_mouse(mouse, state, pty, agents_pty) {
+                    state.on_activity();
+                    self.needs_redraw = true;
+                }
+            }
+            _ => {}
+        }
+        Ok(false)
+    }
+
+    fn tick(&mut self, dt: Duration) {
+        self.state.tick(dt);
+        // Typing and bursts are drawn every tick, the ambient animation of
+        // the motivation pane every `MOTIVATION_FRAME`; with a terminal pane
+        // fullscreen only PTY changes, input and the idle redraw remain.
+        let motivation_visible = self
+            .state
+            .fullscreen
+            .is_none_or(|pane| pane == PaneId::Motivation);
+        let frame_due = self.state.motivation.is_animating()
+            || self.clock.now().saturating_duration_since(self.last_draw) >= MOTIVATION_FRAME;
+        self.needs_redraw |= motivation_visible && frame_due;
+        if self
+            .splits_changed_at
+            .is_some_and(|at| self.clock.now().saturating_duration_since(at) >= SPLITS_SAVE_DELAY)
+        {
+            self.save_splits();
+        }
+        self.git_poll_elapsed = self.git_poll_elapsed.saturating_add(dt);
+        if self.git_poll_elapsed >= GIT_POLL {
+            self.git_poll_elapsed = Duration::ZERO;
+            self.state.loc_delta = self.config.git_repo.as_deref().and_then(compute_loc_delta);
+        }
+        self.last_tick = self.clock.now();
+    }
+
+    /// Writes resized splits to the config file, if there are any.
+    fn save_splits(&mut self) {
+        if self.splits_changed_at.take().is_none() {
+            return;
+        }
+        self.config.splits = splits_config(self.state.splits);
+        if let Err(err) = self.config.save() {
+            let message = fill(self.state.strings().error_splits_save, &[&format!("{err:#}")]);
+            self.state.command_failed(message);
+        }
+    }
+}
+
+/// `config` over the default splits.
+fn split_ratios(config: SplitsConfig) -> SplitRatios {
+    let even = SplitRatios::default();
+    SplitRatios::new(
+        config.rows.unwrap_or(even.rows),
+        config.top.unwrap_or(even.top),
+        config.bottom.unwrap_or(even.bottom),
+    )
+}
+
+/// `splits` as a `[splits]` table, leaving out what matches the default.
+fn splits_config(splits: SplitRatios) -> SplitsConfig {
+    let even = SplitRatios::default();
+    let changed = |value: u16, default: u16| (value != default).then_some(value);
+    SplitsConfig {
+        rows: changed(splits.rows, even.rows),
+        top: changed(splits.top, even.top),
+        bottom: changed(splits.bottom, even.bottom),
+    }
+}
+
+pub(crate) fn anyhow_to_io(error: anyhow::Error) -> io::Error {
+    io::Error::other(error)
+}
+
+fn spawn_general(
+    config: &AppConfig,
+    ptys: &dyn PtyFactory,
+    key: &str,
+    cols: u16,
+    rows: u16,
+) -> anyhow::Result<PtySession> {
+    let builder = pane_builder(config, &config.general, &config.shell, &[], cols, rows);
+    ptys.spawn(builder, key).context("spawn shell")
+}
+
+/// Applies `[keys]` on top of the default bindings. A table with an error is
+/// skipped and the error shown in the command overlay.
+fn configure_keys(config: &AppConfig, state: &mut AppState) {
+    let tables = [
+        (KeyMode::App, &config.keys.app),
+        (KeyMode::Terminal, &config.keys.terminal),
+        (KeyMode::Command, &config.keys.command),
+    ];
+    for (mode, table) in tables {
+        let entries = table.iter().map(|(keys, binding)| (keys.as_str(), binding.as_str()));
+        if let Err(err) = state.keymap.configure(mode, entries) {
+            state.command_failed(err.message(state.strings()));
+        }
+    }
+}
+
+fn load_stats(store: &Store, state: &mut AppState) -> io::Result<()> {
+    let today = chrono::Local::now().date_naive();
+    let focus_seconds_today = store
+        .focus_seconds_for_day(today)
+        .map_err(anyhow_to_io)?
+        .max(0) as u64;
+    let streak = store.streak_days_ending_today().map_err(anyhow_to_io)?;
+    state.focus.set_focus_seconds_today(focus_seconds_today);
+    state.focus.set_streak_days(streak);
+    let (commands, failed) = store.command_counts_for_day(today).map_err(anyhow_to_io)?;
+    state.commands = CommandStats {
+        today: commands.max(0) as u64,
+        failed: failed.max(0) as u64,
+    };
+    let history = store
+        .command_history(COMMAND_HISTORY_LEN)
+        .map_err(anyhow_to_io)?;
+    state.command_line.set_history(history);
+    Ok(())
+}
+
+/// Applies a pane's `[general]`/`[agent_pane]` settings on top of `program`.
+pub(crate) fn pane_builder(
+    config: &AppConfig,
+    pane: &PaneConfig,
+    program: &str,
+    args: &[String],
+    cols: u16,
+    rows: u16,
+) -> PtyBuilder {
+    let mut builder = PtySession::builder(program)
+        .args(args.iter().chain(&pane.args).cloned())
+        .size(cols, rows);
+    if let Some(cwd) = config.pane_cwd(pane) {
+        builder = builder.cwd(cwd);
+    }
+    if let Some(term) = &pane.term {
+        builder = builder.term(term);
+    }
+    if let Some(colorterm) = &pane.colorterm {
+        builder = builder.colorterm(colorterm);
+    }
+    for (key, value) in &pane.env {
+        builder = builder.env(key, value);
+    }
+    builder
+}
+
+/// Matches one PTY to every GENERAL tab: spawns sessions for new tabs and
+/// kills those of closed ones. A tab whose shell fails to start is removed.
+fn sync_general_tabs(
+    config: &AppConfig,
+    launcher: &dyn PtyFactory,
+    state: &mut AppState,
+    ptys: &mut Vec<(u32, PtySession)>,
+    cols: u16,
+    rows: u16,
+) {
+    ptys.retain_mut(|(id, pty)| {
+        let open = state.general_tabs.contains(*id);
+        if !open {
+            pty.kill();
+        }
+        open
+    });
+    let missing: Vec<u32> = state
+        .general_tabs
+        .iter()
+        .map(|tab| tab.id())
+        .filter(|id| ptys.iter().all(|(pty_id, _)| pty_id != id))
+        .collect();
+    for id in missing {
+        let key = unused_general_key(ptys);
+        match spawn_general(config, launcher, &key, cols, rows) {
+            Ok(session) => ptys.push((id, session)),
+            Err(_) => state.general_tabs.remove(id),
+        }
+    }
+}
+
+/// The lowest-numbered GENERAL key no open tab uses.
+fn unused_general_key(ptys: &[(u32, PtySession)]) -> String {
+    let mut n = 0;
+    loop {
+        let key = format!("{GENERAL_KEY}{n}");
+        if ptys.iter().all(|(_, pty)| pty.daemon_key() != Some(key.as_str())) {
+            return key;
+        }
+        n += 1;
+    }
+}
+
+/// The PTY behind the active tab of `tabs`.
+fn active_pty<'a>(
+    tabs: &TerminalTabs,
+    ptys: &'a mut [(u32, PtySession)],
+) -> Option<&'a mut PtySession> {
+    let id = tabs.active().id();
+    ptys.iter_mut()
+        .find(|(pty_id, _)| *pty_id == id)
+        .map(|(_, pty)| pty)
+}
+
+/// Per-frame runtime work for one terminal: reports exits, carries out
+/// restart/record/search requests and copies the PTY's output into `tab`.
+/// What the user should hear about is pushed to `notices`.
+fn sync_terminal(
+    pty: &mut PtySession,
+    tab: &mut TerminalTab,
+    visible: bool,
+    rows: u16,
+    name: &'static str,
+    notices: &mut Vec<TerminalNotice>,
+    respawn: impl FnOnce() -> anyhow::Result<PtySession>,
+) -> bool {
+    let status = tab.process.status();
+    if let Some(code) = pty.exit_code() {
+        tab.process.on_exit(code);
+    }
+    if tab.process.take_restart_request() {
+        // A daemon session outlives its handle, so end it before asking the
+        // daemon for a fresh one under the same key.
+        pty.kill();
+        match respawn() {
+            Ok(session) => {
+                replace_session(pty, session);
+                tab.process.on_spawned();
+            }
+            Err(error) => {
+                tab.process.on_spawn_failed();
+                notices.push(TerminalNotice::RespawnFailed { pane: name, error });
+            }
+        }
+    }
+    if let Some(start) = tab.recording.take_request() {
+        notices.extend(apply_record_request(pty, start, name));
+    }
+
+    pty.set_scrollback(tab.scroll.offset());
+    let output = pty.poll();
+    if output {
+        tab.activity.on_output(visible);
+    }
+    for mark in pty.take_shell_marks() {
+        tab.shell.on_mark(mark);
+    }
+    let origin = pty.history_origin();
+    tab.shell.set_origin(origin);
+    tab.search.set_origin(origin);
+    if pty.take_bell() {
+        tab.activity.on_bell(visible);
+    }
+    if tab.search.take_run_request() {
+        tab.search.run(&pty.history());
+    }
+    let max_offset = pty.scrollback_len();
+    tab.scroll.sync(pty.scrollback(), max_offset, rows as usize);
+    if let Some(found) = tab.search.take_jump() {
+        tab.scroll.reveal(found.line);
+        pty.set_scrollback(tab.scroll.offset());
+    }
+    let screen_changed = pty.update_snapshot(&mut tab.screen);
+    let title = pty
+        .title()
+        .map(str::to_string)
+        .or_else(|| pty.foreground_process());
+    let recording = pty.is_recording();
+    let changed = screen_changed
+        || output
+        || title != tab.title
+        || recording != tab.recording.is_active()
+        || status != tab.process.status();
+    tab.title = title;
+    tab.recording.set_active(recording);
+    changed
+}
+
+/// Something a terminal's per-frame work has to tell the user. Shown in
+/// the command overlay once the tabs are no longer borrowed.
+enum TerminalNotice {
+    RespawnFailed {
+        pane: &'static str,
+        error: anyhow::Error,
+    },
+    RecordStarted(PathBuf),
+    RecordSaved(PathBuf),
+    RecordFailed(anyhow::Error),
+    StatsFailed(anyhow::Error),
+}
+
+impl TerminalNotice {
+    fn show(self, state: &mut AppState) {
+        match self {
+            Self::RespawnFailed { pane, error } => {
+                let message = fill(state.strings().error_respawn, &[&pane, &format!("{error:#}")]);
+                state.command_failed(message);
+            }
+            Self::RecordStarted(path) => {
+                let line = fill(state.strings().record_started, &[&path.display()]);
+                state.command_output(vec![line]);
+            }
+            Self::RecordSaved(path) => {
+                let line = fill(state.strings().record_saved, &[&path.display()]);
+                state.command_output(vec![line]);
+            }
+            Self::RecordFailed(error) => {
+                let message = fill(state.strings().error_record, &[&format!("{error:#}")]);
+                state.command_failed(message);
+            }
+            Self::StatsFailed(error) => {
+                let message = fill(state.strings().error_stats_save, &[&format!("{error:#}")]);
+                state.command_failed(message);
+            }
+        }
+    }
+}
+
+/// Pastes queued input into the agent and focuses it in terminal mode so
+/// the user can add a question and submit.
+fn send_to_agent(
+    send: AgentSend,
+    state: &mut AppState,
+    general_pty: Option<&mut PtySession>,
+    agents_pty: Option<&mut PtySession>,
+) {
+    if state.agents().process.exit_code().is_some() {
+        return;
+    }
+    let text = match send {
+        AgentSend::Text(text) => text,
+        AgentSend::Output(source) => {
+            let Some(general_pty) = general_pty else {
+                return;
+            };
+            let (_, rows) = general_pty.size();
+            let captured = capture(&general_pty.history(), rows, source, &state.general().shell);
+            if captured.is_empty() {
+                return;
+            }
+            frame_for_agent(&captured, source)
+        }
+    };
+    let Some(agents_pty) = agents_pty else {
+        return;
+    };
+    if agents_pty.send_paste(&text).is_err() {
+        return;
+    }
+    state.apply(Action::FocusPane(PaneId::Agents));
+    if state.agents_input_mode == AgentsInputMode::PaneControls {
+        state.apply(Action::EnterAgentsTerminalMode);
+    }
+}
+
+/// Swaps in a respawned session, carrying an active recording over so one
+/// `.cast` file covers every restart.
+fn replace_session(pty: &mut PtySession, mut session: PtySession) {
+    if let Some(recorder) = pty.stop_recording() {
+        session.start_recording(recorder);
+    }
+    *pty = session;
+}
+
+fn apply_record_request(
+    pty: &mut PtySession,
+    start: bool,
+    pane: &str,
+) -> Option<TerminalNotice> {
+    if !start {
+        let recorder = pty.stop_recording()?;
+        return Some(TerminalNotice::RecordSaved(recorder.path().to_path_buf()));
+    }
+    if pty.is_recording() {
+        return None;
+    }
+    let (cols, rows) = pty.size();
+    let started = recording_path(pane).and_then(|path| {
+        let recorder = CastRecorder::create(&path, cols, rows, pty.term(), pty.shell())?;
+        pty.start_recording(recorder);
+        Ok(path)
+    });
+    Some(match started {
+        Ok(path) => TerminalNotice::RecordStarted(path),
+        Err(error) => TerminalNotice::RecordFailed(error),
+    })
+}
+
+fn recording_path(pane: &str) -> anyhow::Result<PathBuf> {
+    let dir = paths::recordings_dir()?;
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let mut path = dir.join(format!("{pane}-{stamp}.cast"));
+    let mut n = 1;
+    while path.exists() {
+        n += 1;
+        path = dir.join(format!("{pane}-{stamp}-{n}.cast"));
+    }
+    Ok(path)
+}
+
+fn compute_loc_delta(repo_path: &str) -> Option<LocDelta> {
+    let output = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo_path)
+        .arg("diff")
+        .arg("--numstat")
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(LocDelta::parse_git_numstat(&stdout))
+}
+
+fn general_pty_size(state: &AppState, term_cols: u16, term_rows: u16) -> (u16, u16) {
+    pane_pty_size(state, term_cols, term_rows, PaneId::General)
+}
+
+fn pane_pty_size(state: &AppState, term_cols: u16, term_rows: u16, pane_id: PaneId) -> (u16, u16) {
+    let screen = PaneRect {
+        x: 0,
+        y: 0,
+        width: term_cols,
+        height: term_rows,
+    };
+    let area = match state.fullscreen {
+        Some(id) if id == pane_id => screen,
+        _ => state.splits.pane_rect(screen, pane_id),
+    };
+    (
+        area.width.saturating_sub(2).max(1),
+        area.height.saturating_sub(2 + augustinus_tui::TERMINAL_HEADER_ROWS).max(1),
+    )
+}
+
@@ -0,0 +1,445 @@
+#[cfg(unix)]
+mod unix {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+
+    use augustinus_app::PaneId;
+    use augustinus_pty::{PtyBuilder, PtySession};
+    use augustinus_runtime::{
+        LocalPtys, ManualClock, PtyFactory, Runtime, RuntimeBuilder, ScriptedEvents,
+    };
+    use augustinus_store::config::{
+        AgentConfig, AppConfig, KeysConfig, Language, PaneConfig, SplitsConfig,
+    };
+    use augustinus_store::db::Store;
+    use crossterm::event::KeyCode;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    /// `/bin/sh` in GENERAL and `/bin/cat` as the agent, in English.
+    fn test_config() -> AppConfig {
+        AppConfig {
+            language: Language::En,
+            shell: "/bin/sh".into(),
+            git_repo: None,
+            agents_cmd: Some(vec!["/bin/cat".into()]),
+            general: PaneConfig::default(),
+            agent_pane: PaneConfig::default(),
+            agents: Vec::new(),
+            persistent_sessions: false,
+            keys: KeysConfig::default(),
+            splits: SplitsConfig::default(),
+        }
+    }
+
+    fn start() -> (Runtime<TestBackend>, ScriptedEvents, ManualClock) {
+        start_with(test_config())
+    }
+
+    fn start_with(config: AppConfig) -> (Runtime<TestBackend>, ScriptedEvents, ManualClock) {
+        start_with_ptys(config, LocalPtys)
+    }
+
+    fn start_with_ptys(
+        config: AppConfig,
+        ptys: impl PtyFactory + 'static,
+    ) -> (Runtime<TestBackend>, ScriptedEvents, ManualClock) {
+        let events = ScriptedEvents::new();
+        let clock = ManualClock::new();
+        let runtime = RuntimeBuilder::new(config, Store::open_in_memory().unwrap())
+            .events(events.clone())
+            .clock(clock.clone())
+            .ptys(ptys)
+            .host_output(std::io::sink())
+            .start(Terminal::new(TestBackend::new(120, 40)).unwrap())
+            .unwrap();
+        (runtime, events, clock)
+    }
+
+    /// Steps until every scripted event is handled, then renders.
+    fn drain(runtime: &mut Runtime<TestBackend>, events: &ScriptedEvents) {
+        while !events.is_empty() {
+            assert!(!runtime.step().unwrap(), "runtime quit");
+        }
+        runtime.draw().unwrap();
+    }
+
+    fn screen(runtime: &Runtime<TestBackend>) -> String {
+        let buffer = runtime.backend().buffer();
+        buffer.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn focus_start_command_updates_screen_and_store() {
+        let (mut runtime, events, _clock) = start();
+        runtime.draw().unwrap();
+        assert!(screen(&runtime).contains("FOCUS READY"));
+
+        events.push_str(":focus st");
+        drain(&mut runtime, &events);
+        assert!(screen(&runtime).contains(":focus st"));
+
+        events.push_str("art");
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+
+        assert!(runtime.state().focus.is_active());
+        assert!(screen(&runtime).contains("FOCUS ACTIVE"));
+        assert_eq!(runtime.store().count_events().unwrap(), 1);
+    }
+
+    #[test]
+    fn focus_stop_records_time_from_the_clock() {
+        let (mut runtime, events, clock) = start();
+
+        events.push_str(":focus start");
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+        clock.advance(Duration::from_secs(90));
+        events.push_str(":focus stop");
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+
+        assert!(!runtime.state().focus.is_active());
+        assert!(screen(&runtime).contains("FOCUS READY"));
+        assert_eq!(runtime.store().count_events().unwrap(), 2);
+        let today = chrono::Local::now().date_naive();
+        assert_eq!(runtime.store().focus_seconds_for_day(today).unwrap(), 90);
+    }
+
+    #[test]
+    fn typed_command_runs_in_the_general_shell() {
+        let (mut runtime, events, _clock) = start();
+
+        focus_general(&mut runtime, &events);
+        events.push_key(KeyCode::Enter);
+        events.push_str("echo abc$((1+1))xyz");
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+
+        let deadline = Instant::now() + Duration::from_millis(2000);
+        while !screen(&runtime).contains("abc2xyz") {
+            if Instant::now() >= deadline {
+                panic!("shell output not shown:\n{}", runtime.state().general().screen.contents());
+            }
+            std::thread::sleep(Duration::from_millis(10));
+            runtime.step().unwrap();
+            runtime.draw().unwrap();
+        }
+    }
+
+    fn focus_general(runtime: &mut Runtime<TestBackend>, events: &ScriptedEvents) {
+        for _ in 0..4 {
+            if runtime.state().focused == PaneId::General {
+                break;
+            }
+            events.push_key(KeyCode::Tab);
+            drain(runtime, events);
+        }
+        assert_eq!(runtime.state().focused, PaneId::General);
+    }
+
+    #[test]
+    fn shell_integration_counts_commands() {
+        if !std::path::Path::new("/bin/bash").exists() {
+            return;
+        }
+        let snippet = concat!(env!("CARGO_MANIFEST_DIR"), "/../../shell/augustinus.bash");
+        let (mut runtime, events, _clock) = start_with(AppConfig {
+            shell: "/bin/bash".into(),
+            general: PaneConfig {
+                args: vec!["--noprofile".into(), "--rcfile".into(), snippet.into(), "-i".into()],
+                ..PaneConfig::default()
+            },
+            ..test_config()
+        });
+
+        focus_general(&mut runtime, &events);
+        events.push_key(KeyCode::Enter);
+        for command in ["true", "", "false"] {
+            events.push_str(command);
+            events.push_key(KeyCode::Enter);
+        }
+        drain(&mut runtime, &events);
+
+        let deadline = Instant::now() + Duration::from_millis(3000);
+        while runtime.state().commands.today < 2 {
+            if Instant::now() >= deadline {
+                panic!("commands not counted:\n{}", runtime.state().general().screen.contents());
+            }
+            std::thread::sleep(Duration::from_millis(10));
+            runtime.step().unwrap();
+        }
+        let today = chrono::Local::now().date_naive();
+        assert_eq!(runtime.store().command_counts_for_day(today).unwrap(), (2, 1));
+        runtime.draw().unwrap();
+        assert!(screen(&runtime).contains("2 today / 1 failed"));
+    }
+
+    #[test]
+    fn quit_command_quits_and_typos_are_reported() {
+        let (mut runtime, events, _clock) = start();
+
+        events.push_str(":focsu start");
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+        assert!(screen(&runtime).contains("unknown command"));
+        assert_eq!(runtime.store().count_events().unwrap(), 0);
+
+        events.push_key(KeyCode::Esc);
+        events.push_str(":q");
+        drain(&mut runtime, &events);
+        events.push_key(KeyCode::Enter);
+        assert!(runtime.step().unwrap());
+    }
+
+    #[test]
+    fn submitted_commands_are_saved_and_recalled() {
+        let (mut runtime, events, _clock) = start();
+
+        events.push_str(":help ta");
+        events.push_key(KeyCode::Tab);
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+        assert!(screen(&runtime).contains(":tab new|close|next|prev"));
+        assert_eq!(runtime.store().command_history(10).unwrap(), ["help tab"]);
+
+        events.push_key(KeyCode::Esc);
+        events.push_str(":");
+        events.push_key(KeyCode::Up);
+        drain(&mut runtime, &events);
+        assert_eq!(runtime.state().command.as_deref(), Some("help tab"));
+    }
+
+    #[test]
+    fn ctrl_c_quits() {
+        let (mut runtime, events, _clock) = start();
+
+        events.push_key(crossterm::event::KeyEvent::new(
+            KeyCode::Char('c'),
+            crossterm::event::KeyModifiers::CONTROL,
+        ));
+        assert!(runtime.step().unwrap());
+    }
+
+    fn ctrl(ch: char) -> crossterm::event::KeyEvent {
+        crossterm::event::KeyEvent::new(KeyCode::Char(ch), crossterm::event::KeyModifiers::CONTROL)
+    }
+
+    #[test]
+    fn configured_sequences_run_from_a_locked_terminal() {
+        let mut keys = KeysConfig::default();
+        keys.terminal.insert("Ctrl-a f".into(), "fullscreen".into());
+        keys.terminal.insert("Ctrl-a t".into(), ":tab new".into());
+        keys.terminal.insert("; ;".into(), "exit-terminal".into());
+        keys.app.insert("Ctrl-c".into(), "none".into());
+        keys.app.insert("Ctrl-q".into(), "quit".into());
+        let (mut runtime, events, _clock) = start_with(AppConfig { keys, ..test_config() });
+
+        focus_general(&mut runtime, &events);
+        events.push_key(KeyCode::Enter);
+        events.push_key(ctrl('a'));
+        drain(&mut runtime, &events);
+        assert!(screen(&runtime).contains("Ctrl-a …"));
+
+        events.push_str("f");
+        events.push_key(ctrl('a'));
+        events.push_str("t");
+        drain(&mut runtime, &events);
+        assert!(!screen(&runtime).contains("Ctrl-a …"));
+        assert_eq!(runtime.state().fullscreen, Some(PaneId::General));
+        assert_eq!(runtime.state().general_tabs.len(), 2);
+
+        // `;` starts a sequence, so `;e` is held back and then typed.
+        events.push_str("echo p$((1+1))q;echo r$((2+2))s");
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+        let deadline = Instant::now() + Duration::from_millis(2000);
+        while !screen(&runtime).contains("r4s") {
+            if Instant::now() >= deadline {
+                panic!("held keys not typed:\n{}", runtime.state().general().screen.contents());
+            }
+            std::thread::sleep(Duration::from_millis(10));
+            runtime.step().unwrap();
+            runtime.draw().unwrap();
+        }
+
+        events.push_str(";;");
+        events.push_key(ctrl('c'));
+        drain(&mut runtime, &events);
+        events.push_key(ctrl('q'));
+        assert!(runtime.step().unwrap());
+    }
+
+    #[test]
+    fn invalid_waiting_patterns_are_reported() {
+        let agent = AgentConfig {
+            name: "cat".into(),
+            command: vec!["/bin/cat".into()],
+            cwd: None,
+            waiting_patterns: vec!["ok$".into(), "(unclosed".into()],
+        };
+        let (mut runtime, _events, _clock) = start_with(AppConfig {
+            agents: vec![agent],
+            ..test_config()
+        });
+        runtime.draw().unwrap();
+        assert!(screen(&runtime).contains(r#"agent cat: waiting pattern "(unclosed" is not"#));
+        assert_eq!(runtime.state().agents().name.as_deref(), Some("cat"));
+    }
+
+    #[test]
+    fn missing_agents_get_an_exited_tab_with_the_reason() {
+        let agent = |name: &str, program: &str| AgentConfig {
+            name: name.into(),
+            command: vec![program.into()],
+            cwd: None,
+            waiting_patterns: Vec::new(),
+        };
+        let (mut runtime, events, _clock) = start_with(AppConfig {
+            agents: vec![agent("cat", "/bin/cat"), agent("ghost", "/nonexistent/ghost")],
+            ..test_config()
+        });
+        let tabs: Vec<_> = runtime.state().agent_tabs.iter().collect();
+        assert_eq!(tabs.len(), 2);
+        assert_eq!(tabs[0].process.exit_code(), None);
+        assert_eq!(tabs[1].name.as_deref(), Some("ghost"));
+        assert_eq!(tabs[1].process.start_error(), Some("/nonexistent/ghost is not on PATH"));
+
+        events.push_str(":agent ghost");
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+        assert!(screen(&runtime).contains("[could not start: /nonexistent/ghost is not on PATH]"));
+    }
+
+    #[test]
+    fn bad_key_tables_are_reported_and_defaults_kept() {
+        let mut keys = KeysConfig::default();
+        keys.app.insert("x y".into(), "nope".into());
+        let (mut runtime, events, _clock) = start_with(AppConfig { keys, ..test_config() });
+        runtime.draw().unwrap();
+        assert!(screen(&runtime).contains(r#"[keys.app] "x y": cannot bind "nope" in this mode"#));
+
+        events.push_key(KeyCode::Esc);
+        events.push_str("l");
+        drain(&mut runtime, &events);
+        assert_eq!(runtime.state().focused, PaneId::General);
+    }
+
+    #[test]
+    fn pty_sizes_follow_the_configured_splits() {
+        let (mut runtime, _events, _clock) = start_with(AppConfig {
+            splits: SplitsConfig {
+                rows: Some(75),
+                top: Some(30),
+                bottom: None,
+            },
+            ..test_config()
+        });
+        runtime.draw().unwrap();
+        let general = runtime.state().layout.pane_area(PaneId::General).unwrap();
+        assert_eq!((general.width, general.height), (84, 30));
+
+        let deadline = Instant::now() + Duration::from_millis(2000);
+        while runtime.state().general().screen.cols() != 82 {
+            if Instant::now() >= deadline {
+                panic!("pty not sized from the splits");
+            }
+            std::thread::sleep(Duration::from_millis(10));
+            runtime.step().unwrap();
+        }
+        assert_eq!(runtime.state().general().screen.rows(), 26);
+        assert_eq!(runtime.state().agents().screen.cols(), 58);
+    }
+
+    /// Starts local PTYs until `fail` is set.
+    #[derive(Clone, Default)]
+    struct FlakyPtys {
+        fail: Rc<Cell<bool>>,
+    }
+
+    impl PtyFactory for FlakyPtys {
+        fn spawn(&self, builder: PtyBuilder, key: &str) -> anyhow::Result<PtySession> {
+            if self.fail.get() {
+                anyhow::bail!("out of ptys");
+            }
+            LocalPtys.spawn(builder, key)
+        }
+    }
+
+    #[test]
+    fn failed_respawn_is_reported_and_retried() {
+        let ptys = FlakyPtys::default();
+        let (mut runtime, events, _clock) = start_with_ptys(test_config(), ptys.clone());
+        ptys.fail.set(true);
+
+        events.push_str(":respawn general");
+        events.push_key(KeyCode::Enter);
+        drain(&mut runtime, &events);
+        runtime.step().unwrap();
+        runtime.draw().unwrap();
+
+        assert!(screen(&runtime).contains("respawn general: spawn shell: out of ptys"));
+        assert!(runtime.state().general().process.restart_in().is_some());
+    }
+
+    #[test]
+    fn idle_default_layout_is_not_redrawn_every_tick() {
+        let (mut runtime, _events, clock) = start();
+        let deadline = Instant::now() + Duration::from_millis(2000);
+        while runtime.state().general().screen.contents().trim().is_empty() {
+            if Instant::now() >= deadline {
+                panic!("shell prompt not shown");
+            }
+            std::thread::sleep(Duration::from_millis(10));
+            runtime.step().unwrap();
+        }
+        // Let the quote finish typing.
+        clock.advance(Duration::from_secs(5));
+        runtime.step().unwrap();
+        assert!(!runtime.state().motivation.is_animating());
+        runtime.draw().unwrap();
+
+        let frames = runtime.frame_count();
+        for _ in 0..2 {
+            clock.advance(Duration::from_millis(40));
+            runtime.step().unwrap();
+        }
+        assert_eq!(runtime.frame_count(), frames, "idle ticks redrew the screen");
+
+        // The ticker still moves, just at a lower frame rate.
+        clock.advance(Duration::from_millis(40));
+        runtime.step().unwrap();
+        runtime.step().unwrap();
+        assert_eq!(runtime.frame_count(), frames + 1);
+    }
+
+    #[test]
+    fn untracked_pointer_motion_does_not_redraw() {
+        let (mut runtime, events, clock) = start();
+        let deadline = Instant::now() + Duration::from_millis(2000);
+        while runtime.state().general().screen.contents().trim().is_empty() {
+            if Instant::now() >= deadline {
+                panic!("shell prompt not shown");
+            }
+            std::thread::sleep(Duration::from_millis(10));
+            runtime.step().unwrap();
+        }
+        clock.advance(Duration::from_secs(5));
+        runtime.step().unwrap();
+        runtime.draw().unwrap();
+
+        let frames = runtime.frame_count();
+        for column in 0..20 {
+            events.push(crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
+                kind: crossterm::event::MouseEventKind::Moved,
+                column,
+                row: 5,
+                modifiers: crossterm::event::KeyModifiers::NONE,
+            }));
+            runtime.step().unwrap();
+        }
+        assert!(events.is_empty());
+        assert_eq!(runtime.frame_count(), frames, "pointer motion redrew the screen");
+    }
+}
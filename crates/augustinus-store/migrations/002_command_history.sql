@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS command_history (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  ts INTEGER NOT NULL,
+  command TEXT NOT NULL
+);
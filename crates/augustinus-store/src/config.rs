@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     fs,
     path::{Path, PathBuf},
 };
@@ -11,7 +12,120 @@ pub struct AppConfig {
     pub language: Language,
     pub shell: String,
     pub git_repo: Option<String>,
+    /// Single-agent shorthand, used when no `[[agents]]` is configured.
     pub agents_cmd: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "PaneConfig::is_empty")]
+    pub general: PaneConfig,
+    /// Settings shared by every agent's PTY.
+    #[serde(default, skip_serializing_if = "PaneConfig::is_empty")]
+    pub agent_pane: PaneConfig,
+    /// The `[[agents]]` entries, each run in its own PTY in AI AGENTS.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<AgentConfig>,
+    /// Run shells and agents inside a background daemon, so they survive
+    /// the UI quitting or crashing.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub persistent_sessions: bool,
+    #[serde(default, skip_serializing_if = "KeysConfig::is_empty")]
+    pub keys: KeysConfig,
+    #[serde(default, skip_serializing_if = "SplitsConfig::is_default")]
+    pub splits: SplitsConfig,
+}
+
+/// One `[[agents]]` entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentConfig {
+    pub name: String,
+    /// Program and arguments, e.g. `["codex", "--full-auto"]`.
+    pub command: Vec<String>,
+    /// Overrides `[agent_pane].cwd`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Regexes that mean the agent waits for the user when they match its
+    /// screen text (multi-line, so `^`/`$` anchor at lines), e.g. an
+    /// approval question.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waiting_patterns: Vec<String>,
+}
+
+/// Agent CLIs offered in the picker when no configured agent is installed.
+pub const KNOWN_AGENT_CLIS: &[&str] = &["codex", "claude", "gemini", "aider", "opencode", "goose"];
+
+/// How a PTY pane starts its child: the `[general]` and `[agent_pane]`
+/// tables.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaneConfig {
+    /// Working directory; falls back to `git_repo`, then the app's own cwd.
+    /// A leading `~` is expanded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Extra arguments, e.g. `["-l"]` for a login shell. For the agents pane
+    /// they follow `agents_cmd`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colorterm: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Alert when the pane is out of view and has printed nothing for this
+    /// many seconds after earlier output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub silence_alert_secs: Option<u64>,
+}
+
+impl PaneConfig {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// The `[keys.app]`, `[keys.terminal]` and `[keys.command]` tables. Each maps
+/// a key sequence like `"Ctrl-a f"` to an action name, a `:` command line or
+/// `"none"`, on top of the default bindings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeysConfig {
+    /// While no terminal is locked.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub app: BTreeMap<String, String>,
+    /// Inside a locked terminal; keys that are not bound reach its program.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub terminal: BTreeMap<String, String>,
+    /// At the `:` prompt.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub command: BTreeMap<String, String>,
+}
+
+impl KeysConfig {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// The `[splits]` table: how the 2x2 grid is divided, in percent; a
+/// missing value keeps the app's default. Saved once panes stop being
+/// resized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SplitsConfig {
+    /// Height of the top row.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<u16>,
+    /// Width of MOTIVATION, beside GENERAL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top: Option<u16>,
+    /// Width of AI AGENTS, beside STATS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bottom: Option<u16>,
+}
+
+impl SplitsConfig {
+    pub fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
 }
 
 impl AppConfig {
@@ -49,6 +163,49 @@ impl AppConfig {
     pub fn path() -> Result<PathBuf> {
         config_path()
     }
+
+    /// The configured agents: `[[agents]]` entries, else `agents_cmd`, else
+    /// `codex`; the last two followed by `[agent_pane].args`.
+    pub fn agent_configs(&self) -> Vec<AgentConfig> {
+        if !self.agents.is_empty() {
+            return self.agents.clone();
+        }
+        let mut command = self
+            .agents_cmd
+            .clone()
+            .filter(|cmd| !cmd.is_empty())
+            .unwrap_or_else(|| vec!["codex".to_string()]);
+        command.extend(self.agent_pane.args.iter().cloned());
+        let name = Path::new(&command[0])
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| command[0].clone());
+        vec![AgentConfig {
+            name,
+            command,
+            cwd: None,
+            waiting_patterns: Vec::new(),
+        }]
+    }
+
+    /// Directory a pane's child should start in.
+    pub fn pane_cwd(&self, pane: &PaneConfig) -> Option<PathBuf> {
+        pane.cwd
+            .as_deref()
+            .or(self.git_repo.as_deref())
+            .map(expand_home)
+    }
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    let home = std::env::var_os("HOME");
+    match (path.strip_prefix('~'), home) {
+        (Some(""), Some(home)) => PathBuf::from(home),
+        (Some(rest), Some(home)) if rest.starts_with('/') => {
+            Path::new(&home).join(rest.trim_start_matches('/'))
+        }
+        _ => PathBuf::from(path),
+    }
 }
 
 fn config_path() -> Result<PathBuf> {
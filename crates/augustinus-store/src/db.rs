@@ -1,6 +1,7 @@
 use std::{
     fs,
     path::{Path, PathBuf},
+    time::Duration,
 };
 
 use anyhow::{Context, Result};
@@ -8,6 +9,7 @@ use chrono::{Local, NaiveDate};
 use rusqlite::{params, Connection, Error as SqliteError, OpenFlags};
 
 const MIGRATION_001: &str = include_str!("../migrations/001_init.sql");
+const MIGRATION_002: &str = include_str!("../migrations/002_command_history.sql");
 
 pub struct Store {
     conn: Connection,
@@ -61,6 +63,84 @@ impl Store {
         Ok(n)
     }
 
+    /// Records a shell command reported by shell integration as a
+    /// `command` event.
+    pub fn record_command(
+        &self,
+        command: &str,
+        exit_code: Option<i32>,
+        duration: Duration,
+    ) -> Result<()> {
+        let ts = chrono::Utc::now().timestamp();
+        let duration_ms = i64::try_from(duration.as_millis()).unwrap_or(i64::MAX);
+        self.conn
+            .execute(
+                r#"
+INSERT INTO events(ts, kind, payload_json)
+VALUES (?1, 'command', json_object('command', ?2, 'exit_code', ?3, 'duration_ms', ?4))
+"#,
+                params![ts, command, exit_code, duration_ms],
+            )
+            .context("insert command event")?;
+        Ok(())
+    }
+
+    /// Commands recorded on local day `day`, and how many of them failed
+    /// (non-zero exit code).
+    pub fn command_counts_for_day(&self, day: NaiveDate) -> Result<(i64, i64)> {
+        let (start, end) = day_bounds(day);
+        self.conn
+            .query_row(
+                r#"
+SELECT COUNT(*),
+       COALESCE(SUM(json_extract(payload_json, '$.exit_code') <> 0), 0)
+FROM events
+WHERE kind = 'command' AND ts >= ?1 AND ts < ?2
+"#,
+                params![start, end],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .context("count commands")
+    }
+
+    /// Appends a line typed at the `:` prompt, dropping all but the last
+    /// `keep` lines.
+    pub fn add_command_history(&self, command: &str, keep: usize) -> Result<()> {
+        let ts = chrono::Utc::now().timestamp();
+        self.conn
+            .execute(
+                "INSERT INTO command_history(ts, command) VALUES (?1, ?2)",
+                params![ts, command],
+            )
+            .context("insert command history")?;
+        self.conn
+            .execute(
+                r#"
+DELETE FROM command_history
+WHERE id <= (SELECT id FROM command_history ORDER BY id DESC LIMIT 1 OFFSET ?1)
+"#,
+                params![keep as i64],
+            )
+            .context("prune command history")?;
+        Ok(())
+    }
+
+    /// The last `limit` lines typed at the `:` prompt, oldest first.
+    pub fn command_history(&self, limit: usize) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT command FROM command_history ORDER BY id DESC LIMIT ?1")
+            .context("prepare command history")?;
+        let rows = stmt
+            .query_map(params![limit as i64], |row| row.get(0))
+            .context("query command history")?;
+        let mut history = rows
+            .collect::<rusqlite::Result<Vec<String>>>()
+            .context("read command history")?;
+        history.reverse();
+        Ok(history)
+    }
+
     pub fn add_focus_seconds_today(&self, seconds: i64) -> Result<()> {
         let today = Local::now().date_naive();
         self.add_focus_seconds_for_day(today, seconds)
@@ -113,15 +193,7 @@ ON CONFLICT(day) DO UPDATE SET focus_seconds = focus_seconds + excluded.focus_se
     }
 
     pub fn default_db_path() -> Result<PathBuf> {
-        if let Some(xdg) = std::env::var_os("XDG_DATA_HOME") {
-            return Ok(Path::new(&xdg).join("augustinus").join("augustinus.db"));
-        }
-        let home = std::env::var_os("HOME").context("HOME not set")?;
-        Ok(Path::new(&home)
-            .join(".local")
-            .join("share")
-            .join("augustinus")
-            .join("augustinus.db"))
+        Ok(crate::paths::data_dir()?.join("augustinus.db"))
     }
 
     fn configure_on_disk(&self) -> Result<()> {
@@ -133,6 +205,20 @@ ON CONFLICT(day) DO UPDATE SET focus_seconds = focus_seconds + excluded.focus_se
         self.conn
             .execute_batch(MIGRATION_001)
             .context("apply migrations")?;
+        self.conn
+            .execute_batch(MIGRATION_002)
+            .context("apply migrations")?;
         Ok(())
     }
 }
+
+/// Unix timestamps of the start of local day `day` and of the next day.
+fn day_bounds(day: NaiveDate) -> (i64, i64) {
+    let start = |day: NaiveDate| {
+        day.and_hms_opt(0, 0, 0)
+            .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+            .map_or(0, |midnight| midnight.timestamp())
+    };
+    let next = day.succ_opt().map_or(i64::MAX, start);
+    (start(day), next)
+}
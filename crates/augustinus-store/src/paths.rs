@@ -0,0 +1,34 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// `$XDG_DATA_HOME/augustinus`, falling back to `~/.local/share/augustinus`.
+pub fn data_dir() -> Result<PathBuf> {
+    if let Some(xdg) = std::env::var_os("XDG_DATA_HOME") {
+        return Ok(Path::new(&xdg).join("augustinus"));
+    }
+    let home = std::env::var_os("HOME").context("HOME not set")?;
+    Ok(Path::new(&home)
+        .join(".local")
+        .join("share")
+        .join("augustinus"))
+}
+
+/// Where terminal session recordings (`.cast` files) are written.
+pub fn recordings_dir() -> Result<PathBuf> {
+    Ok(data_dir()?.join("recordings"))
+}
+
+/// Directory for the PTY daemon's socket: `$XDG_RUNTIME_DIR/augustinus`,
+/// falling back to `run` under [`data_dir`].
+pub fn runtime_dir() -> Result<PathBuf> {
+    if let Some(xdg) = std::env::var_os("XDG_RUNTIME_DIR") {
+        return Ok(Path::new(&xdg).join("augustinus"));
+    }
+    Ok(data_dir()?.join("run"))
+}
+
+/// Socket the PTY daemon listens on.
+pub fn pty_socket() -> Result<PathBuf> {
+    Ok(runtime_dir()?.join("pty.sock"))
+}
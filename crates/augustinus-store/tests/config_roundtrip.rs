@@ -1,4 +1,4 @@
-use augustinus_store::config::{AppConfig, Language};
+use augustinus_store::config::{AppConfig, KeysConfig, Language, PaneConfig, SplitsConfig};
 
 #[test]
 fn config_roundtrips_toml() {
@@ -7,8 +7,142 @@ fn config_roundtrips_toml() {
         shell: "/bin/bash".into(),
         git_repo: None,
         agents_cmd: None,
+        general: PaneConfig::default(),
+        agent_pane: PaneConfig::default(),
+        agents: Vec::new(),
+        persistent_sessions: false,
+        keys: KeysConfig::default(),
+        splits: SplitsConfig::default(),
     };
     let toml = c.to_toml_string();
     let parsed = AppConfig::from_toml_str(&toml).unwrap();
     assert_eq!(parsed.language, Language::Ja);
 }
+
+#[test]
+fn pane_sections_roundtrip_and_are_optional() {
+    let input = r#"
+language = "en"
+shell = "/bin/zsh"
+git_repo = "/srv/project"
+persistent_sessions = true
+
+[general]
+args = ["-l"]
+colorterm = "truecolor"
+
+[general.env]
+EDITOR = "vim"
+
+[agent_pane]
+cwd = "/srv/project/app"
+term = "xterm"
+silence_alert_secs = 30
+"#;
+    let parsed = AppConfig::from_toml_str(input).unwrap();
+    assert_eq!(parsed.general.args, vec!["-l".to_string()]);
+    assert_eq!(parsed.general.env.get("EDITOR").map(String::as_str), Some("vim"));
+    assert_eq!(parsed.agent_pane.term.as_deref(), Some("xterm"));
+    assert_eq!(parsed.agent_pane.silence_alert_secs, Some(30));
+    assert!(parsed.persistent_sessions);
+    assert_eq!(AppConfig::from_toml_str(&parsed.to_toml_string()).unwrap(), parsed);
+
+    let bare = AppConfig::from_toml_str("language = \"en\"\nshell = \"/bin/sh\"\n").unwrap();
+    assert!(bare.general.is_empty());
+    assert!(!bare.to_toml_string().contains("[general]"));
+    assert!(!bare.persistent_sessions);
+    assert!(!bare.to_toml_string().contains("persistent_sessions"));
+}
+
+#[test]
+fn pane_cwd_falls_back_to_git_repo() {
+    let mut parsed = AppConfig::from_toml_str(
+        "language = \"en\"\nshell = \"/bin/sh\"\ngit_repo = \"/srv/project\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        parsed.pane_cwd(&parsed.general),
+        Some("/srv/project".into())
+    );
+    parsed.agent_pane.cwd = Some("/tmp".into());
+    assert_eq!(parsed.pane_cwd(&parsed.agent_pane), Some("/tmp".into()));
+    parsed.git_repo = None;
+    assert_eq!(parsed.pane_cwd(&parsed.general), None);
+}
+
+#[test]
+fn agents_fall_back_to_agents_cmd_then_codex() {
+    let input = r#"
+language = "en"
+shell = "/bin/sh"
+
+[[agents]]
+name = "codex"
+command = ["codex", "--full-auto"]
+
+[[agents]]
+name = "aider"
+command = ["aider"]
+cwd = "~/src"
+waiting_patterns = ["^> $", "Allow .*\\?"]
+"#;
+    let parsed = AppConfig::from_toml_str(input).unwrap();
+    let names: Vec<_> = parsed.agent_configs().into_iter().map(|agent| agent.name).collect();
+    assert_eq!(names, ["codex", "aider"]);
+    assert_eq!(parsed.agents[1].cwd.as_deref(), Some("~/src"));
+    assert_eq!(parsed.agents[1].waiting_patterns, ["^> $", "Allow .*\\?"]);
+    assert_eq!(AppConfig::from_toml_str(&parsed.to_toml_string()).unwrap(), parsed);
+
+    let mut bare = AppConfig::from_toml_str("language = \"en\"\nshell = \"/bin/sh\"\n").unwrap();
+    assert_eq!(bare.agent_configs()[0].command, ["codex"]);
+    bare.agents_cmd = Some(vec!["/opt/bin/claude".into(), "-c".into()]);
+    assert_eq!(bare.agent_configs()[0].name, "claude");
+    bare.agent_pane.args = vec!["--resume".into()];
+    assert_eq!(bare.agent_configs()[0].command, ["/opt/bin/claude", "-c", "--resume"]);
+    assert!(!bare.to_toml_string().contains("[[agents]]"));
+}
+
+#[test]
+fn keys_tables_roundtrip_and_are_optional() {
+    let input = r#"
+language = "en"
+shell = "/bin/sh"
+
+[keys.app]
+"Ctrl-c" = "none"
+"g t" = ":tab next"
+
+[keys.terminal]
+"Ctrl-a f" = "fullscreen"
+"#;
+    let parsed = AppConfig::from_toml_str(input).unwrap();
+    assert_eq!(parsed.keys.app.get("g t").map(String::as_str), Some(":tab next"));
+    assert_eq!(parsed.keys.terminal.len(), 1);
+    assert!(parsed.keys.command.is_empty());
+    assert_eq!(AppConfig::from_toml_str(&parsed.to_toml_string()).unwrap(), parsed);
+
+    let bare = AppConfig::from_toml_str("language = \"en\"\nshell = \"/bin/sh\"\n").unwrap();
+    assert!(bare.keys.is_empty());
+    assert!(!bare.to_toml_string().contains("keys"));
+}
+
+#[test]
+fn splits_roundtrip_and_are_optional() {
+    let parsed = AppConfig::from_toml_str(
+        "language = \"en\"\nshell = \"/bin/sh\"\n\n[splits]\nrows = 65\nbottom = 70\n",
+    )
+    .unwrap();
+    assert_eq!(
+        parsed.splits,
+        SplitsConfig {
+            rows: Some(65),
+            top: None,
+            bottom: Some(70),
+        }
+    );
+    assert_eq!(AppConfig::from_toml_str(&parsed.to_toml_string()).unwrap(), parsed);
+
+    let bare = AppConfig::from_toml_str("language = \"en\"\nshell = \"/bin/sh\"\n").unwrap();
+    assert!(bare.splits.is_default());
+    assert!(!bare.to_toml_string().contains("[splits]"));
+}
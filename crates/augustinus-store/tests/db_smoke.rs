@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use chrono::Local;
 
 use augustinus_store::db::Store;
@@ -34,3 +36,45 @@ fn streak_counts_consecutive_days_ending_today() {
     let streak = store.streak_days_ending_today().unwrap();
     assert_eq!(streak, 2);
 }
+
+#[test]
+fn counts_todays_commands_and_failures() {
+    let store = Store::open_in_memory().unwrap();
+    store
+        .record_command("cargo test", Some(0), Duration::from_millis(1500))
+        .unwrap();
+    store
+        .record_command("echo \"quoted\" \\ path", Some(2), Duration::ZERO)
+        .unwrap();
+    store.record_command("sleep 1", None, Duration::ZERO).unwrap();
+    store.insert_event("focus_start", "{}").unwrap();
+
+    let today = Local::now().date_naive();
+    assert_eq!(store.command_counts_for_day(today).unwrap(), (3, 1));
+    let yesterday = today.pred_opt().unwrap();
+    assert_eq!(store.command_counts_for_day(yesterday).unwrap(), (0, 0));
+}
+
+#[test]
+fn command_history_returns_the_latest_lines_oldest_first() {
+    let store = Store::open_in_memory().unwrap();
+    assert!(store.command_history(10).unwrap().is_empty());
+    for command in ["help", "tab new", "focus start", "agent \"claude code\""] {
+        store.add_command_history(command, 10).unwrap();
+    }
+
+    assert_eq!(
+        store.command_history(3).unwrap(),
+        vec!["tab new", "focus start", "agent \"claude code\""]
+    );
+}
+
+#[test]
+fn command_history_keeps_only_the_latest_lines() {
+    let store = Store::open_in_memory().unwrap();
+    for command in ["help", "tab new", "focus start", "quit"] {
+        store.add_command_history(command, 2).unwrap();
+    }
+
+    assert_eq!(store.command_history(10).unwrap(), vec!["focus start", "quit"]);
+}
@@ -0,0 +1,8 @@
+//! Plain terminal data shared by the PTY sessions that produce it and the
+//! app state and UI that consume it.
+
+mod mark;
+mod screen;
+
+pub use mark::{ShellMark, ShellMarkKind};
+pub use screen::{TermCell, TermColor, TerminalScreen};
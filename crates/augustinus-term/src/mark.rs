@@ -0,0 +1,23 @@
+use std::time::Instant;
+
+/// A shell integration mark (OSC 133). `line` counts every line the terminal
+/// has shown, so it stays put when old scrollback is dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShellMark {
+    pub kind: ShellMarkKind,
+    pub line: u64,
+    pub col: u16,
+    pub at: Instant,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShellMarkKind {
+    /// `OSC 133;A`: the prompt is about to be drawn.
+    PromptStart,
+    /// `OSC 133;B`: the prompt ended; what follows is typed by the user.
+    CommandStart,
+    /// `OSC 133;C`: the command was submitted and its output begins.
+    OutputStart { command: String },
+    /// `OSC 133;D[;code]`: the command finished.
+    CommandFinished { exit_code: Option<i32> },
+}
@@ -0,0 +1,110 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TermColor {
+    #[default]
+    Default,
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TermCell {
+    pub symbol: String,
+    pub fg: TermColor,
+    pub bg: TermColor,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub inverse: bool,
+    /// Right half of a double-width character; the renderer skips it.
+    pub wide_continuation: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TerminalScreen {
+    rows: u16,
+    cols: u16,
+    cells: Vec<TermCell>,
+    pub cursor_row: u16,
+    pub cursor_col: u16,
+    pub hide_cursor: bool,
+}
+
+impl TerminalScreen {
+    pub fn new(rows: u16, cols: u16) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: vec![TermCell::default(); rows as usize * cols as usize],
+            cursor_row: 0,
+            cursor_col: 0,
+            hide_cursor: false,
+        }
+    }
+
+    pub fn rows(&self) -> u16 {
+        self.rows
+    }
+
+    pub fn cols(&self) -> u16 {
+        self.cols
+    }
+
+    pub fn cell(&self, row: u16, col: u16) -> Option<&TermCell> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.cells.get(row as usize * self.cols as usize + col as usize)
+    }
+
+    pub fn cell_mut(&mut self, row: u16, col: u16) -> Option<&mut TermCell> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.cells
+            .get_mut(row as usize * self.cols as usize + col as usize)
+    }
+
+    pub fn row_text(&self, row: u16) -> String {
+        self.row_text_range(row, 0, self.cols)
+    }
+
+    /// Text of columns `start..end` of a row, trailing blanks trimmed.
+    pub fn row_text_range(&self, row: u16, start: u16, end: u16) -> String {
+        let mut out = String::new();
+        for col in start..end.min(self.cols) {
+            let Some(cell) = self.cell(row, col) else { break };
+            if cell.wide_continuation {
+                continue;
+            }
+            if cell.symbol.is_empty() {
+                out.push(' ');
+            } else {
+                out.push_str(&cell.symbol);
+            }
+        }
+        out.truncate(out.trim_end().len());
+        out
+    }
+
+    /// Plain text of the whole screen with trailing blank rows removed.
+    pub fn contents(&self) -> String {
+        let mut lines: Vec<String> = (0..self.rows).map(|row| self.row_text(row)).collect();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.cells.iter().all(|cell| cell.symbol.trim().is_empty())
+    }
+
+    /// Index of the last row that holds text or the cursor.
+    pub fn last_used_row(&self) -> u16 {
+        let last_text = (0..self.rows)
+            .rev()
+            .find(|&row| !self.row_text(row).is_empty())
+            .unwrap_or(0);
+        last_text.max(self.cursor_row.min(self.rows.saturating_sub(1)))
+    }
+}
@@ -1,7 +1,7 @@
-use augustinus_app::AppState;
+use augustinus_app::{AppState, CommandFeedback, Completion};
 use ratatui::{
     layout::{Position, Rect},
-    prelude::Stylize,
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -10,6 +10,9 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::theme::Theme;
 
+/// Candidates shown at once in the completion popup.
+const POPUP_ROWS: usize = 8;
+
 pub fn render(frame: &mut Frame<'_>, state: &AppState, theme: &Theme) {
     let Some(buffer) = state.command.as_deref() else {
         return;
@@ -20,18 +23,37 @@ pub fn render(frame: &mut Frame<'_>, state: &AppState, theme: &Theme) {
         return;
     }
 
+    let feedback: Vec<Line> = match &state.command_feedback {
+        Some(CommandFeedback::Error(message)) => vec![Line::from(Span::styled(
+            message.as_str(),
+            theme.base().fg(theme.border_focused).bold(),
+        ))],
+        Some(CommandFeedback::Output(lines)) => lines
+            .iter()
+            .map(|line| Line::from(Span::styled(line.as_str(), theme.base().fg(theme.fg))))
+            .collect(),
+        None => Vec::new(),
+    };
+
     let max_width = area.width.saturating_sub(4);
     let prompt_width = 1 + UnicodeWidthStr::width(buffer);
-    let mut overlay_width = (prompt_width as u16).saturating_add(4);
+    let content_width = feedback
+        .iter()
+        .map(Line::width)
+        .fold(prompt_width, usize::max);
+    let mut overlay_width = (content_width.min(u16::MAX as usize) as u16).saturating_add(4);
     overlay_width = overlay_width.max(30).min(max_width.max(30).min(area.width));
-    let overlay_height = 5u16;
+    // Prompt, hint and a spare row, plus whatever the last command reported.
+    let feedback_rows = (feedback.len() as u16).min(area.height.saturating_sub(7));
+    let overlay_height = 5u16 + feedback_rows;
 
     let overlay = centered_rect(area, overlay_width, overlay_height);
 
     frame.render_widget(Clear, overlay);
 
+    let strings = state.strings();
     let block = Block::default()
-        .title("COMMAND")
+        .title(strings.command_title)
         .borders(Borders::ALL)
         .style(theme.base())
         .border_style(theme.base().fg(theme.accent).bold());
@@ -39,12 +61,18 @@ pub fn render(frame: &mut Frame<'_>, state: &AppState, theme: &Theme) {
     let inner = block.inner(overlay);
     frame.render_widget(block, overlay);
 
+    // Keep the cursor in view: as much as fits before it, then after it.
     let max_buffer_width = inner.width.saturating_sub(1) as usize;
-    let visible_buffer = tail_by_display_width(buffer, max_buffer_width);
+    let cursor = state.command_line.cursor(buffer);
+    let before = tail_by_display_width(&buffer[..cursor], max_buffer_width);
+    let before_width = UnicodeWidthStr::width(before.as_str());
+    let after = head_by_display_width(&buffer[cursor..], max_buffer_width - before_width);
+    let visible_start = cursor - before.len();
 
     let prompt = Line::from(vec![
         Span::styled(":", theme.base().fg(theme.accent).bold()),
-        Span::styled(visible_buffer.as_str(), theme.base().fg(theme.fg)),
+        Span::styled(before.as_str(), theme.base().fg(theme.fg)),
+        Span::styled(after, theme.base().fg(theme.fg)),
     ]);
     frame.render_widget(
         Paragraph::new(prompt),
@@ -58,9 +86,20 @@ pub fn render(frame: &mut Frame<'_>, state: &AppState, theme: &Theme) {
 
     let hint = Line::from(vec![
         Span::styled("Enter", theme.base().fg(theme.accent).bold()),
-        Span::styled(": run  ", theme.base().fg(theme.accent)),
+        Span::styled(
+            format!(": {}  ", strings.command_run),
+            theme.base().fg(theme.accent),
+        ),
+        Span::styled("Tab", theme.base().fg(theme.accent).bold()),
+        Span::styled(
+            format!(": {}  ", strings.command_complete),
+            theme.base().fg(theme.accent),
+        ),
         Span::styled("Esc", theme.base().fg(theme.accent).bold()),
-        Span::styled(": cancel", theme.base().fg(theme.accent)),
+        Span::styled(
+            format!(": {}", strings.command_cancel),
+            theme.base().fg(theme.accent),
+        ),
     ]);
     frame.render_widget(
         Paragraph::new(hint),
@@ -72,11 +111,33 @@ pub fn render(frame: &mut Frame<'_>, state: &AppState, theme: &Theme) {
         },
     );
 
-    let visible_width = UnicodeWidthStr::width(visible_buffer.as_str()) as u16;
+    if feedback_rows > 0 {
+        frame.render_widget(
+            Paragraph::new(feedback),
+            Rect {
+                x: inner.x,
+                y: inner.y.saturating_add(2),
+                width: inner.width,
+                height: feedback_rows,
+            },
+        );
+    }
+
+    if let Some(completion) = state.command_line.completion() {
+        // Under the word being completed, or the prompt's start if that
+        // word scrolled out of view.
+        let word_x = buffer
+            .get(visible_start..completion.start)
+            .map_or(0, UnicodeWidthStr::width) as u16;
+        // The border and padding put the candidates right under the word.
+        let x = inner.x.saturating_add(word_x).saturating_sub(1);
+        render_completions(frame, completion, x, inner.y.saturating_add(1), theme);
+    }
+
     let cursor_x = inner
         .x
         .saturating_add(1)
-        .saturating_add(visible_width)
+        .saturating_add(before_width as u16)
         .min(inner.x.saturating_add(inner.width.saturating_sub(1)));
     frame.set_cursor_position(Position {
         x: cursor_x,
@@ -84,6 +145,68 @@ pub fn render(frame: &mut Frame<'_>, state: &AppState, theme: &Theme) {
     });
 }
 
+/// The candidates in a bordered list whose top-left corner is at `(x, y)`,
+/// scrolled to keep the selected one visible.
+fn render_completions(
+    frame: &mut Frame<'_>,
+    completion: &Completion,
+    x: u16,
+    y: u16,
+    theme: &Theme,
+) {
+    let area = frame.area();
+    let rows = completion.candidates.len().min(POPUP_ROWS);
+    let width = completion
+        .candidates
+        .iter()
+        .map(|candidate| UnicodeWidthStr::width(candidate.as_str()))
+        .max()
+        .unwrap_or(0)
+        .saturating_add(4)
+        .min(area.width as usize) as u16;
+    let height = (rows as u16).saturating_add(2).min(area.bottom().saturating_sub(y));
+    if height < 3 {
+        return;
+    }
+    let popup = Rect {
+        x: x.min(area.right().saturating_sub(width)),
+        y,
+        width,
+        height,
+    };
+    let first = completion
+        .selected
+        .map_or(0, |selected| (selected + 1).saturating_sub(rows));
+    let lines: Vec<Line> = completion
+        .candidates
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(rows)
+        .map(|(index, candidate)| {
+            let style = if completion.selected == Some(index) {
+                theme
+                    .base()
+                    .fg(theme.bg)
+                    .bg(theme.accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                theme.base().fg(theme.fg)
+            };
+            Line::from(Span::styled(format!(" {candidate} "), style))
+        })
+        .collect();
+
+    frame.render_widget(Clear, popup);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .style(theme.base())
+        .border_style(theme.base().fg(theme.accent));
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
 fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
     let width = width.min(area.width);
     let height = height.min(area.height);
@@ -115,3 +238,13 @@ fn tail_by_display_width(s: &str, max_width: usize) -> String {
     }
     chars.into_iter().rev().collect()
 }
+
+fn head_by_display_width(s: &str, max_width: usize) -> String {
+    let mut width = 0usize;
+    s.chars()
+        .take_while(|ch| {
+            width = width.saturating_add(ch.width().unwrap_or(0));
+            width <= max_width
+        })
+        .collect()
+}
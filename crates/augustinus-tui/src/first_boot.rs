@@ -1,17 +1,25 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
-    style::Stylize,
     text::{Line, Text},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
+use augustinus_i18n::Language;
+
 use crate::theme::Theme;
 
-const OPTIONS: &[&str] = &["English", "Français", "日本語"];
+const OPTIONS: &[(&str, Language)] = &[
+    ("English", Language::En),
+    ("Français", Language::Fr),
+    ("日本語", Language::Ja),
+];
 
+/// The picker speaks the highlighted language.
 pub fn render(frame: &mut Frame<'_>, selected_index: usize) {
     let theme = Theme::arctic();
+    let language = OPTIONS.get(selected_index).map_or(Language::En, |(_, language)| *language);
+    let strings = augustinus_i18n::strings(language);
     let area = frame.area();
     frame.render_widget(Block::default().style(theme.base()), area);
 
@@ -25,7 +33,7 @@ pub fn render(frame: &mut Frame<'_>, selected_index: usize) {
         .margin(2)
         .areas(area);
 
-    let title = Paragraph::new("Select language")
+    let title = Paragraph::new(strings.select_language)
         .alignment(Alignment::Center)
         .style(theme.base().fg(theme.fg).bold());
     frame.render_widget(title, title_area);
@@ -33,7 +41,7 @@ pub fn render(frame: &mut Frame<'_>, selected_index: usize) {
     let lines: Vec<Line<'static>> = OPTIONS
         .iter()
         .enumerate()
-        .map(|(i, label)| {
+        .map(|(i, (label, _))| {
             if i == selected_index {
                 Line::from(format!("> {label}")).style(theme.base().fg(theme.fg))
             } else {
@@ -46,14 +54,14 @@ pub fn render(frame: &mut Frame<'_>, selected_index: usize) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Language")
+                .title(strings.language)
                 .style(theme.base().fg(theme.accent)),
         )
         .alignment(Alignment::Left)
         .style(theme.base());
     frame.render_widget(list, list_area);
 
-    let hint = Paragraph::new("j/k to move, Enter to confirm (↑/↓ also works)")
+    let hint = Paragraph::new(strings.language_hint)
         .alignment(Alignment::Center)
         .style(theme.base().fg(theme.accent));
     frame.render_widget(hint, hint_area);
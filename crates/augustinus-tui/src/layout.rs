@@ -1,49 +1,53 @@
-use augustinus_app::{AppState, PaneId};
+use augustinus_app::{AppState, PaneId, PaneRect};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    prelude::Stylize,
+    layout::Rect,
     widgets::{Block, Borders},
     Frame,
 };
 
-use crate::{command_overlay, panes, theme::Theme};
+use crate::{command_overlay, panes, pending_keys, theme::Theme};
 
 pub fn render_root(frame: &mut Frame<'_>, state: &mut AppState) {
     let theme = Theme::arctic();
     frame.render_widget(Block::default().style(theme.base()), frame.area());
+    state.layout.clear();
 
     if let Some(fullscreen) = state.fullscreen {
         render_pane(frame, state, fullscreen, frame.area(), &theme);
+        pending_keys::render(frame, state, &theme);
         command_overlay::render(frame, state, &theme);
         return;
     }
 
-    let [top, bottom] = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .areas(frame.area());
-
-    let [tl, tr] = split_h(top);
-    let [bl, br] = split_h(bottom);
-
-    render_pane(frame, state, PaneId::Motivation, tl, &theme);
-    render_pane(frame, state, PaneId::General, tr, &theme);
-    render_pane(frame, state, PaneId::Agents, bl, &theme);
-    render_pane(frame, state, PaneId::Stats, br, &theme);
+    let screen = pane_rect(frame.area());
+    for id in [PaneId::Motivation, PaneId::General, PaneId::Agents, PaneId::Stats] {
+        let area = state.splits.pane_rect(screen, id);
+        render_pane(frame, state, id, rect(area), &theme);
+    }
+    pending_keys::render(frame, state, &theme);
     command_overlay::render(frame, state, &theme);
 }
 
-fn split_h(area: Rect) -> [Rect; 2] {
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .areas(area)
+pub(crate) fn pane_rect(area: Rect) -> PaneRect {
+    PaneRect {
+        x: area.x,
+        y: area.y,
+        width: area.width,
+        height: area.height,
+    }
+}
+
+fn rect(area: PaneRect) -> Rect {
+    Rect::new(area.x, area.y, area.width, area.height)
 }
 
 fn render_pane(frame: &mut Frame<'_>, state: &mut AppState, id: PaneId, area: Rect, theme: &Theme) {
+    state.layout.set_pane(id, pane_rect(area));
     let focused = state.focused == id;
-    let title = panes::title(id);
-    let border_color = if focused {
+    let title = panes::title_line(id, state, theme);
+    let border_color = if panes::is_flashing(id, state) {
+        theme.alert
+    } else if focused {
         theme.border_focused
     } else {
         theme.border_unfocused
@@ -2,6 +2,7 @@ mod command_overlay;
 mod first_boot;
 mod layout;
 mod panes;
+mod pending_keys;
 mod splash;
 mod theme;
 pub mod widgets;
@@ -9,6 +10,9 @@ pub mod widgets;
 use augustinus_app::AppState;
 use ratatui::Frame;
 
+/// Rows above the embedded terminal in the GENERAL and AI AGENTS panes.
+pub const TERMINAL_HEADER_ROWS: u16 = 2;
+
 pub fn render(frame: &mut Frame<'_>, state: &mut AppState) {
     layout::render_root(frame, state);
 }
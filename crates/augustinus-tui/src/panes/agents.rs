@@ -1,13 +1,17 @@
 use ratatui::{
-    layout::Alignment,
-    layout::Position,
-    text::{Line, Text},
+    layout::{Alignment, Rect},
+    style::Modifier,
+    text::{Line, Span, Text},
     widgets::{Block, Paragraph},
     Frame,
 };
 
+use crate::layout::pane_rect;
+use crate::panes::terminal_view;
 use crate::theme::Theme;
-use augustinus_app::{AgentsInputMode, AppState, PaneId};
+use crate::TERMINAL_HEADER_ROWS;
+use augustinus_app::{AgentPicker, AgentsInputMode, AppState, PaneId};
+use augustinus_i18n::{fill, Strings};
 
 pub fn render(
     frame: &mut Frame<'_>,
@@ -16,76 +20,120 @@ pub fn render(
     theme: &Theme,
     state: &mut AppState,
 ) {
+    let block = block.style(theme.base());
     let inner = block.inner(area);
+    frame.render_widget(block, area);
 
+    let strings = state.strings();
+    if let Some(picker) = &state.agent_picker {
+        render_picker(frame, inner, picker, strings, theme);
+        return;
+    }
+
+    let tab = state.agents();
+    let name = tab.name.as_deref().unwrap_or(strings.agent_unnamed);
     let mut lines = Vec::new();
-    if state.agents_input_mode == AgentsInputMode::CodexLocked {
+    if let Some(copy) = &tab.copy {
+        lines.push(terminal_view::copy_header(copy, strings, theme));
+        lines.push(Line::from(""));
+    } else if tab.scroll.is_active() {
+        lines.push(terminal_view::scroll_header(&tab.scroll, strings, theme));
+        lines.push(Line::from(""));
+    } else if state.agents_input_mode == AgentsInputMode::CodexLocked {
         lines.push(
-            Line::from("CODEX LOCKED — Esc to return to pane controls")
+            Line::from(fill(strings.agents_locked_hint, &[&name.to_uppercase()]))
                 .style(theme.base().fg(theme.accent)),
         );
         lines.push(Line::from(""));
     } else {
         if state.focused == PaneId::Agents {
             lines.push(
-                Line::from("Enter: control Codex  Esc: return  h/j/k/l Tab: move focus")
+                Line::from(fill(strings.agents_focused_hint, &[&name]))
                     .style(theme.base().fg(theme.accent)),
             );
         } else {
             lines.push(
-                Line::from("Focus with h/j/k/l; Enter to lock; \":\" commands")
-                    .style(theme.base().fg(theme.accent)),
+                Line::from(strings.agents_unfocused_hint).style(theme.base().fg(theme.accent)),
             );
         }
         lines.push(Line::from(""));
     }
 
-    let header_height = lines.len() as u16;
+    if let Some(line) = terminal_view::search_line(&tab.search, strings, theme) {
+        lines[1] = line;
+    }
+    if let Some(line) = terminal_view::exit_line(&tab.process, strings, theme) {
+        lines[1] = line;
+    }
 
-    let contents = if state.agents_screen.is_empty() {
-        "Starting codex…".to_string()
-    } else {
-        state.agents_screen.clone()
+    let header_height = TERMINAL_HEADER_ROWS.min(inner.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines))
+            .style(theme.base())
+            .alignment(Alignment::Left),
+        Rect {
+            height: header_height,
+            ..inner
+        },
+    );
+
+    let screen_area = Rect {
+        y: inner.y + header_height,
+        height: inner.height - header_height,
+        ..inner
     };
 
-    let reserved_height = header_height.saturating_add(2);
-    let max_lines = area.height.saturating_sub(reserved_height) as usize;
-    let all_lines: Vec<&str> = contents.lines().collect();
-    let visible_start = all_lines.len().saturating_sub(max_lines);
-    for line in &all_lines[visible_start..] {
-        lines.push(Line::from(line.to_string()));
+    if tab.process.start_error().is_some() {
+        return;
+    }
+    if tab.screen.is_blank() {
+        frame.render_widget(
+            Paragraph::new(fill(strings.starting_agent, &[&name])).style(theme.base()),
+            screen_area,
+        );
+        return;
     }
 
-    let text = Text::from(lines);
-    let widget = Paragraph::new(text)
-        .block(block)
-        .style(theme.base())
-        .alignment(Alignment::Left);
-    frame.render_widget(widget, area);
-
+    let start_row = terminal_view::render_screen(frame, screen_area, &tab.screen, theme);
+    terminal_view::highlight_matches(frame, screen_area, tab, start_row, theme);
+    terminal_view::highlight_copy(frame, screen_area, tab, start_row, theme);
+    state
+        .layout
+        .set_screen(PaneId::Agents, pane_rect(screen_area), start_row);
     if state.focused == PaneId::Agents && state.agents_input_mode == AgentsInputMode::CodexLocked {
-        let cursor_row = state.agents_cursor_row as usize;
-        let cursor_col = state.agents_cursor_col as u16;
+        terminal_view::set_cursor(frame, screen_area, &state.agents().screen, start_row);
+    }
+}
 
-        let cursor_row_in_view = if cursor_row >= visible_start {
-            (cursor_row - visible_start) as u16
+fn render_picker(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    picker: &AgentPicker,
+    strings: &Strings,
+    theme: &Theme,
+) {
+    let mut lines = vec![
+        Line::from(strings.picker_title).style(theme.base().fg(theme.accent)),
+        Line::from(strings.picker_hint).style(theme.base().fg(theme.accent)),
+        Line::from(""),
+    ];
+    for (index, choice) in picker.choices().iter().enumerate() {
+        let label = format!(" {}. {choice} ", index + 1);
+        let style = if index == picker.selected() {
+            theme
+                .base()
+                .fg(theme.bg)
+                .bg(theme.accent)
+                .add_modifier(Modifier::BOLD)
         } else {
-            0
+            theme.base().fg(theme.fg)
         };
-
-        let cursor_x = inner
-            .x
-            .saturating_add(cursor_col)
-            .min(inner.x.saturating_add(inner.width.saturating_sub(1)));
-        let cursor_y = inner
-            .y
-            .saturating_add(header_height)
-            .saturating_add(cursor_row_in_view)
-            .min(inner.y.saturating_add(inner.height.saturating_sub(1)));
-
-        frame.set_cursor_position(Position {
-            x: cursor_x,
-            y: cursor_y,
-        });
+        lines.push(Line::from(Span::styled(label, style)));
     }
+    frame.render_widget(
+        Paragraph::new(Text::from(lines))
+            .style(theme.base())
+            .alignment(Alignment::Left),
+        area,
+    );
 }
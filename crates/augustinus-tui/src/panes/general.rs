@@ -1,11 +1,14 @@
 use ratatui::{
-    layout::Alignment,
+    layout::{Alignment, Rect},
     text::{Line, Text},
     widgets::{Block, Paragraph},
     Frame,
 };
 
+use crate::layout::pane_rect;
+use crate::panes::terminal_view;
 use crate::theme::Theme;
+use crate::TERMINAL_HEADER_ROWS;
 use augustinus_app::{AppState, GeneralInputMode, PaneId};
 
 pub fn render(
@@ -15,48 +18,78 @@ pub fn render(
     theme: &Theme,
     state: &mut AppState,
 ) {
+    let block = block.style(theme.base());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let strings = state.strings();
+    let tab = state.general();
     let mut lines = Vec::new();
-    if state.general_input_mode == GeneralInputMode::TerminalLocked {
+    if let Some(copy) = &tab.copy {
+        lines.push(terminal_view::copy_header(copy, strings, theme));
+        lines.push(Line::from(""));
+    } else if tab.scroll.is_active() {
+        lines.push(terminal_view::scroll_header(&tab.scroll, strings, theme));
+        lines.push(Line::from(""));
+    } else if state.general_input_mode == GeneralInputMode::TerminalLocked {
         lines.push(
-            Line::from("TERMINAL MODE (locked) — Esc to return to app controls")
-                .style(theme.base().fg(theme.accent)),
+            Line::from(strings.general_locked_hint).style(theme.base().fg(theme.accent)),
         );
         lines.push(Line::from(""));
     } else {
         if state.focused == PaneId::General {
             lines.push(
-                Line::from(
-                    "Enter: enter terminal mode; h/j/k/l Tab: move focus; \":\" commands",
-                )
-                .style(theme.base().fg(theme.accent)),
+                Line::from(strings.general_focused_hint).style(theme.base().fg(theme.accent)),
             );
         } else {
             lines.push(
-                Line::from("Press Enter to fullscreen; Focus with h/j/k/l; \":\" commands")
-                    .style(theme.base().fg(theme.accent)),
+                Line::from(strings.general_unfocused_hint).style(theme.base().fg(theme.accent)),
             );
         }
         lines.push(Line::from(""));
     }
 
-    let contents = if state.general_screen.is_empty() {
-        "Starting shell…".to_string()
-    } else {
-        state.general_screen.clone()
+    if let Some(line) = terminal_view::search_line(&tab.search, strings, theme) {
+        lines[1] = line;
+    }
+    if let Some(line) = terminal_view::exit_line(&tab.process, strings, theme) {
+        lines[1] = line;
+    }
+
+    let header_height = TERMINAL_HEADER_ROWS.min(inner.height);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines))
+            .style(theme.base())
+            .alignment(Alignment::Left),
+        Rect {
+            height: header_height,
+            ..inner
+        },
+    );
+
+    let screen_area = Rect {
+        y: inner.y + header_height,
+        height: inner.height - header_height,
+        ..inner
     };
 
-    let reserved_height = (lines.len() as u16).saturating_add(2);
-    let max_lines = area.height.saturating_sub(reserved_height) as usize;
-    let all_lines: Vec<&str> = contents.lines().collect();
-    let start = all_lines.len().saturating_sub(max_lines);
-    for line in &all_lines[start..] {
-        lines.push(Line::from(line.to_string()));
+    if tab.screen.is_blank() {
+        frame.render_widget(
+            Paragraph::new(strings.starting_shell).style(theme.base()),
+            screen_area,
+        );
+        return;
     }
 
-    let text = Text::from(lines);
-    let widget = Paragraph::new(text)
-        .block(block)
-        .style(theme.base())
-        .alignment(Alignment::Left);
-    frame.render_widget(widget, area);
+    let start_row = terminal_view::render_screen(frame, screen_area, &tab.screen, theme);
+    terminal_view::highlight_matches(frame, screen_area, tab, start_row, theme);
+    terminal_view::highlight_copy(frame, screen_area, tab, start_row, theme);
+    state
+        .layout
+        .set_screen(PaneId::General, pane_rect(screen_area), start_row);
+    if state.focused == PaneId::General
+        && state.general_input_mode == GeneralInputMode::TerminalLocked
+    {
+        terminal_view::set_cursor(frame, screen_area, &state.general().screen, start_row);
+    }
 }
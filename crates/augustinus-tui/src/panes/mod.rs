@@ -2,22 +2,160 @@ mod agents;
 mod general;
 mod motivation;
 mod stats;
+mod terminal_view;
 
-use augustinus_app::PaneId;
-use ratatui::{widgets::Block, Frame};
+use augustinus_app::{ActivityMonitor, AgentStatus, PaneId, TerminalTab};
+use augustinus_i18n::Strings;
+use ratatui::{
+    style::Modifier,
+    text::{Line, Span},
+    widgets::Block,
+    Frame,
+};
 
 use crate::theme::Theme;
 use augustinus_app::AppState;
 
-pub fn title(id: PaneId) -> &'static str {
+pub fn title(id: PaneId, strings: &Strings) -> &'static str {
     match id {
-        PaneId::Motivation => "MOTIVATION",
-        PaneId::General => "GENERAL",
-        PaneId::Agents => "AI AGENTS",
-        PaneId::Stats => "STATS",
+        PaneId::Motivation => strings.pane_motivation,
+        PaneId::General => strings.pane_general,
+        PaneId::Agents => strings.pane_agents,
+        PaneId::Stats => strings.pane_stats,
     }
 }
 
+const TAB_TITLE_MAX: usize = 16;
+const PANE_TITLE_MAX: usize = 32;
+
+/// Pane title. Terminal panes add what the child is running (an agent's
+/// configured name, else its OSC title or foreground process); GENERAL and
+/// AI AGENTS list their tabs once there is more than one, marking exited
+/// ones with `✗`, and a recording pane is marked with `● REC`. Agents show
+/// whether they are busy (`…`) or waiting for input (`?`). Terminals the
+/// user can't see carry bell (`!`), activity (`+`) and silence (`~`) badges.
+pub fn title_line(id: PaneId, state: &AppState, theme: &Theme) -> Line<'static> {
+    let tabs = match id {
+        PaneId::General => Some(&state.general_tabs),
+        PaneId::Agents => Some(&state.agent_tabs),
+        _ => None,
+    };
+    let strings = state.strings();
+    let mut spans = vec![Span::raw(title(id, strings))];
+    if let Some(tabs) = tabs.filter(|tabs| tabs.len() > 1) {
+        spans.push(Span::raw(" "));
+        for (index, tab) in tabs.iter().enumerate() {
+            let mut marks = alert_marks(&tab.activity);
+            if tab.process.exit_code().is_some() {
+                marks.push('✗');
+            } else if id == PaneId::Agents {
+                marks.push(if tab.agent_status.is_busy() { '…' } else { '?' });
+            }
+            let label = match tab_label(tab) {
+                Some(title) => {
+                    format!(" {}:{}{marks} ", index + 1, truncate(title, TAB_TITLE_MAX))
+                }
+                None => format!(" {}{marks} ", index + 1),
+            };
+            if index == tabs.active_index() {
+                spans.push(Span::styled(
+                    label,
+                    theme
+                        .base()
+                        .fg(theme.bg)
+                        .bg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            } else if tab.activity.has_alert() {
+                spans.push(Span::styled(
+                    label,
+                    theme.base().fg(theme.alert).add_modifier(Modifier::BOLD),
+                ));
+            } else {
+                spans.push(Span::styled(label, theme.base().fg(theme.fg)));
+            }
+        }
+    } else if let Some(terminal) = state.terminal(id) {
+        if let Some(title) = tab_label(terminal) {
+            spans.push(Span::styled(
+                format!(" — {}", truncate(title, PANE_TITLE_MAX)),
+                theme.base().fg(theme.accent),
+            ));
+        }
+        if id == PaneId::Agents && terminal.process.exit_code().is_none() {
+            let status = agent_status_label(terminal.agent_status.status(), strings);
+            spans.push(Span::styled(
+                format!(" ({status})"),
+                theme.base().fg(theme.accent),
+            ));
+        }
+        let marks = alert_marks(&terminal.activity);
+        if !marks.is_empty() {
+            spans.push(Span::styled(
+                format!(" {marks}"),
+                theme.base().fg(theme.alert).add_modifier(Modifier::BOLD),
+            ));
+        }
+    }
+    if state
+        .terminal(id)
+        .is_some_and(|terminal| terminal.recording.is_active())
+    {
+        spans.push(Span::styled(
+            " ● REC",
+            theme.base().fg(theme.border_focused).add_modifier(Modifier::BOLD),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Whether any of the pane's terminals is in its post-alert border flash.
+pub fn is_flashing(id: PaneId, state: &AppState) -> bool {
+    match id {
+        PaneId::General => state
+            .general_tabs
+            .iter()
+            .any(|tab| tab.activity.is_flashing()),
+        _ => state
+            .terminal(id)
+            .is_some_and(|terminal| terminal.activity.is_flashing()),
+    }
+}
+
+pub(crate) fn agent_status_label(status: AgentStatus, strings: &Strings) -> &'static str {
+    match status {
+        AgentStatus::Busy => strings.agent_busy,
+        AgentStatus::Waiting => strings.agent_waiting,
+    }
+}
+
+fn tab_label(tab: &TerminalTab) -> Option<&str> {
+    tab.name.as_deref().or(tab.title.as_deref())
+}
+
+fn alert_marks(activity: &ActivityMonitor) -> String {
+    let mut marks = String::new();
+    if activity.has_bell() {
+        marks.push('!');
+    }
+    if activity.has_activity() {
+        marks.push('+');
+    }
+    if activity.has_silence() {
+        marks.push('~');
+    }
+    marks
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut out: String = text.chars().take(max - 1).collect();
+    out.push('…');
+    out
+}
+
 pub fn render(
     frame: &mut Frame<'_>,
     state: &mut AppState,
@@ -9,6 +9,7 @@ use ratatui::{
 use crate::theme::Theme;
 use crate::widgets::big_text::BigText;
 use augustinus_app::{particles::ParticleKind, AppState, Tone, DAILY_FOCUS_GOAL_SECS};
+use augustinus_i18n::fill;
 
 pub fn render(
     frame: &mut Frame<'_>,
@@ -49,26 +50,28 @@ fn render_particles(frame: &mut Frame<'_>, state: &AppState, inner: ratatui::lay
     for p in state.motivation.particles.points() {
         let gx = inner.x.saturating_add(p.x).min(inner.right().saturating_sub(1));
         let gy = inner.y.saturating_add(p.y).min(inner.bottom().saturating_sub(1));
-        if gx < inner.right() && gy < inner.bottom() {
-            if let Some(cell) = buf.cell_mut((gx, gy)) {
-                cell.set_char(p.ch);
-                let style = match p.kind {
-                    ParticleKind::Background => theme.base().fg(theme.accent).add_modifier(Modifier::DIM),
-                    ParticleKind::Burst => theme.base().fg(theme.border_focused).add_modifier(Modifier::BOLD),
-                };
-                cell.set_style(style);
-            }
+        if gx < inner.right()
+            && gy < inner.bottom()
+            && let Some(cell) = buf.cell_mut((gx, gy))
+        {
+            cell.set_char(p.ch);
+            let style = match p.kind {
+                ParticleKind::Background => theme.base().fg(theme.accent).add_modifier(Modifier::DIM),
+                ParticleKind::Burst => theme.base().fg(theme.border_focused).add_modifier(Modifier::BOLD),
+            };
+            cell.set_style(style);
         }
     }
 }
 
 fn render_header(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout::Rect, theme: &Theme) {
-    let tone = state.motivation.tone();
-    let tone_label = match tone {
-        Tone::Brutal => "BRUTAL",
-        Tone::Encouraging => "ENCOURAGING",
-        Tone::Emperor => "EMPEROR",
-    };
+    let strings = state.strings();
+    let tone_label = match state.motivation.tone() {
+        Tone::Brutal => strings.tone_brutal,
+        Tone::Encouraging => strings.tone_encouraging,
+        Tone::Emperor => strings.tone_emperor,
+    }
+    .to_uppercase();
 
     let is_idle = state.motivation.idle.is_idle();
     let idle_style = if is_idle {
@@ -96,12 +99,20 @@ fn render_header(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout:
         Span::styled(tone_label, theme.base().fg(theme.accent)),
         Span::raw(" "),
         Span::styled(
-            if is_idle { "• IDLE" } else { "• LIVE" },
+            if is_idle {
+                strings.badge_idle
+            } else {
+                strings.badge_live
+            },
             idle_style,
         ),
         Span::raw("  "),
         Span::styled(
-            if focus_active { "FOCUS ACTIVE" } else { "FOCUS READY" },
+            if focus_active {
+                strings.badge_focus_active
+            } else {
+                strings.badge_focus_ready
+            },
             focus_style,
         ),
     ]);
@@ -120,15 +131,16 @@ fn render_header(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout:
 }
 
 fn render_banner(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout::Rect, theme: &Theme) {
+    let strings = state.strings();
     let streak_days = state.focus.streak_days();
     let banner = if state.motivation.idle.is_idle() {
-        "WAKE UP".to_string()
+        strings.banner_wake_up.to_string()
     } else if state.focus.is_active() {
-        "LOCK IN".to_string()
+        strings.banner_lock_in.to_string()
     } else if streak_days > 0 {
-        format!("STREAK {streak_days}")
+        fill(strings.banner_streak, &[&streak_days])
     } else {
-        "DEEP WORK".to_string()
+        strings.banner_deep_work.to_string()
     };
 
     let intensity = state.motivation.pulse.intensity_0_to_255();
@@ -167,7 +179,7 @@ fn render_banner(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout:
 fn render_quote(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout::Rect, theme: &Theme) {
     let card = Block::default()
         .borders(Borders::ALL)
-        .title("NOW")
+        .title(state.strings().quote_title)
         .style(theme.base().fg(theme.accent));
 
     let text = state.motivation.typewriter.visible_text();
@@ -182,6 +194,7 @@ fn render_quote(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout::
 }
 
 fn render_stats(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout::Rect, theme: &Theme) {
+    let strings = state.strings();
     let focus_seconds = state.focus.focus_seconds_today();
     let streak_days = state.focus.streak_days();
 
@@ -195,14 +208,20 @@ fn render_stats(frame: &mut Frame<'_>, state: &AppState, area: ratatui::layout::
 
     let stats_text = Text::from(vec![
         Line::from(vec![
-            Span::styled("STREAK ", theme.base().fg(theme.fg).add_modifier(Modifier::BOLD)),
             Span::styled(
-                format!("{streak_days} day(s)"),
+                format!("{} ", strings.label_streak),
+                theme.base().fg(theme.fg).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                fill(strings.days_count, &[&streak_days]),
                 theme.base().fg(theme.accent).add_modifier(Modifier::BOLD),
             ),
         ]),
         Line::from(vec![
-            Span::styled("TODAY ", theme.base().fg(theme.fg).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!("{} ", strings.label_today),
+                theme.base().fg(theme.fg).add_modifier(Modifier::BOLD),
+            ),
             Span::styled(
                 format!("{focus_hms} / {goal_hms}"),
                 theme.base().fg(theme.accent),
@@ -1,4 +1,5 @@
 use augustinus_app::{AppState, DAILY_FOCUS_GOAL_SECS, PaneId, Tone};
+use augustinus_i18n::{fill, Strings};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout},
@@ -7,6 +8,9 @@ use ratatui::{
     widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Wrap},
 };
 
+use unicode_width::UnicodeWidthStr;
+
+use crate::panes::agent_status_label;
 use crate::{theme::Theme, widgets::big_text::BigText};
 
 pub fn render(
@@ -31,7 +35,7 @@ pub fn render(
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(cards_height), Constraint::Min(0)])
         .split(inner);
-    let top = *top_bottom.get(0).unwrap_or(&inner);
+    let top = *top_bottom.first().unwrap_or(&inner);
     let bottom = *top_bottom.get(1).unwrap_or(&inner);
 
     let cards = Layout::default()
@@ -42,7 +46,7 @@ pub fn render(
             Constraint::Percentage(33),
         ])
         .split(top);
-    let c1 = *cards.get(0).unwrap_or(&top);
+    let c1 = *cards.first().unwrap_or(&top);
     let c2 = *cards.get(1).unwrap_or(&top);
     let c3 = *cards.get(2).unwrap_or(&top);
 
@@ -58,21 +62,25 @@ fn render_compact(
     theme: &Theme,
     state: &AppState,
 ) {
+    let strings = state.strings();
     let focus_seconds = state.focus.focus_seconds_today();
     let streak_days = state.focus.streak_days();
     let loc_line = match state.loc_delta {
-        Some(delta) => format!("LOC: +{} / -{}", delta.added, delta.removed),
-        None => "LOC: N/A".to_string(),
+        Some(delta) => fill(strings.compact_loc, &[&delta.added, &delta.removed]),
+        None => strings.compact_loc_unavailable.to_string(),
     };
 
     let text = Text::from(vec![
-        Line::from(format!("Streak: {streak_days} day(s)")),
-        Line::from(format!(
-            "Focus: {} / {}",
-            format_hms(focus_seconds),
-            format_hms(DAILY_FOCUS_GOAL_SECS)
+        Line::from(fill(strings.compact_streak, &[&streak_days])),
+        Line::from(fill(
+            strings.compact_focus,
+            &[
+                &format_hms(focus_seconds),
+                &format_hms(DAILY_FOCUS_GOAL_SECS),
+            ],
         )),
         Line::from(loc_line),
+        Line::from(fill(strings.compact_commands, &[&commands_summary(state)])),
     ]);
 
     frame.render_widget(
@@ -91,7 +99,7 @@ fn render_streak_card(
     state: &AppState,
 ) {
     let block = Block::default()
-        .title(accent_title(theme, "STREAK"))
+        .title(accent_title(theme, state.strings().card_streak))
         .borders(Borders::ALL)
         .style(theme.base());
     let inner = block.inner(area);
@@ -109,7 +117,10 @@ fn render_streak_card(
             ))
         })
         .collect();
-    lines.push(Line::from(Span::styled("days", theme.base().fg(theme.fg))));
+    lines.push(Line::from(Span::styled(
+        state.strings().days,
+        theme.base().fg(theme.fg),
+    )));
 
     let content_height = u16::try_from(lines.len()).unwrap_or(inner.height);
     let para = Paragraph::new(Text::from(lines))
@@ -139,8 +150,9 @@ fn render_focus_card(
     theme: &Theme,
     state: &AppState,
 ) {
+    let strings = state.strings();
     let block = Block::default()
-        .title(accent_title(theme, "FOCUS"))
+        .title(accent_title(theme, strings.card_focus))
         .borders(Borders::ALL)
         .style(theme.base());
     let inner = block.inner(area);
@@ -157,13 +169,20 @@ fn render_focus_card(
         .direction(Direction::Vertical)
         .constraints([Constraint::Length(2), Constraint::Min(0)])
         .split(inner);
-    let top = *top_gauge.get(0).unwrap_or(&inner);
+    let top = *top_gauge.first().unwrap_or(&inner);
     let gauge_area = *top_gauge.get(1).unwrap_or(&inner);
 
+    // Labels share a column width so the times line up.
+    let label_width = UnicodeWidthStr::width(strings.label_today)
+        .max(UnicodeWidthStr::width(strings.label_goal));
+    let label = |text: &str| {
+        let pad = label_width - UnicodeWidthStr::width(text) + 1;
+        format!("{text}{}", " ".repeat(pad))
+    };
     let top_text = Text::from(vec![
         Line::from(vec![
             Span::styled(
-                "TODAY ",
+                label(strings.label_today),
                 theme.base().fg(theme.fg).add_modifier(Modifier::BOLD),
             ),
             Span::styled(
@@ -173,7 +192,7 @@ fn render_focus_card(
         ]),
         Line::from(vec![
             Span::styled(
-                "GOAL  ",
+                label(strings.label_goal),
                 theme.base().fg(theme.fg).add_modifier(Modifier::BOLD),
             ),
             Span::styled(
@@ -204,8 +223,9 @@ fn render_loc_card(
     theme: &Theme,
     state: &AppState,
 ) {
+    let strings = state.strings();
     let block = Block::default()
-        .title(accent_title(theme, "LOC"))
+        .title(accent_title(theme, strings.card_loc))
         .borders(Borders::ALL)
         .style(theme.base());
     let inner = block.inner(area);
@@ -241,11 +261,14 @@ fn render_loc_card(
             ]),
             Line::from(""),
             Line::from(Span::styled(
-                format!("net {}", delta.added.saturating_sub(delta.removed)),
+                fill(
+                    strings.loc_net,
+                    &[&delta.added.saturating_sub(delta.removed)],
+                ),
                 theme.base().fg(theme.fg),
             )),
         ],
-        None => vec![Line::from("git diff: N/A")],
+        None => vec![Line::from(strings.loc_unavailable)],
     };
 
     frame.render_widget(
@@ -263,54 +286,49 @@ fn render_status_table(
     theme: &Theme,
     state: &AppState,
 ) {
+    let strings = state.strings();
     let block = Block::default()
-        .title(accent_title(theme, "STATUS"))
+        .title(accent_title(theme, strings.card_status))
         .borders(Borders::ALL)
         .style(theme.base());
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
-    let tone = match state.motivation.tone() {
-        Tone::Brutal => "Brutal",
-        Tone::Encouraging => "Encouraging",
-        Tone::Emperor => "Emperor",
-    };
-
-    let focused = match state.focused {
-        PaneId::Motivation => "Motivation",
-        PaneId::General => "General",
-        PaneId::Agents => "Agents",
-        PaneId::Stats => "Stats",
-    };
-
-    let fullscreen = state.fullscreen.map(|p| match p {
-        PaneId::Motivation => "Motivation",
-        PaneId::General => "General",
-        PaneId::Agents => "Agents",
-        PaneId::Stats => "Stats",
-    });
+    let tone = tone_label(state.motivation.tone(), strings);
+    let yes_no = |value: bool| if value { strings.yes } else { strings.no };
 
     let rows = vec![
-        Row::new(vec![Cell::from("Focused"), Cell::from(focused)]),
         Row::new(vec![
-            Cell::from("Fullscreen"),
-            Cell::from(fullscreen.unwrap_or("No")),
+            Cell::from(strings.status_focused),
+            Cell::from(pane_label(state.focused, strings)),
+        ]),
+        Row::new(vec![
+            Cell::from(strings.status_fullscreen),
+            Cell::from(
+                state
+                    .fullscreen
+                    .map_or(strings.no, |pane| pane_label(pane, strings)),
+            ),
+        ]),
+        Row::new(vec![
+            Cell::from(strings.status_focus_active),
+            Cell::from(yes_no(state.focus.is_active())),
         ]),
         Row::new(vec![
-            Cell::from("Focus active"),
-            Cell::from(if state.focus.is_active() { "Yes" } else { "No" }),
+            Cell::from(strings.status_commands),
+            Cell::from(commands_summary(state)),
         ]),
         Row::new(vec![
-            Cell::from("Idle"),
-            Cell::from(if state.motivation.idle.is_idle() {
-                "Yes"
-            } else {
-                "No"
-            }),
+            Cell::from(strings.status_idle),
+            Cell::from(yes_no(state.motivation.idle.is_idle())),
+        ]),
+        Row::new(vec![
+            Cell::from(strings.status_agents),
+            Cell::from(agents_summary(state)),
         ]),
-        Row::new(vec![Cell::from("Tone"), Cell::from(tone)]),
+        Row::new(vec![Cell::from(strings.status_tone), Cell::from(tone)]),
         Row::new(vec![
-            Cell::from("Last command"),
+            Cell::from(strings.status_last_command),
             Cell::from(
                 state
                     .last_command
@@ -326,11 +344,11 @@ fn render_status_table(
         .header(
             Row::new(vec![
                 Cell::from(Span::styled(
-                    "Key",
+                    strings.status_key,
                     theme.base().fg(theme.fg).add_modifier(Modifier::BOLD),
                 )),
                 Cell::from(Span::styled(
-                    "Value",
+                    strings.status_value,
                     theme.base().fg(theme.fg).add_modifier(Modifier::BOLD),
                 )),
             ])
@@ -341,6 +359,54 @@ fn render_status_table(
     frame.render_widget(table, inner);
 }
 
+/// `codex busy, aider waiting`; exited agents are listed as such.
+fn agents_summary(state: &AppState) -> String {
+    let strings = state.strings();
+    let summary: Vec<String> = state
+        .agent_tabs
+        .iter()
+        .filter_map(|tab| {
+            let name = tab.name.as_deref()?;
+            let status = if tab.process.exit_code().is_some() {
+                strings.agent_exited
+            } else {
+                agent_status_label(tab.agent_status.status(), strings)
+            };
+            Some(format!("{name} {status}"))
+        })
+        .collect();
+    if summary.is_empty() {
+        "—".to_string()
+    } else {
+        summary.join(", ")
+    }
+}
+
+/// `12 today / 2 failed`, counting shell commands seen through OSC 133.
+fn commands_summary(state: &AppState) -> String {
+    fill(
+        state.strings().commands_summary,
+        &[&state.commands.today, &state.commands.failed],
+    )
+}
+
+fn tone_label(tone: Tone, strings: &Strings) -> &'static str {
+    match tone {
+        Tone::Brutal => strings.tone_brutal,
+        Tone::Encouraging => strings.tone_encouraging,
+        Tone::Emperor => strings.tone_emperor,
+    }
+}
+
+fn pane_label(pane: PaneId, strings: &Strings) -> &'static str {
+    match pane {
+        PaneId::Motivation => strings.status_motivation,
+        PaneId::General => strings.status_general,
+        PaneId::Agents => strings.status_agents_pane,
+        PaneId::Stats => strings.status_stats,
+    }
+}
+
 fn format_hms(total_seconds: u64) -> String {
     let hours = total_seconds / 3600;
     let minutes = (total_seconds % 3600) / 60;
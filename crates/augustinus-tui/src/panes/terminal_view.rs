@@ -0,0 +1,250 @@
+use augustinus_app::{
+    CopyMode, CopyPos, ProcessLifecycle, ScrollState, SearchState, SelectionKind, TermCell,
+    TerminalScreen, TerminalTab,
+};
+use augustinus_i18n::{fill, Strings};
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    Frame,
+};
+
+use crate::theme::Theme;
+
+/// Draws a PTY screen cell by cell into `area`, keeping the bottom of the used
+/// region in view when the screen is taller than the area. Returns the first
+/// screen row that was drawn.
+pub fn render_screen(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    screen: &TerminalScreen,
+    theme: &Theme,
+) -> u16 {
+    let start_row = (screen.last_used_row() + 1).saturating_sub(area.height);
+    let buf = frame.buffer_mut();
+    for y in 0..area.height {
+        let row = start_row + y;
+        if row >= screen.rows() {
+            break;
+        }
+        for x in 0..area.width.min(screen.cols()) {
+            let Some(cell) = screen.cell(row, x) else { break };
+            if cell.wide_continuation {
+                continue;
+            }
+            let Some(target) = buf.cell_mut((area.x + x, area.y + y)) else {
+                continue;
+            };
+            if cell.symbol.is_empty() {
+                target.set_char(' ');
+            } else {
+                target.set_symbol(&cell.symbol);
+            }
+            target.set_style(cell_style(cell, theme));
+        }
+    }
+    start_row
+}
+
+/// Places the terminal cursor when the child has not hidden it.
+pub fn set_cursor(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    screen: &TerminalScreen,
+    start_row: u16,
+) {
+    if screen.hide_cursor || area.width == 0 || area.height == 0 {
+        return;
+    }
+    let row = screen.cursor_row.saturating_sub(start_row);
+    frame.set_cursor_position(Position {
+        x: area
+            .x
+            .saturating_add(screen.cursor_col)
+            .min(area.right().saturating_sub(1)),
+        y: area.y.saturating_add(row).min(area.bottom().saturating_sub(1)),
+    });
+}
+
+pub fn scroll_header(scroll: &ScrollState, strings: &Strings, theme: &Theme) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            fill(strings.scroll_header, &[&scroll.offset()]),
+            theme.base().fg(theme.border_focused).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            fill(strings.scroll_hint, &[&scroll.max_offset()]),
+            theme.base().fg(theme.accent),
+        ),
+    ])
+}
+
+/// Highlights the search matches that fall on the drawn rows; the selected
+/// match stands out from the rest.
+pub fn highlight_matches(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    tab: &TerminalTab,
+    start_row: u16,
+    theme: &Theme,
+) {
+    let search = &tab.search;
+    if !search.is_active() {
+        return;
+    }
+    // History line shown on screen row 0 at the current scroll offset.
+    let top = tab.scroll.max_offset().saturating_sub(tab.scroll.offset());
+    let current = search.current_index();
+    let buf = frame.buffer_mut();
+    for (index, found) in search.matches().iter().enumerate() {
+        let Some(row) = found.line.checked_sub(top) else {
+            continue;
+        };
+        let Some(y) = row.checked_sub(start_row as usize) else {
+            continue;
+        };
+        if y >= area.height as usize {
+            continue;
+        }
+        let style = if Some(index) == current {
+            theme.base().fg(theme.bg).bg(theme.border_focused).add_modifier(Modifier::BOLD)
+        } else {
+            theme.base().fg(theme.bg).bg(theme.accent)
+        };
+        for col in found.start_col..found.end_col.min(area.width) {
+            if let Some(cell) = buf.cell_mut((area.x + col, area.y + y as u16)) {
+                cell.set_style(style);
+            }
+        }
+    }
+}
+
+/// Marks the copy-mode selection and cursor on the drawn rows.
+pub fn highlight_copy(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    tab: &TerminalTab,
+    start_row: u16,
+    theme: &Theme,
+) {
+    let Some(copy) = tab.copy else { return };
+    let top = tab.scroll.max_offset().saturating_sub(tab.scroll.offset()) + start_row as usize;
+    let buf = frame.buffer_mut();
+    for y in 0..area.height {
+        for x in 0..area.width.min(tab.screen.cols()) {
+            let pos = CopyPos {
+                line: top + y as usize,
+                col: x,
+            };
+            let style = if pos == copy.cursor() {
+                theme.base().fg(theme.bg).bg(theme.border_focused)
+            } else if copy.is_selected(pos) {
+                theme.base().fg(theme.bg).bg(theme.accent)
+            } else {
+                continue;
+            };
+            if let Some(cell) = buf.cell_mut((area.x + x, area.y + y)) {
+                cell.set_style(style);
+            }
+        }
+    }
+}
+
+pub fn copy_header(copy: &CopyMode, strings: &Strings, theme: &Theme) -> Line<'static> {
+    let mode = match copy.selection_kind() {
+        None => strings.copy_header,
+        Some(SelectionKind::Char) => strings.copy_selecting,
+        Some(SelectionKind::Line) => strings.copy_selecting_lines,
+        Some(SelectionKind::Block) => strings.copy_selecting_block,
+    };
+    Line::from(vec![
+        Span::styled(
+            mode,
+            theme.base().fg(theme.border_focused).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(strings.copy_hint, theme.base().fg(theme.accent)),
+    ])
+}
+
+/// Search prompt while typing, then the query and match position.
+pub fn search_line(
+    search: &SearchState,
+    strings: &Strings,
+    theme: &Theme,
+) -> Option<Line<'static>> {
+    let mode = if search.is_regex() {
+        strings.search_mode_regex
+    } else {
+        strings.search_mode_text
+    };
+    if let Some(prompt) = search.prompt() {
+        return Some(Line::from(vec![
+            Span::styled(
+                format!("/{prompt}▏"),
+                theme.base().fg(theme.fg).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                fill(strings.search_prompt_hint, &[&mode]),
+                theme.base().fg(theme.accent),
+            ),
+        ]));
+    }
+    let query = search.query()?;
+    let status = if let Some(error) = search.error() {
+        fill(
+            strings.search_invalid_regex,
+            &[&error.lines().last().unwrap_or(error)],
+        )
+    } else if let Some(current) = search.current_index() {
+        fill(strings.search_match, &[&(current + 1), &search.matches().len()])
+    } else {
+        strings.search_no_matches.to_string()
+    };
+    Some(Line::from(vec![
+        Span::styled(
+            fill(strings.search_header, &[&query, &status]),
+            theme.base().fg(theme.border_focused).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(strings.search_hint, theme.base().fg(theme.accent)),
+    ]))
+}
+
+pub fn exit_line(
+    process: &ProcessLifecycle,
+    strings: &Strings,
+    theme: &Theme,
+) -> Option<Line<'static>> {
+    let code = process.exit_code()?;
+    let mut text = match process.start_error() {
+        Some(error) => fill(strings.process_start_failed, &[&error]),
+        None => fill(strings.process_exited, &[&code]),
+    };
+    if let Some(remaining) = process.restart_in() {
+        let secs = remaining.as_millis().div_ceil(1000);
+        text.push_str(&fill(strings.process_auto_restart, &[&secs]));
+    }
+    Some(Line::from(Span::styled(
+        text,
+        theme.base().fg(theme.border_focused).add_modifier(Modifier::BOLD),
+    )))
+}
+
+fn cell_style(cell: &TermCell, theme: &Theme) -> Style {
+    let mut fg = theme.term_color(cell.fg, theme.fg);
+    let mut bg = theme.term_color(cell.bg, theme.bg);
+    if cell.inverse {
+        std::mem::swap(&mut fg, &mut bg);
+    }
+    let mut modifier = Modifier::empty();
+    if cell.bold {
+        modifier |= Modifier::BOLD;
+    }
+    if cell.italic {
+        modifier |= Modifier::ITALIC;
+    }
+    if cell.underline {
+        modifier |= Modifier::UNDERLINED;
+    }
+    Style::default().fg(fg).bg(bg).add_modifier(modifier)
+}
@@ -0,0 +1,30 @@
+use augustinus_app::AppState;
+use ratatui::{layout::Rect, text::Span, widgets::Paragraph, Frame};
+use unicode_width::UnicodeWidthStr;
+
+use crate::theme::Theme;
+
+/// The keys typed so far of an unfinished sequence, like `Ctrl-a …`, in the
+/// bottom-right corner over the pane borders.
+pub fn render(frame: &mut Frame<'_>, state: &AppState, theme: &Theme) {
+    let pending = state.keymap.pending();
+    if pending.is_empty() {
+        return;
+    }
+    let keys: Vec<String> = pending.iter().map(ToString::to_string).collect();
+    let text = format!(" {} … ", keys.join(" "));
+
+    let area = frame.area();
+    let width = (UnicodeWidthStr::width(text.as_str()) as u16).min(area.width.saturating_sub(2));
+    if width == 0 || area.height == 0 {
+        return;
+    }
+    let corner = Rect {
+        x: area.right() - 1 - width,
+        y: area.bottom() - 1,
+        width,
+        height: 1,
+    };
+    let style = theme.base().fg(theme.bg).bg(theme.accent).bold();
+    frame.render_widget(Paragraph::new(Span::styled(text, style)), corner);
+}
@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use ratatui::{
     layout::{Alignment, Rect},
-    style::{Style, Stylize},
+    style::Style,
     text::{Line, Text},
     widgets::{Block, Paragraph},
     Frame,
@@ -23,7 +23,7 @@ pub fn render(frame: &mut Frame<'_>, elapsed: Duration) {
     let max_line_len = art_lines.iter().map(|line| line.len()).max().unwrap_or(0);
     let width = (max_line_len as u16).max(1);
 
-    let shimmer = ((elapsed.as_millis() / 120) % 2) == 0;
+    let shimmer = (elapsed.as_millis() / 120).is_multiple_of(2);
     let fg = if shimmer { theme.fg } else { theme.accent };
 
     let rect = centered_rect(area, height, width);
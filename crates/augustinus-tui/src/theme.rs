@@ -1,3 +1,4 @@
+use augustinus_app::TermColor;
 use ratatui::style::{Color, Style};
 
 #[derive(Debug, Clone)]
@@ -7,6 +8,10 @@ pub struct Theme {
     pub accent: Color,
     pub border_focused: Color,
     pub border_unfocused: Color,
+    /// Bell/activity/silence badges and the border flash that comes with them.
+    pub alert: Color,
+    /// The 16 base ANSI colors used by programs running inside the PTY panes.
+    pub ansi: [Color; 16],
 }
 
 impl Theme {
@@ -17,10 +22,39 @@ impl Theme {
             accent: Color::Rgb(120, 220, 255),
             border_focused: Color::Rgb(255, 36, 90),
             border_unfocused: Color::Rgb(120, 220, 255),
+            alert: Color::Rgb(255, 214, 120),
+            ansi: [
+                Color::Rgb(10, 28, 50),
+                Color::Rgb(255, 84, 112),
+                Color::Rgb(120, 230, 160),
+                Color::Rgb(255, 214, 120),
+                Color::Rgb(90, 160, 255),
+                Color::Rgb(200, 140, 255),
+                Color::Rgb(120, 220, 255),
+                Color::Rgb(200, 215, 230),
+                Color::Rgb(70, 95, 125),
+                Color::Rgb(255, 120, 145),
+                Color::Rgb(160, 250, 190),
+                Color::Rgb(255, 235, 160),
+                Color::Rgb(140, 190, 255),
+                Color::Rgb(225, 180, 255),
+                Color::Rgb(170, 235, 255),
+                Color::Rgb(235, 245, 255),
+            ],
         }
     }
 
     pub fn base(&self) -> Style {
         Style::default().fg(self.fg).bg(self.bg)
     }
+
+    /// Maps a PTY cell color onto the theme; `Default` resolves to `default`.
+    pub fn term_color(&self, color: TermColor, default: Color) -> Color {
+        match color {
+            TermColor::Default => default,
+            TermColor::Indexed(idx) if idx < 16 => self.ansi[idx as usize],
+            TermColor::Indexed(idx) => Color::Indexed(idx),
+            TermColor::Rgb(r, g, b) => Color::Rgb(r, g, b),
+        }
+    }
 }
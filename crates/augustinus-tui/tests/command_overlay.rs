@@ -1,5 +1,5 @@
-use augustinus_app::AppState;
-use ratatui::{backend::TestBackend, Terminal};
+use augustinus_app::{Action, AppState};
+use ratatui::{backend::TestBackend, style::Color, Terminal};
 
 #[test]
 fn command_overlay_renders_when_active() {
@@ -20,3 +20,74 @@ fn command_overlay_renders_when_active() {
 
     assert!(screen.contains(":q"));
 }
+
+#[test]
+fn command_overlay_shows_errors_and_help_inline() {
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    let mut state = AppState::new_for_test();
+    state.apply(Action::EnterCommandMode);
+    for ch in "tab open".chars() {
+        state.apply(Action::CommandAppend(ch));
+    }
+    state.apply(Action::SubmitCommand);
+
+    terminal.draw(|f| augustinus_tui::render(f, &mut state)).unwrap();
+    let text = screen(&terminal);
+    assert!(text.contains(":tab open"));
+    assert!(text.contains(r#"tab: "open" is not new, close, next or prev"#));
+
+    state.apply(Action::ExitCommandMode);
+    state.apply(Action::EnterCommandMode);
+    state.apply(Action::CommandAppend('h'));
+    state.apply(Action::SubmitCommand);
+    terminal.draw(|f| augustinus_tui::render(f, &mut state)).unwrap();
+    let text = screen(&terminal);
+    assert!(text.contains("Leave augustinus"));
+    assert!(text.contains(":record start|stop [general|agents]"));
+}
+
+fn screen(terminal: &Terminal<TestBackend>) -> String {
+    terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|c| c.symbol())
+        .collect()
+}
+
+#[test]
+fn completion_popup_lists_candidates_under_the_prompt() {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    let mut state = AppState::new_for_test();
+    state.apply(Action::EnterCommandMode);
+    for ch in "tab n".chars() {
+        state.apply(Action::CommandAppend(ch));
+    }
+    state.apply(Action::CommandComplete);
+    state.apply(Action::CommandComplete);
+
+    terminal.draw(|f| augustinus_tui::render(f, &mut state)).unwrap();
+    let rows = rows(&terminal);
+    let prompt = rows.iter().position(|row| row.contains(":tab new")).unwrap();
+    let x = column(&rows[prompt], "new").unwrap();
+    assert_eq!(column(&rows[prompt + 2], "new"), Some(x));
+    assert_eq!(column(&rows[prompt + 3], "next"), Some(x));
+    let selected = &terminal.backend().buffer()[(x as u16, prompt as u16 + 2)];
+    assert_eq!(selected.bg, Color::Rgb(120, 220, 255));
+}
+
+fn column(row: &str, text: &str) -> Option<usize> {
+    row.find(text).map(|index| row[..index].chars().count())
+}
+
+fn rows(terminal: &Terminal<TestBackend>) -> Vec<String> {
+    let buffer = terminal.backend().buffer();
+    (0..buffer.area.height)
+        .map(|y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,104 @@
+use augustinus_app::{Action, AgentPicker, AppState, PaneId};
+use augustinus_i18n::Language;
+use ratatui::{backend::TestBackend, Terminal};
+
+fn rendered(state: &mut AppState) -> String {
+    let backend = TestBackend::new(120, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| augustinus_tui::render(f, state)).unwrap();
+    let buf = terminal.backend().buffer();
+    let area = buf.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            (area.left()..area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn single_tab_keeps_plain_title() {
+    let mut state = AppState::new_for_test();
+    let text = rendered(&mut state);
+    assert!(text.contains("GENERAL"));
+    assert!(!text.contains("GENERAL  1 "));
+}
+
+#[test]
+fn title_lists_tabs_when_several_are_open() {
+    let mut state = AppState::new_for_test();
+    state.apply(Action::TabNew);
+    state.apply(Action::TabNew);
+    let text = rendered(&mut state);
+    assert!(text.contains("GENERAL  1  2  3 "));
+}
+
+#[test]
+fn titles_follow_what_the_terminals_run() {
+    let mut state = AppState::new_for_test();
+    state.agents_mut().title = Some("vim".to_string());
+    state.general_mut().title = Some("cargo watch".to_string());
+    let text = rendered(&mut state);
+    assert!(text.contains("GENERAL — cargo watch"));
+    assert!(text.contains("AI AGENTS — vim"));
+
+    state.apply(Action::TabNew);
+    state.general_mut().title = Some("bash".to_string());
+    let text = rendered(&mut state);
+    assert!(text.contains("GENERAL  1:cargo watch  2:bash "));
+}
+
+#[test]
+fn hidden_terminals_show_alert_badges() {
+    let mut state = AppState::new_for_test();
+    state.apply(Action::FocusPane(PaneId::Stats));
+    state.agents_mut().activity.on_bell(false);
+    state.agents_mut().activity.on_output(false);
+    let text = rendered(&mut state);
+    assert!(text.contains("AI AGENTS (waiting) !+"));
+
+    state.apply(Action::FocusPane(PaneId::Agents));
+    let text = rendered(&mut state);
+    assert!(!text.contains("AI AGENTS (waiting) !+"));
+}
+
+#[test]
+fn agents_are_listed_by_name_with_exit_status() {
+    let mut state = AppState::new_for_test();
+    state.agents_mut().name = Some("codex".to_string());
+    let text = rendered(&mut state);
+    assert!(text.contains("AI AGENTS — codex (waiting)"));
+
+    state.agent_tabs.open();
+    state.agents_mut().name = Some("aider".to_string());
+    state.agents_mut().process.on_exit(1);
+    let text = rendered(&mut state);
+    assert!(text.contains("AI AGENTS  1:codex?  2:aider✗ "));
+}
+
+#[test]
+fn picker_replaces_the_agent_screen() {
+    let mut state = AppState::new_for_test();
+    state.agent_picker = Some(AgentPicker::new(vec!["claude".to_string(), "shell".to_string()]));
+    let text = rendered(&mut state);
+    assert!(text.contains("No configured agent found on PATH"));
+    assert!(text.contains(" 1. claude "));
+    assert!(text.contains(" 2. shell "));
+}
+
+#[test]
+fn titles_follow_the_language() {
+    let mut state = AppState::new_for_test();
+    state.agents_mut().name = Some("codex".to_string());
+    state.apply(Action::SetLanguage(Language::Fr));
+    let text = rendered(&mut state);
+    assert!(text.contains("GÉNÉRAL"));
+    assert!(text.contains("AGENTS IA — codex (en attente)"));
+    assert!(!text.contains("AI AGENTS"));
+
+    state.apply(Action::SetLanguage(Language::En));
+    let text = rendered(&mut state);
+    assert!(text.contains("AI AGENTS — codex (waiting)"));
+}
@@ -0,0 +1,23 @@
+use augustinus_app::{AppState, KeyChord, KeyMode};
+use ratatui::{backend::TestBackend, Terminal};
+
+fn last_row(state: &mut AppState) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|f| augustinus_tui::render(f, state)).unwrap();
+    let buf = terminal.backend().buffer();
+    (0..buf.area.width).map(|x| buf[(x, buf.area.height - 1)].symbol()).collect()
+}
+
+#[test]
+fn pending_sequence_shows_in_the_corner() {
+    let mut state = AppState::new_for_test();
+    state
+        .keymap
+        .configure(KeyMode::App, [("Ctrl-a f", "fullscreen")])
+        .unwrap();
+    assert!(!last_row(&mut state).contains('…'));
+
+    state.keymap.feed(KeyMode::App, KeyChord::parse("Ctrl-a").unwrap());
+    let row = last_row(&mut state);
+    assert!(row.trim_end_matches('┘').ends_with(" Ctrl-a … "), "{row:?}");
+}
@@ -0,0 +1,45 @@
+use augustinus_app::{AppState, PaneId, TerminalScreen};
+use ratatui::{backend::TestBackend, style::Color, Terminal};
+
+#[test]
+fn matches_are_highlighted_and_counted() {
+    let backend = TestBackend::new(100, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let mut screen = TerminalScreen::new(4, 30);
+    for (row, text) in ["boom here", "fine", "boom again"].iter().enumerate() {
+        for (col, ch) in text.chars().enumerate() {
+            screen.cell_mut(row as u16, col as u16).unwrap().symbol = ch.to_string();
+        }
+    }
+
+    let mut state = AppState::new_for_test();
+    state.focused = PaneId::General;
+    let tab = state.general_mut();
+    tab.scroll.sync(0, 0, 4);
+    tab.search.open_prompt();
+    for ch in "boom".chars() {
+        tab.search.push(ch);
+    }
+    tab.search.submit();
+    tab.search.run(&screen);
+    tab.screen = screen;
+
+    terminal.draw(|f| augustinus_tui::render(f, &mut state)).unwrap();
+    let buf = terminal.backend().buffer();
+
+    let text: Vec<String> = (0..30)
+        .map(|y| (0..100).map(|x| buf[(x, y)].symbol()).collect())
+        .collect();
+    assert!(text.iter().any(|line| line.contains("match 2 of 2")));
+
+    let (x, y) = text
+        .iter()
+        .enumerate()
+        .find_map(|(y, line)| line.find("boom here").map(|idx| (line[..idx].chars().count(), y)))
+        .unwrap();
+    let theme_accent = Color::Rgb(120, 220, 255);
+    assert_eq!(buf[(x as u16, y as u16)].bg, theme_accent);
+    assert_ne!(buf[(x as u16 + 5, y as u16)].bg, theme_accent);
+    assert_eq!(buf[(x as u16, y as u16 + 2)].bg, Color::Rgb(255, 36, 90));
+}
@@ -0,0 +1,24 @@
+use augustinus_app::{AppState, PaneId, PaneRect, SplitRatios};
+use ratatui::{backend::TestBackend, Terminal};
+
+#[test]
+fn panes_follow_the_split_ratios() {
+    let mut state = AppState::new_for_test();
+    state.splits = SplitRatios::new(70, 40, 75);
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal
+        .draw(|f| augustinus_tui::render(f, &mut state))
+        .unwrap();
+
+    let screen = PaneRect {
+        x: 0,
+        y: 0,
+        width: 100,
+        height: 40,
+    };
+    for pane in [PaneId::Motivation, PaneId::General, PaneId::Agents, PaneId::Stats] {
+        assert_eq!(state.layout.pane_area(pane), Some(state.splits.pane_rect(screen, pane)));
+    }
+    let general = state.layout.pane_area(PaneId::General).unwrap();
+    assert_eq!((general.x, general.width, general.height), (40, 60, 28));
+}
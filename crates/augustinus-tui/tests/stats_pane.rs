@@ -1,4 +1,6 @@
-use augustinus_app::{AppState, LocDelta};
+use std::time::Duration;
+
+use augustinus_app::{AppState, LocDelta, PaneId};
 use ratatui::{backend::TestBackend, Terminal};
 
 #[test]
@@ -56,3 +58,53 @@ fn stats_pane_small_terminal_still_renders_useful_text() {
     assert!(screen.contains("Streak") || screen.contains("STREAK"));
     assert!(screen.contains("Focus") || screen.contains("FOCUS"));
 }
+
+#[test]
+fn status_table_lists_agent_states() {
+    let backend = TestBackend::new(120, 40);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let mut state = AppState::new_for_test();
+    state.fullscreen = Some(PaneId::Stats);
+    state.agents_mut().name = Some("codex".to_string());
+    state.agents_mut().agent_status.on_screen_change(false);
+    state.agents_mut().agent_status.tick(Duration::from_millis(100));
+    state.agent_tabs.open();
+    state.agents_mut().name = Some("aider".to_string());
+
+    terminal
+        .draw(|f| augustinus_tui::render(f, &mut state))
+        .unwrap();
+
+    let buf = terminal.backend().buffer();
+    let screen = buf
+        .content()
+        .iter()
+        .map(|c| c.symbol())
+        .collect::<String>();
+    assert!(screen.contains("codex busy, aider waiting"));
+}
+
+#[test]
+fn status_table_counts_shell_commands() {
+    let backend = TestBackend::new(120, 40);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let mut state = AppState::new_for_test();
+    state.fullscreen = Some(PaneId::Stats);
+    state.commands.record(Some(0));
+    state.commands.record(Some(127));
+    state.commands.record(None);
+
+    terminal
+        .draw(|f| augustinus_tui::render(f, &mut state))
+        .unwrap();
+
+    let buf = terminal.backend().buffer();
+    let screen = buf
+        .content()
+        .iter()
+        .map(|c| c.symbol())
+        .collect::<String>();
+    assert!(screen.contains("3 today / 1 failed"));
+}
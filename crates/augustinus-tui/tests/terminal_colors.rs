@@ -0,0 +1,49 @@
+use augustinus_app::{AppState, PaneId, TermColor, TerminalScreen};
+use ratatui::{backend::TestBackend, style::Color, style::Modifier, Terminal};
+
+#[test]
+fn general_pane_renders_cell_colors_and_attributes() {
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let mut screen = TerminalScreen::new(4, 20);
+    for (col, ch) in "err".chars().enumerate() {
+        let cell = screen.cell_mut(0, col as u16).unwrap();
+        cell.symbol = ch.to_string();
+        cell.fg = TermColor::Rgb(200, 10, 10);
+        cell.bold = true;
+    }
+    let cell = screen.cell_mut(1, 0).unwrap();
+    cell.symbol = "x".to_string();
+    cell.fg = TermColor::Indexed(196);
+    cell.inverse = true;
+
+    let mut state = AppState::new_for_test();
+    state.focused = PaneId::General;
+    state.general_mut().screen = screen;
+
+    terminal.draw(|f| augustinus_tui::render(f, &mut state)).unwrap();
+
+    let buf = terminal.backend().buffer();
+    let (x, y) = find(buf, "err").expect("screen text rendered");
+    let cell = &buf[(x, y)];
+    assert_eq!(cell.fg, Color::Rgb(200, 10, 10));
+    assert!(cell.modifier.contains(Modifier::BOLD));
+
+    let inverse = &buf[(x, y + 1)];
+    assert_eq!(inverse.symbol(), "x");
+    assert_eq!(inverse.bg, Color::Indexed(196));
+}
+
+fn find(buf: &ratatui::buffer::Buffer, needle: &str) -> Option<(u16, u16)> {
+    let area = buf.area;
+    for y in area.top()..area.bottom() {
+        let row: String = (area.left()..area.right())
+            .map(|x| buf[(x, y)].symbol())
+            .collect();
+        if let Some(idx) = row.find(needle) {
+            return Some((row[..idx].chars().count() as u16, y));
+        }
+    }
+    None
+}
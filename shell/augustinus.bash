@@ -0,0 +1,36 @@
+# Shell integration for bash: marks prompts and commands with OSC 133 so the
+# GENERAL pane can jump between prompts, select a command's output and count
+# commands in STATS. Source it at the end of ~/.bashrc, after anything else
+# that sets PS1 or PROMPT_COMMAND:
+#
+#     source /path/to/augustinus/shell/augustinus.bash
+
+if [[ $- == *i* && -z ${__augustinus_marks-} ]]; then
+  __augustinus_marks=1
+  # Set while the prompt is up; the DEBUG trap then means a command starts.
+  __augustinus_ready=
+  # Set once a command started, so empty lines don't count as commands.
+  __augustinus_ran=
+
+  __augustinus_preexec() {
+    [[ -n $__augustinus_ready && -z ${COMP_LINE-} ]] || return
+    [[ $BASH_COMMAND == __augustinus_precmd ]] && return
+    __augustinus_ready=
+    __augustinus_ran=1
+    printf '\033]133;C\007'
+  }
+
+  __augustinus_precmd() {
+    local code=$?
+    __augustinus_ready=
+    if [[ -n $__augustinus_ran ]]; then
+      printf '\033]133;D;%s\007' "$code"
+    fi
+    __augustinus_ran=
+    return "$code"
+  }
+
+  PROMPT_COMMAND="__augustinus_precmd${PROMPT_COMMAND:+; $PROMPT_COMMAND}; __augustinus_ready=1"
+  PS1="\[\033]133;A\007\]$PS1\[\033]133;B\007\]"
+  trap '__augustinus_preexec' DEBUG
+fi
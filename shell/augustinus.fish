@@ -0,0 +1,24 @@
+# Shell integration for fish: marks prompts and commands with OSC 133 so the
+# GENERAL pane can jump between prompts, select a command's output and count
+# commands in STATS. Source it from ~/.config/fish/config.fish:
+#
+#     source /path/to/augustinus/shell/augustinus.fish
+
+if status is-interactive; and not set -q __augustinus_marks
+    set -g __augustinus_marks 1
+
+    functions -c fish_prompt __augustinus_fish_prompt
+    function fish_prompt
+        printf '\e]133;A\a'
+        __augustinus_fish_prompt
+        printf '\e]133;B\a'
+    end
+
+    function __augustinus_preexec --on-event fish_preexec
+        printf '\e]133;C\a'
+    end
+
+    function __augustinus_postexec --on-event fish_postexec
+        printf '\e]133;D;%s\a' $status
+    end
+end